@@ -0,0 +1,852 @@
+// =============================================================================
+// ALGORITHMS - Classic Algorithms Written with Generics
+// =============================================================================
+// A library crate (lib.rs, no main): run the examples with
+//   cargo test -p algorithms
+//
+// Everything here is generic over T: Ord, so the same code sorts and
+// searches integers, strings, or any type with a total order.
+// =============================================================================
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+// =============================================================================
+// BINARY SEARCH - O(log n) on a SORTED slice
+// =============================================================================
+// Keep a half-open window [low, high) that must contain the target if it is
+// present, and halve it on every step.
+//
+// With duplicates, ANY matching index may be returned (like slice::binary_search).
+
+pub fn binary_search<T: Ord>(slice: &[T], target: &T) -> Option<usize> {
+    let mut low = 0;
+    let mut high = slice.len();
+
+    while low < high {
+        // Not (low + high) / 2 - that can overflow for huge slices
+        let mid = low + (high - low) / 2;
+        match slice[mid].cmp(target) {
+            Ordering::Equal => return Some(mid),
+            Ordering::Less => low = mid + 1,  // Target is to the right
+            Ordering::Greater => high = mid,  // Target is to the left
+        }
+    }
+    None
+}
+
+// =============================================================================
+// MERGE SORT - Iterative, Bottom-Up, O(n log n)
+// =============================================================================
+// Instead of recursively splitting, start from runs of width 1 (trivially
+// sorted) and repeatedly merge neighbouring runs: width 1 -> 2 -> 4 -> ...
+//
+//   [5] [2] [4] [1]  ->  [2 5] [1 4]  ->  [1 2 4 5]
+//
+// Each pass reads from one buffer and writes to the other, then they swap.
+// No recursion means no risk of stack overflow. The merge takes from the
+// LEFT run on ties, so the sort is stable.
+
+pub fn merge_sort<T: Ord + Clone>(data: &[T]) -> Vec<T> {
+    let len = data.len();
+    let mut src = data.to_vec();
+    let mut dst = Vec::with_capacity(len);
+    let mut width = 1;
+
+    while width < len {
+        dst.clear();
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            merge(&src[start..mid], &src[mid..end], &mut dst);
+        }
+        std::mem::swap(&mut src, &mut dst);
+        width *= 2;
+    }
+    src
+}
+
+// Appends the merge of two sorted runs to `out`
+fn merge<T: Ord + Clone>(left: &[T], right: &[T], out: &mut Vec<T>) {
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] <= right[j] {
+            out.push(left[i].clone());
+            i += 1;
+        } else {
+            out.push(right[j].clone());
+            j += 1;
+        }
+    }
+    // One run is used up - the rest of the other is already sorted
+    out.extend_from_slice(&left[i..]);
+    out.extend_from_slice(&right[j..]);
+}
+
+// =============================================================================
+// DIJKSTRA'S SHORTEST PATHS - BinaryHeap as a Priority Queue
+// =============================================================================
+// graph[node] = list of (neighbour, edge cost). Costs must be non-negative.
+//
+// Always expand the cheapest unexplored path next. The first time a node is
+// popped, no cheaper path to it can exist - every other path in the frontier
+// already costs at least as much, and edges never reduce the cost.
+//
+// BinaryHeap is a MAX-heap; wrapping entries in Reverse makes it pop the
+// smallest cost first. Tuples compare field by field, so cost comes first.
+//
+// Returns node -> (shortest cost, path from start). Unreachable nodes are
+// simply absent.
+
+pub fn dijkstra(graph: &HashMap<usize, Vec<(usize, u64)>>, start: usize) -> HashMap<usize, (u64, Vec<usize>)> {
+    let mut best: HashMap<usize, u64> = HashMap::new();  // Cheapest cost seen so far
+    let mut result = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    best.insert(start, 0);
+    frontier.push(Reverse((0, start, vec![start])));
+
+    while let Some(Reverse((cost, node, path))) = frontier.pop() {
+        // A cheaper path to this node was already settled - skip stale entry
+        if result.contains_key(&node) {
+            continue;
+        }
+
+        for &(next, edge_cost) in graph.get(&node).into_iter().flatten() {
+            let next_cost = cost + edge_cost;
+            // Only queue paths that improve on the best known cost
+            if best.get(&next).is_none_or(|&known| next_cost < known) {
+                best.insert(next, next_cost);
+                let mut next_path = path.clone();
+                next_path.push(next);
+                frontier.push(Reverse((next_cost, next, next_path)));
+            }
+        }
+
+        result.insert(node, (cost, path));
+    }
+    result
+}
+
+// =============================================================================
+// KNUTH-MORRIS-PRATT - Substring Search in O(n + m)
+// =============================================================================
+// The naive search restarts from scratch after every mismatch: O(n * m).
+// KMP precomputes, for each prefix of the pattern, how much of it can be
+// REUSED after a mismatch, so the text is never re-read.
+//
+// failure[i] = length of the longest proper prefix of pattern[..=i] that is
+// also a suffix of it. For "abab": [0, 0, 1, 2].
+//
+// Works on bytes, so results are byte offsets. That is exact for ASCII, and
+// also safe for UTF-8: a valid UTF-8 pattern can only match on a char boundary.
+
+pub fn failure_function(pattern: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut matched = 0;  // Length of the current prefix-that-is-also-suffix
+
+    for i in 1..pattern.len() {
+        // Fall back through shorter borders until one can be extended
+        while matched > 0 && pattern[i] != pattern[matched] {
+            matched = failure[matched - 1];
+        }
+        if pattern[i] == pattern[matched] {
+            matched += 1;
+        }
+        failure[i] = matched;
+    }
+    failure
+}
+
+// Start index of every occurrence, overlapping ones included.
+// An empty pattern matches at every position 0..text.len().
+pub fn kmp_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..text.len()).collect();
+    }
+
+    let failure = failure_function(pattern);
+    let mut matches = Vec::new();
+    let mut matched = 0;  // How many pattern bytes currently line up
+
+    for (i, &byte) in text.iter().enumerate() {
+        while matched > 0 && byte != pattern[matched] {
+            matched = failure[matched - 1];
+        }
+        if byte == pattern[matched] {
+            matched += 1;
+        }
+        if matched == pattern.len() {
+            matches.push(i + 1 - pattern.len());
+            matched = failure[matched - 1];  // Keep going for overlaps
+        }
+    }
+    matches
+}
+
+// =============================================================================
+// DYNAMIC PROGRAMMING - Longest Common Subsequence and Edit Distance
+// =============================================================================
+// Both problems are solved by filling a table where each cell depends only
+// on its neighbours above and to the left:
+//
+//   table[i][j] = answer for the first i items of a and first j items of b
+//
+// Every cell is computed once, so both run in O(m * n) time and space.
+
+// One longest sequence of items appearing in both a and b in the same order
+// (not necessarily next to each other). "ABCBDAB" / "BDCABA" -> e.g. "BCBA".
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let (m, n) = (a.len(), b.len());
+    let mut table = vec![vec![0usize; n + 1]; m + 1];
+
+    for i in 1..=m {
+        for j in 1..=n {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1] + 1  // Extend the common subsequence
+            } else {
+                table[i - 1][j].max(table[i][j - 1])  // Drop one item
+            };
+        }
+    }
+
+    // Walk back from the bottom-right corner to recover the items
+    let mut result = Vec::with_capacity(table[m][n]);
+    let (mut i, mut j) = (m, n);
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    result.reverse();  // Collected back to front
+    result
+}
+
+// Levenshtein distance: the fewest single-character insertions, deletions
+// and substitutions that turn a into b. Compares chars, not bytes.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Only the previous row is needed, so keep two rows instead of a table.
+    // Row 0: turning "" into b[..j] takes j insertions.
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;  // Turning a[..i] into "" takes i deletions
+        for j in 1..=b.len() {
+            let substitution = prev[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let deletion = prev[j] + 1;
+            let insertion = curr[j - 1] + 1;
+            curr[j] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+// =============================================================================
+// UNION-FIND (DISJOINT SET UNION) - Which Group Is Each Item In?
+// =============================================================================
+// Each set is a tree stored in `parent`; a node whose parent is itself is
+// the ROOT and represents the whole set. Two items are in the same set
+// exactly when find() reaches the same root.
+//
+// Two tricks keep the trees almost flat, making every operation effectively
+// O(1) (amortised O(alpha(n)), where alpha grows absurdly slowly):
+// - union by rank: hang the SHORTER tree under the taller one
+// - path compression: after find(x), point every node on the way directly
+//   at the root
+//
+//   before find(3):  0 <- 1 <- 2 <- 3      after:  0 <- 1
+//                                                  0 <- 2
+//                                                  0 <- 3
+
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,  // Upper bound on tree height; only meaningful for roots
+}
+
+impl UnionFind {
+    // n singleton sets: {0}, {1}, ..., {n - 1}
+    pub fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n).collect(), rank: vec![0; n] }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        // First pass: walk up to the root
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Second pass: path compression
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    pub fn union(&mut self, x: usize, y: usize) {
+        let (root_x, root_y) = (self.find(x), self.find(y));
+        if root_x == root_y {
+            return;  // Already in the same set
+        }
+        match self.rank[root_x].cmp(&self.rank[root_y]) {
+            Ordering::Less => self.parent[root_x] = root_y,
+            Ordering::Greater => self.parent[root_y] = root_x,
+            Ordering::Equal => {
+                // Equal heights: the merged tree is one level taller
+                self.parent[root_y] = root_x;
+                self.rank[root_x] += 1;
+            }
+        }
+    }
+
+    pub fn same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    // One root per set
+    pub fn count_sets(&self) -> usize {
+        self.parent.iter().enumerate().filter(|&(i, &p)| i == p).count()
+    }
+}
+
+// =============================================================================
+// SEGMENT TREE - Range Queries and Point Updates in O(log n)
+// =============================================================================
+// Answers "combine everything in data[l..=r]" for any associative combine:
+// sum, min, max, gcd... Stored as a flat array of 2n entries (iterative,
+// bottom-up layout):
+//
+//   tree[n + i] = data[i]                              the leaves
+//   tree[i]     = combine(tree[2i], tree[2i + 1])      internal nodes
+//
+// update() changes one leaf and recomputes its ancestors: log n steps.
+// query() climbs from both ends of the range towards the root, picking up
+// every node that lies fully inside [l, r]: also log n steps.
+//
+// tree[0] is never used; T::default() just fills the slot. The query never
+// uses default() as a starting value, because it is not an identity for
+// every combine (0 is wrong for min).
+
+pub struct SegmentTree<T: Clone + Default> {
+    tree: Vec<T>,
+    n: usize,
+    combine: fn(&T, &T) -> T,
+}
+
+impl<T: Clone + Default> SegmentTree<T> {
+    pub fn build(data: &[T], combine: fn(&T, &T) -> T) -> Self {
+        let n = data.len();
+        let mut tree = vec![T::default(); n];
+        tree.extend_from_slice(data);
+        for i in (1..n).rev() {
+            tree[i] = combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        SegmentTree { tree, n, combine }
+    }
+
+    pub fn update(&mut self, pos: usize, val: T) {
+        assert!(pos < self.n, "position {} out of bounds", pos);
+        let mut i = pos + self.n;
+        self.tree[i] = val;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = (self.combine)(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    // Combines data[l..=r] (inclusive)
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(l <= r && r < self.n, "invalid range [{}, {}]", l, r);
+        // Separate accumulators for the left and right edges keep elements
+        // in order, so non-commutative combines work too
+        let mut left_acc: Option<T> = None;
+        let mut right_acc: Option<T> = None;
+        let (mut lo, mut hi) = (l + self.n, r + self.n + 1);  // Half-open [lo, hi)
+        while lo < hi {
+            if lo % 2 == 1 {
+                // lo is a right child: its parent would overshoot the range
+                left_acc = Some(match left_acc {
+                    Some(acc) => (self.combine)(&acc, &self.tree[lo]),
+                    None => self.tree[lo].clone(),
+                });
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                right_acc = Some(match right_acc {
+                    Some(acc) => (self.combine)(&self.tree[hi], &acc),
+                    None => self.tree[hi].clone(),
+                });
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+        match (left_acc, right_acc) {
+            (Some(a), Some(b)) => (self.combine)(&a, &b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => unreachable!("a non-empty range covers at least one node"),
+        }
+    }
+}
+
+// =============================================================================
+// FENWICK TREE (BINARY INDEXED TREE) - Prefix Sums in O(log n)
+// =============================================================================
+// A plain array makes updates O(1) but prefix sums O(n); a prefix-sum array
+// is the reverse. A Fenwick tree makes both O(log n) in a single Vec.
+//
+// Indices are 1-based. tree[i] holds the sum of the `lowbit(i)` elements
+// ending at i, where lowbit(i) = i & i.wrapping_neg() is i's lowest set bit:
+//
+//   tree[4] = a[1..=4]     tree[6] = a[5..=6]     tree[7] = a[7]
+//
+// prefix_sum(i) strips the lowest bit each step (7 -> 6 -> 4 -> 0);
+// update(i) adds it each step, reaching every range that covers i
+// (3 -> 4 -> 8 -> 16 ...).
+
+pub struct FenwickTree {
+    tree: Vec<i64>,  // tree[0] is unused
+    n: usize,
+}
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl FenwickTree {
+    // n elements, all zero
+    pub fn new(n: usize) -> Self {
+        FenwickTree { tree: vec![0; n + 1], n }
+    }
+
+    // a[i] += delta (1-indexed)
+    pub fn update(&mut self, i: usize, delta: i64) {
+        assert!(i >= 1 && i <= self.n, "index {} out of bounds 1..={}", i, self.n);
+        let mut i = i;
+        while i <= self.n {
+            self.tree[i] += delta;
+            i += lowbit(i);
+        }
+    }
+
+    // a[1] + ... + a[i]; prefix_sum(0) is the empty sum, 0
+    pub fn prefix_sum(&self, i: usize) -> i64 {
+        assert!(i <= self.n, "index {} out of bounds 0..={}", i, self.n);
+        let mut i = i;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    // a[l] + ... + a[r] (1-indexed, inclusive)
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        assert!(l >= 1 && l <= r, "invalid range [{}, {}]", l, r);
+        self.prefix_sum(r) - self.prefix_sum(l - 1)
+    }
+}
+
+// =============================================================================
+// STACK - LIFO, and the Standard Collection Traits
+// =============================================================================
+// The stack itself is just a Vec whose END is the top. The interesting part
+// is the trait impls that make it feel like a std collection:
+//
+//   FromIterator<T>  -> iter.collect::<Stack<_>>()   pushes in iteration order
+//   Extend<T>        -> stack.extend([4, 5])         pushes onto the top
+//   From<Vec<T>>     -> Stack::from(v)               v's last item is the top
+//   From<Stack<T>>   -> Vec::from(stack)             top FIRST (pop order)
+//
+// None of them need T: Clone - the items are moved, never copied.
+
+#[derive(Debug)]
+pub struct Stack<T> {
+    items: Vec<T>,  // items.last() is the top
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Stack::new();
+        stack.extend(iter);
+        stack
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Stack<T> {
+    fn from(items: Vec<T>) -> Self {
+        Stack { items }  // Same layout: no copying at all
+    }
+}
+
+impl<T> From<Stack<T>> for Vec<T> {
+    fn from(stack: Stack<T>) -> Self {
+        let mut items = stack.items;
+        items.reverse();  // Top first, the order pop() would return them
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_search_middle_first_last() {
+        let data = [1, 3, 5, 7, 9];
+        assert_eq!(binary_search(&data, &5), Some(2));
+        assert_eq!(binary_search(&data, &1), Some(0));
+        assert_eq!(binary_search(&data, &9), Some(4));
+    }
+
+    #[test]
+    fn test_binary_search_duplicates() {
+        let data = [1, 2, 2, 2, 3];
+        let index = binary_search(&data, &2).unwrap();
+        assert!((1..=3).contains(&index));
+        assert_eq!(data[index], 2);
+    }
+
+    #[test]
+    fn test_binary_search_absent() {
+        let data = ["apple", "banana", "cherry"];
+        assert_eq!(binary_search(&data, &"blueberry"), None);
+        assert_eq!(binary_search(&data, &"aardvark"), None);  // Before the start
+        assert_eq!(binary_search(&data, &"zucchini"), None);  // After the end
+        assert_eq!(binary_search(&[] as &[i32], &1), None);
+    }
+
+    #[test]
+    fn test_merge_sort_empty_and_single() {
+        assert_eq!(merge_sort::<i32>(&[]), Vec::<i32>::new());
+        assert_eq!(merge_sort(&[42]), vec![42]);
+    }
+
+    #[test]
+    fn test_merge_sort_sorted_and_reversed() {
+        assert_eq!(merge_sort(&[1, 2, 3, 4, 5]), vec![1, 2, 3, 4, 5]);
+        assert_eq!(merge_sort(&[5, 4, 3, 2, 1]), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_merge_sort_duplicates() {
+        // 7 elements: the last run at each width is shorter than the rest
+        assert_eq!(merge_sort(&[3, 1, 3, 2, 1, 3, 2]), vec![1, 1, 2, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_merge_sort_is_stable() {
+        // Compare only the number; the letters show the original order
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Item(u32, char);
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let sorted = merge_sort(&[Item(2, 'a'), Item(1, 'b'), Item(2, 'c'), Item(1, 'd')]);
+        assert_eq!(sorted, vec![Item(1, 'b'), Item(1, 'd'), Item(2, 'a'), Item(2, 'c')]);
+    }
+
+    // Builds an adjacency list from directed (from, to, cost) edges
+    fn graph(edges: &[(usize, usize, u64)]) -> HashMap<usize, Vec<(usize, u64)>> {
+        let mut graph: HashMap<usize, Vec<(usize, u64)>> = HashMap::new();
+        for &(from, to, cost) in edges {
+            graph.entry(from).or_default().push((to, cost));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_dijkstra_shortest_path() {
+        //      0 --7--> 1 --1--> 4
+        //      |        ^        ^
+        //      2        1        |
+        //      v        |        |
+        //      2 --4--> 3 --9----+
+        let g = graph(&[(0, 1, 7), (0, 2, 2), (2, 3, 4), (3, 1, 1), (1, 4, 1), (3, 4, 9)]);
+        let paths = dijkstra(&g, 0);
+
+        assert_eq!(paths[&0], (0, vec![0]));
+        assert_eq!(paths[&1], (7, vec![0, 1]));  // Tie: 0->2->3->1 also costs 7
+        assert_eq!(paths[&4], (8, vec![0, 1, 4]));
+        assert_eq!(paths[&3], (6, vec![0, 2, 3]));
+    }
+
+    #[test]
+    fn test_dijkstra_cheaper_detour() {
+        let g = graph(&[(0, 1, 10), (0, 2, 1), (2, 3, 1), (3, 1, 1), (1, 4, 2)]);
+        let paths = dijkstra(&g, 0);
+        assert_eq!(paths[&4], (5, vec![0, 2, 3, 1, 4]));
+    }
+
+    #[test]
+    fn test_dijkstra_disconnected() {
+        let g = graph(&[(0, 1, 3), (2, 3, 1)]);
+        let paths = dijkstra(&g, 0);
+        assert_eq!(paths.len(), 2);
+        assert!(!paths.contains_key(&3));
+        assert!(!paths.contains_key(&2));
+    }
+
+    #[test]
+    fn test_dijkstra_zero_weight_edge() {
+        let g = graph(&[(0, 1, 0), (1, 2, 5), (0, 2, 6)]);
+        let paths = dijkstra(&g, 0);
+        assert_eq!(paths[&1], (0, vec![0, 1]));
+        assert_eq!(paths[&2], (5, vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_failure_function() {
+        assert_eq!(failure_function(b"abab"), vec![0, 0, 1, 2]);
+        assert_eq!(failure_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(failure_function(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_kmp_single_and_multiple() {
+        assert_eq!(kmp_search(b"hello world", b"world"), vec![6]);
+        assert_eq!(kmp_search(b"abcXabcXabc", b"abc"), vec![0, 4, 8]);
+    }
+
+    #[test]
+    fn test_kmp_overlapping() {
+        assert_eq!(kmp_search(b"aaa", b"aa"), vec![0, 1]);
+        assert_eq!(kmp_search(b"abababa", b"aba"), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_kmp_no_match_and_edge_cases() {
+        assert_eq!(kmp_search(b"hello", b"xyz"), Vec::<usize>::new());
+        assert_eq!(kmp_search(b"abc", b""), vec![0, 1, 2]);
+        assert_eq!(kmp_search(b"ab", b"abc"), Vec::<usize>::new());  // Pattern longer than text
+    }
+
+    // True if `sub` appears in `seq` in order
+    fn is_subsequence<T: Eq>(sub: &[T], seq: &[T]) -> bool {
+        let mut seq = seq.iter();
+        sub.iter().all(|item| seq.any(|x| x == item))
+    }
+
+    #[test]
+    fn test_lcs_identical_and_disjoint() {
+        assert_eq!(lcs(&[1, 2, 3], &[1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(lcs(&[1, 2, 3], &[4, 5, 6]), Vec::<i32>::new());
+        assert_eq!(lcs::<i32>(&[], &[1]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_lcs_classic_example() {
+        let a: Vec<char> = "ABCBDAB".chars().collect();
+        let b: Vec<char> = "BDCABA".chars().collect();
+        let common = lcs(&a, &b);
+        assert_eq!(common.len(), 4);  // Several answers exist, e.g. BCBA, BDAB
+        assert!(is_subsequence(&common, &a));
+        assert!(is_subsequence(&common, &b));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("cat", "cats"), 1);  // Insertion
+        assert_eq!(edit_distance("cats", "cat"), 1);  // Deletion
+        assert_eq!(edit_distance("cat", "cut"), 1);   // Substitution
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("café", "cafe"), 1);  // Chars, not bytes
+    }
+
+    #[test]
+    fn test_union_find_counts_components() {
+        let mut sets = UnionFind::new(5);
+        assert_eq!(sets.count_sets(), 5);
+
+        sets.union(0, 1);
+        sets.union(1, 2);
+        sets.union(3, 4);
+        assert_eq!(sets.count_sets(), 2);  // {0, 1, 2} and {3, 4}
+
+        sets.union(2, 4);
+        assert_eq!(sets.count_sets(), 1);
+    }
+
+    #[test]
+    fn test_union_find_same_set() {
+        let mut sets = UnionFind::new(5);
+        sets.union(0, 1);
+        sets.union(1, 2);
+        sets.union(3, 4);
+
+        assert!(sets.same_set(0, 2));
+        assert!(sets.same_set(4, 3));
+        assert!(!sets.same_set(0, 3));
+        assert!(sets.same_set(1, 1));
+
+        sets.union(0, 0);  // No-op
+        assert_eq!(sets.count_sets(), 2);
+    }
+
+    #[test]
+    fn test_union_find_path_compression() {
+        let mut sets = UnionFind::new(4);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(0, 2);  // Root 2 goes under root 0, so 3 is two hops away
+        assert_eq!(sets.parent[3], 2);
+
+        let root = sets.find(3);
+        assert_eq!(sets.parent[3], root);  // Now points straight at the root
+    }
+
+    #[test]
+    fn test_segment_tree_sum_with_update() {
+        let mut sums = SegmentTree::build(&[2, 1, 5, 3, 4], |a, b| a + b);
+        assert_eq!(sums.query(1, 3), 9);
+        assert_eq!(sums.query(0, 4), 15);
+
+        sums.update(2, 10);
+        assert_eq!(sums.query(0, 4), 20);
+        assert_eq!(sums.query(2, 2), 10);
+    }
+
+    #[test]
+    fn test_segment_tree_min() {
+        let data = [2, 1, 5, 3, 4];
+        let mut mins = SegmentTree::build(&data, |a: &i32, b: &i32| *a.min(b));
+        // Compare every range against a brute-force minimum
+        for l in 0..data.len() {
+            for r in l..data.len() {
+                assert_eq!(mins.query(l, r), *data[l..=r].iter().min().unwrap(), "range [{}, {}]", l, r);
+            }
+        }
+        mins.update(1, 7);
+        assert_eq!(mins.query(0, 2), 2);
+        assert_eq!(mins.query(1, 4), 3);
+    }
+
+    #[test]
+    fn test_segment_tree_keeps_order() {
+        // String concatenation is associative but NOT commutative
+        let words: Vec<String> = ["a", "b", "c", "d", "e", "f"].iter().map(|s| s.to_string()).collect();
+        let tree = SegmentTree::build(&words, |a, b| format!("{a}{b}"));
+        assert_eq!(tree.query(0, 5), "abcdef");
+        assert_eq!(tree.query(1, 4), "bcde");
+    }
+
+    fn sample_fenwick() -> FenwickTree {
+        let data = [3, 2, -1, 6, 5, 4, -3, 3, 7, 2, 3];
+        let mut tree = FenwickTree::new(data.len());
+        for (i, &value) in data.iter().enumerate() {
+            tree.update(i + 1, value);  // 1-indexed
+        }
+        tree
+    }
+
+    #[test]
+    fn test_fenwick_prefix_sums() {
+        let tree = sample_fenwick();
+        assert_eq!(tree.prefix_sum(0), 0);
+        assert_eq!(tree.prefix_sum(5), 15);  // 3 + 2 - 1 + 6 + 5
+        assert_eq!(tree.prefix_sum(6), 19);
+        assert_eq!(tree.prefix_sum(11), 31);
+    }
+
+    #[test]
+    fn test_fenwick_update_and_range_sum() {
+        let mut tree = sample_fenwick();
+        tree.update(3, 2);  // -1 becomes 1
+        assert_eq!(tree.range_sum(2, 5), 14);  // 2 + 1 + 6 + 5
+        assert_eq!(tree.range_sum(3, 3), 1);
+        assert_eq!(tree.range_sum(1, 11), 33);
+    }
+
+    #[test]
+    fn test_stack_collect_pushes_in_order() {
+        let mut stack: Stack<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));  // 1 was pushed first, so it's deepest
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_stack_extend_adds_to_top() {
+        let mut stack: Stack<i32> = (1..=3).collect();
+        stack.extend([4, 5]);
+        assert_eq!(stack.peek(), Some(&5));
+        assert_eq!(Vec::from(stack), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_stack_vec_conversions() {
+        let stack = Stack::from(vec!["bottom", "middle", "top"]);
+        assert_eq!(stack.peek(), Some(&"top"));
+        assert_eq!(Vec::<&str>::from(stack), vec!["top", "middle", "bottom"]);
+
+        let empty: Stack<i32> = Stack::default();
+        assert_eq!(Vec::<i32>::from(empty), Vec::<i32>::new());
+    }
+}
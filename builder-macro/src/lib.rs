@@ -0,0 +1,266 @@
+// =============================================================================
+// #[derive(Builder)] - A PROCEDURAL MACRO
+// =============================================================================
+// A procedural macro is a Rust function that runs AT COMPILE TIME: it receives
+// the tokens of the item it is attached to and returns new tokens that get
+// compiled alongside it.
+//
+//   #[derive(Builder)]            generates   struct UserBuilder { ... }
+//   struct User { ... }           ------->    impl UserBuilder { setters, build() }
+//
+// Proc macros must live in their own crate with `proc-macro = true`. The
+// usual toolkit:
+// - syn   - parses tokens into a syntax tree (DeriveInput, Field, Type ...)
+// - quote - turns quasi-quoted Rust back into tokens (#var interpolates)
+//
+// Rules for the generated build():
+// - Option<T> fields may be left unset - they become None
+// - #[builder(default)] fields fall back to Default::default()
+// - any other unset field makes build() return Err
+// =============================================================================
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, GenericArgument, PathArguments, Type};
+
+// `attributes(builder)` registers #[builder(...)] as a helper attribute,
+// otherwise the compiler would reject it on the struct's fields
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    // Errors become compile_error!{...} so they point at the user's code
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+// Works on proc_macro2 types, which (unlike proc_macro) can be used outside
+// of a real macro invocation - that is what makes it unit-testable
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let builder_name = format_ident!("{}Builder", name);
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "Builder does not support generic structs"));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => return Err(syn::Error::new_spanned(name, "Builder requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "Builder can only be derived for structs")),
+    };
+
+    let mut builder_fields = Vec::new();
+    let mut setters = Vec::new();
+    let mut build_fields = Vec::new();
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields always have an ident");
+        let ty = &field.ty;
+
+        // Every builder field is an Option: None means "not set yet".
+        // Option<T> fields are stored as-is instead of Option<Option<T>>.
+        let (stored_ty, setter_ty, value) = match option_inner(ty) {
+            Some(inner) => (quote!(#ty), inner, quote!(self.#ident)),
+            None => {
+                let value = if has_default_attr(field)? {
+                    quote!(self.#ident.unwrap_or_default())
+                } else {
+                    let missing = format!("field `{}` was not set", ident);
+                    quote!(self.#ident.ok_or_else(|| ::std::string::String::from(#missing))?)
+                };
+                (quote!(::std::option::Option<#ty>), ty, value)
+            }
+        };
+
+        builder_fields.push(quote!(#ident: #stored_ty));
+        // impl Into lets callers pass "alice" for a String field
+        setters.push(quote! {
+            #vis fn #ident(mut self, value: impl ::std::convert::Into<#setter_ty>) -> Self {
+                self.#ident = ::std::option::Option::Some(value.into());
+                self
+            }
+        });
+        build_fields.push(quote!(#ident: #value));
+    }
+
+    // Paths are fully qualified (::std::...) so the generated code still
+    // compiles if the user's module shadows Option, String or Result
+    Ok(quote! {
+        #[derive(Default)]
+        #vis struct #builder_name {
+            #(#builder_fields,)*
+        }
+
+        impl #builder_name {
+            #(#setters)*
+
+            #vis fn build(self) -> ::std::result::Result<#name, ::std::string::String> {
+                ::std::result::Result::Ok(#name {
+                    #(#build_fields,)*
+                })
+            }
+        }
+
+        impl #name {
+            #vis fn builder() -> #builder_name {
+                ::std::default::Default::default()
+            }
+        }
+    })
+}
+
+// Option<String> -> Some(String); anything else -> None.
+// Macros only see tokens, not resolved types, so this is a best-effort
+// match on the last path segment (Option, std::option::Option, ...).
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+// Looks for #[builder(default)] on a field
+fn has_default_attr(field: &Field) -> syn::Result<bool> {
+    let mut default = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("builder") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    default = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `default`"))
+                }
+            })?;
+        }
+    }
+    Ok(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::{parse_quote, Item, ItemImpl, ItemStruct};
+
+    fn expand_to_file(input: DeriveInput) -> syn::File {
+        syn::parse2(expand(input).expect("expansion should succeed")).expect("output should be valid Rust")
+    }
+
+    fn builder_struct(file: &syn::File) -> &ItemStruct {
+        file.items
+            .iter()
+            .find_map(|item| match item {
+                Item::Struct(s) => Some(s),
+                _ => None,
+            })
+            .expect("a builder struct is generated")
+    }
+
+    fn builder_impl<'f>(file: &'f syn::File, builder_name: &str) -> &'f ItemImpl {
+        file.items
+            .iter()
+            .find_map(|item| match item {
+                Item::Impl(i) => {
+                    let self_ty = &i.self_ty;
+                    (quote!(#self_ty).to_string() == builder_name).then_some(i)
+                }
+                _ => None,
+            })
+            .expect("an impl block for the builder is generated")
+    }
+
+    #[test]
+    fn test_builder_struct_wraps_fields_in_option() {
+        let file = expand_to_file(parse_quote! {
+            struct User {
+                name: String,
+                nickname: Option<String>,
+            }
+        });
+        let builder = builder_struct(&file);
+        assert_eq!(builder.ident, "UserBuilder");
+
+        let types: Vec<String> = builder
+            .fields
+            .iter()
+            .map(|f| {
+                let ty = &f.ty;
+                quote!(#ty).to_string()
+            })
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                quote!(::std::option::Option<String>).to_string(),
+                quote!(Option<String>).to_string(),  // Not Option<Option<String>>
+            ]
+        );
+    }
+
+    #[test]
+    fn test_one_setter_per_field_plus_build() {
+        let file = expand_to_file(parse_quote! {
+            pub struct Config {
+                host: String,
+                port: u16,
+            }
+        });
+        let methods: Vec<String> = builder_impl(&file, "ConfigBuilder")
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(f) => Some(f.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(methods, vec!["host", "port", "build"]);
+        // Visibility is copied from the original struct
+        assert!(matches!(builder_struct(&file).vis, syn::Visibility::Public(_)));
+    }
+
+    #[test]
+    fn test_required_and_default_fields() {
+        let output = expand(parse_quote! {
+            struct User {
+                name: String,
+                #[builder(default)]
+                visits: u64,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(output.contains("\"field `name` was not set\""));
+        assert!(!output.contains("\"field `visits` was not set\""));
+        assert!(output.contains("unwrap_or_default"));
+    }
+
+    #[test]
+    fn test_rejects_non_structs() {
+        let err = expand(parse_quote! { enum Shape { Circle, Square } }).unwrap_err();
+        assert_eq!(err.to_string(), "Builder can only be derived for structs");
+
+        let err = expand(parse_quote! { struct Pair(i32, i32); }).unwrap_err();
+        assert_eq!(err.to_string(), "Builder requires a struct with named fields");
+    }
+
+    #[test]
+    fn test_rejects_unknown_builder_attribute() {
+        let err = expand(parse_quote! {
+            struct User {
+                #[builder(skip)]
+                name: String,
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "expected `default`");
+    }
+}
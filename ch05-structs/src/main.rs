@@ -10,6 +10,10 @@
 // 3. Unit structs (no fields)
 // =============================================================================
 
+use builder_macro::Builder;  // #[derive(Builder)] from the builder-macro crate
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
 fn main() {
     println!("=== Chapter 5: Using Structs ===\n");
 
@@ -17,17 +21,22 @@ fn main() {
     tuple_structs();
     methods_and_impl();
     associated_functions();
+    derived_builder();
+    conversion_traits();
+    ordering_by_area();
 }
 
 // =============================================================================
 // PART 1: DEFINING AND INSTANTIATING STRUCTS
 // =============================================================================
 
-#[derive(Debug)]
+#[derive(Debug, Default, PartialEq, Builder)]
 struct User {
     username: String,
     email: String,
+    #[builder(default)]  // 0 if not set
     sign_in_count: u64,
+    #[builder(default)]  // false if not set
     active: bool,
 }
 
@@ -111,7 +120,7 @@ fn tuple_structs() {
 // PART 3: METHODS WITH IMPL BLOCKS
 // =============================================================================
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]  // Equal when width AND height match
 struct Rectangle {
     width: u32,
     height: u32,
@@ -179,6 +188,153 @@ fn associated_functions() {
     println!();
 }
 
+// =============================================================================
+// PART 5: DERIVING A BUILDER WITH A PROCEDURAL MACRO
+// =============================================================================
+// #[derive(Builder)] on User (PART 1) generates a UserBuilder at compile time:
+//
+//   struct UserBuilder { username: Option<String>, email: Option<String>, ... }
+//   impl UserBuilder { fn username(self, ..) -> Self; ...; fn build(self) -> Result<User, String> }
+//
+// Handy when a struct has many fields and some have sensible defaults.
+// See builder-macro/src/lib.rs for how the code is generated.
+
+fn derived_builder() {
+    println!("--- Part 5: #[derive(Builder)] ---\n");
+
+    let user = UserBuilder::default()
+        .username("builder_user")  // &str works: setters take impl Into<String>
+        .email("builder@example.com")
+        .sign_in_count(1u64)  // Suffix needed: impl Into<u64> can't infer a literal
+        .active(true)
+        .build();
+    println!("Built: {:?}", user);
+
+    // email is required and has no default
+    let incomplete = User::builder().username("no_email").build();
+    println!("Missing field: {:?}", incomplete);
+
+    println!();
+}
+
+// =============================================================================
+// PART 6: DEFAULT, FROM, AND INTO
+// =============================================================================
+// Standard traits give a struct conventional constructors and conversions:
+// - Default        -> User::default(), and ..Default::default() in literals
+// - From<&str>     -> User::from("name:email")
+// - Into           -> FREE: std has `impl<T, U: From<T>> Into<U> for T`,
+//                     so From<&str> for User also gives "..".into()
+// - From<User> for String -> String::from(user) / user.into()
+
+// User derives Default (PART 1), which generates exactly what we'd write by
+// hand - every field's own default:
+//
+// impl Default for User {
+//     fn default() -> Self {
+//         User {
+//             username: String::new(),  // ""
+//             email: String::new(),     // ""
+//             sign_in_count: 0,
+//             active: false,
+//         }
+//     }
+// }
+//
+// Write the impl by hand only when some default differs from the field
+// type's default (clippy's derivable_impls lint points out the rest).
+
+// Parses "username:email". From can't fail, so input without a ':' becomes
+// a username with an empty email - use TryFrom when bad input must be
+// reported instead.
+impl From<&str> for User {
+    fn from(s: &str) -> Self {
+        let (username, email) = s.split_once(':').unwrap_or((s, ""));
+        User {
+            username: username.to_string(),
+            email: email.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+// CSV line: username,email,sign_in_count,active
+impl From<User> for String {
+    fn from(user: User) -> Self {
+        format!("{},{},{},{}", user.username, user.email, user.sign_in_count, user.active)
+    }
+}
+
+fn conversion_traits() {
+    println!("--- Part 6: Default, From, and Into ---\n");
+
+    println!("Default: {:?}", User::default());
+
+    let alice = User::from("alice:alice@example.com");
+    println!("From<&str>: {:?}", alice);
+
+    let bob: User = "bob:bob@example.com".into();  // Into comes from From
+    let csv: String = bob.into();
+    println!("Into<String>: {}", csv);
+
+    println!();
+}
+
+// =============================================================================
+// PART 7: ORDERING BY AREA - PartialOrd and Ord
+// =============================================================================
+// PartialOrd gives <, >, <=, >=; Ord gives a TOTAL order, which is what
+// .sort(), .max() and BTreeSet/BTreeMap need.
+//
+// WARNING: these impls are deliberately INCONSISTENT with the derived Eq.
+// 2x8 and 4x4 both have area 16, so:
+//   a == b                         -> false (different fields)
+//   a.cmp(&b) == Ordering::Equal   -> true  (same area)
+// std documents that Ord must agree with Eq. BTreeSet::insert treats
+// "Equal" as "the same value" and silently drops the second rectangle -
+// while collect() into a BTreeSet dedups with == and keeps both! Code that
+// breaks the rule gets whichever answer the std method happens to use.
+// Prefer sort_by_key(|r| r.area()) when you just need one particular order.
+//
+// When a type is Ord, partial_cmp should just wrap cmp, so the two can
+// never disagree (clippy: non_canonical_partial_ord_impl).
+
+impl Ord for Rectangle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.area().cmp(&other.area())
+    }
+}
+
+impl PartialOrd for Rectangle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn ordering_by_area() {
+    println!("--- Part 7: Ordering by Area ---\n");
+
+    let mut rects = vec![
+        Rectangle::new(10, 10),
+        Rectangle::new(2, 8),
+        Rectangle::new(30, 1),
+        Rectangle::square(4),
+    ];
+    rects.sort();  // Uses Ord
+    println!("Sorted by area: {:?}", rects);
+    println!("2x8 == 4x4? {}", Rectangle::new(2, 8) == Rectangle::square(4));
+    println!("2x8 cmp 4x4: {:?}", Rectangle::new(2, 8).cmp(&Rectangle::square(4)));
+
+    // insert() goes by Ord: 2x8 and 4x4 are "equal", so only one is kept
+    let mut set = BTreeSet::new();
+    for rect in rects {
+        set.insert(rect);
+    }
+    println!("BTreeSet keeps {} of 4: {:?}", set.len(), set);
+
+    println!();
+}
+
 // =============================================================================
 // KEY CONCEPTS SUMMARY
 // =============================================================================
@@ -197,8 +353,109 @@ fn associated_functions() {
 // | Method (mutable)   | &mut self       | instance.method()|
 // | Associated fn      | (none)          | Type::function() |
 //
+// CONVERSION TRAITS:
+// - Default         - Type::default() and ..Default::default()
+// - impl From<T>     - Type::from(t), and t.into() for free
+//
 // COMMON DERIVE TRAITS:
 // - #[derive(Debug)]    - Enable {:?} printing
 // - #[derive(Clone)]    - Enable .clone()
 // - #[derive(PartialEq)]- Enable == comparison
 // =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_required_fields() {
+        let user = UserBuilder::default().username("alice").email("x@y.com").build();
+        assert_eq!(
+            user,
+            Ok(User {
+                username: String::from("alice"),
+                email: String::from("x@y.com"),
+                sign_in_count: 0,
+                active: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_builder_missing_required_field() {
+        let result = UserBuilder::default().username("alice").build();
+        assert_eq!(result, Err(String::from("field `email` was not set")));
+    }
+
+    #[test]
+    fn test_builder_overrides_defaults() {
+        let user = User::builder()
+            .username("bob")
+            .email("bob@example.com")
+            .sign_in_count(3u64)
+            .active(true)
+            .build()
+            .unwrap();
+        assert_eq!(user.sign_in_count, 3);
+        assert!(user.active);
+    }
+
+    #[test]
+    fn test_user_default() {
+        let user = User::default();
+        assert_eq!(user.username, "");
+        assert_eq!(user.email, "");
+        assert_eq!(user.sign_in_count, 0);
+        assert!(!user.active);
+    }
+
+    #[test]
+    fn test_user_from_str() {
+        let user = User::from("alice:alice@example.com");
+        assert_eq!(user.username, "alice");
+        assert_eq!(user.email, "alice@example.com");
+        assert_eq!(User::from("no_colon").email, "");
+    }
+
+    #[test]
+    fn test_user_into_csv_string() {
+        let user = User { sign_in_count: 3, active: true, ..User::from("carol:c@example.com") };
+        assert_eq!(String::from(user), "carol,c@example.com,3,true");
+    }
+
+    #[test]
+    fn test_str_into_user() {
+        let user: User = "bob:bob@example.com".into();
+        assert_eq!(user, User::from("bob:bob@example.com"));
+        assert_eq!(user.email, "bob@example.com");
+    }
+
+    #[test]
+    fn test_same_area_is_ord_equal_but_not_eq() {
+        let wide = Rectangle::new(2, 8);
+        let square = Rectangle::square(4);
+        assert_eq!(wide.cmp(&square), Ordering::Equal);
+        assert_ne!(wide, square);
+        assert!(Rectangle::new(1, 1) < Rectangle::new(2, 1));
+    }
+
+    #[test]
+    fn test_btreeset_dedups_by_area() {
+        let mut set = BTreeSet::new();
+        assert!(set.insert(Rectangle::new(2, 8)));
+        assert!(!set.insert(Rectangle::square(4)));  // Same area: rejected
+        assert!(set.insert(Rectangle::new(1, 3)));
+        assert_eq!(set.len(), 2);
+        // The first rectangle with area 16 was kept; contains() also goes by Ord
+        assert!(set.contains(&Rectangle::new(16, 1)));
+        assert_eq!(set.iter().next_back(), Some(&Rectangle::new(2, 8)));
+    }
+
+    #[test]
+    fn test_sort_by_area() {
+        let mut rects = [Rectangle::new(5, 5), Rectangle::new(1, 2), Rectangle::new(3, 3)];
+        rects.sort();
+        let areas: Vec<u32> = rects.iter().map(Rectangle::area).collect();
+        assert_eq!(areas, vec![2, 9, 25]);
+    }
+}
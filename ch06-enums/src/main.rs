@@ -9,8 +9,12 @@
 // 2. Option<T> handles nullable values safely
 // 3. match must be exhaustive (handle all cases)
 // 4. if let provides concise single-pattern matching
+// 5. From/TryFrom convert between enums and raw values
 // =============================================================================
 
+use std::collections::VecDeque;
+use std::io::{BufRead, Cursor};
+
 fn main() {
     println!("=== Chapter 6: Enums and Pattern Matching ===\n");
 
@@ -19,6 +23,9 @@ fn main() {
     option_enum();
     match_expressions();
     if_let_syntax();
+    enum_conversions();
+    option_combinators_demo();
+    while_let_destructuring();
 }
 
 // =============================================================================
@@ -220,6 +227,220 @@ fn if_let_syntax() {
     println!();
 }
 
+// =============================================================================
+// PART 6: CONVERTING ENUMS WITH FROM AND TRYFROM
+// =============================================================================
+// Going enum -> u8 always works, so it's a From. Going u8 -> enum can fail
+// (what is Severity 7?), so it's a TryFrom with an error type.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl TryFrom<u8> for Severity {
+    type Error = u8; // Hand the rejected byte back to the caller
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            0 => Ok(Severity::Low),
+            1 => Ok(Severity::Medium),
+            2 => Ok(Severity::High),
+            3 => Ok(Severity::Critical),
+            other => Err(other),
+        }
+    }
+}
+
+impl From<Severity> for u8 {
+    fn from(s: Severity) -> u8 {
+        s as u8 // Fieldless enums cast to their discriminant: 0, 1, 2, 3
+    }
+}
+
+impl TryFrom<&str> for Severity {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        match name.to_lowercase().as_str() {
+            "low" => Ok(Severity::Low),
+            "medium" => Ok(Severity::Medium),
+            "high" => Ok(Severity::High),
+            "critical" => Ok(Severity::Critical),
+            _ => Err(format!("unknown severity: {name}")),
+        }
+    }
+}
+
+// The error types match (u8 in, u8 out), so ? can forward the failure as-is
+fn parse_severity(b: u8) -> Result<Severity, u8> {
+    let severity: Severity = b.try_into()?;
+    Ok(severity)
+}
+
+fn enum_conversions() {
+    println!("--- Part 6: From and TryFrom ---\n");
+
+    let high = Severity::try_from(2u8);
+    println!("TryFrom<u8>(2): {:?}", high);
+    println!("TryFrom<u8>(9): {:?}", Severity::try_from(9u8));
+
+    let byte: u8 = Severity::Critical.into();
+    println!("Critical as u8: {byte}");
+
+    println!("TryFrom<&str>(\"MeDiUm\"): {:?}", Severity::try_from("MeDiUm"));
+    println!("TryFrom<&str>(\"severe\"): {:?}", Severity::try_from("severe"));
+
+    for b in [1, 4] {
+        match parse_severity(b) {
+            Ok(s) => println!("parse_severity({b}) -> {:?}", s),
+            Err(bad) => println!("parse_severity({b}) -> rejected byte {bad}"),
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 7: OPTION COMBINATORS - Chaining Instead of Matching
+// =============================================================================
+// Each combinator is a small, named match. The rule of thumb: None flows
+// straight through (short-circuits), and only a Some value ever reaches
+// your closure.
+
+// checked_div is None for a zero divisor (and for i32::MIN / -1)
+fn safe_div(n: i32, d: i32) -> Option<i32> {
+    n.checked_div(d)
+}
+
+// Stand-in for something costly, like a cache miss hitting the disk
+fn expensive_default() -> Option<i32> {
+    println!("    (or_else closure ran)");
+    Some(0)
+}
+
+fn option_combinators_demo() {
+    println!("--- Part 7: Option Combinators ---\n");
+
+    let some: Option<i32> = Some(20);
+    let none: Option<i32> = None;
+
+    // and_then: the closure itself returns an Option, so failures chain
+    println!("and_then:  {:?} {:?} {:?}",
+        some.and_then(|n| safe_div(n, 4)),   // Some(5)
+        some.and_then(|n| safe_div(n, 0)),   // None - divisor was zero
+        none.and_then(|n| safe_div(n, 4)));  // None - closure never ran
+
+    // or_else: the fallback closure only runs when needed (LAZY)
+    println!("or_else on Some:");
+    println!("    {:?}", some.or_else(expensive_default));  // No "ran" line
+    println!("or_else on None:");
+    println!("    {:?}", none.or_else(expensive_default));
+
+    // filter: keep the value only if the predicate holds
+    println!("filter:    {:?} {:?} {:?}",
+        some.filter(|n| n % 2 == 0),   // Some(20)
+        some.filter(|n| *n > 100),     // None - predicate failed
+        none.filter(|n| n % 2 == 0));  // None
+
+    // flatten: Option<Option<T>> -> Option<T>
+    let nested: [Option<Option<i32>>; 3] = [Some(Some(1)), Some(None), None];
+    println!("flatten:   {:?}", nested.map(Option::flatten));  // [Some(1), None, None]
+
+    // zip: Some only if BOTH are Some
+    let name = Some("Ada");
+    println!("zip:       {:?} {:?}", some.zip(name), none.zip(name));
+
+    // unzip: Option<(A, B)> -> (Option<A>, Option<B>)
+    let pair: Option<(i32, &str)> = Some((36, "Ada"));
+    let no_pair: Option<(i32, &str)> = None;
+    println!("unzip:     {:?} {:?}", pair.unzip(), no_pair.unzip());
+
+    // transpose: Option<Result<T, E>> <-> Result<Option<T>, E>
+    // "an optional field that, if present, must parse"
+    let parse = |s: Option<&str>| s.map(str::parse::<i32>).transpose();
+    println!("transpose: {:?} {:?} {:?}", parse(Some("42")), parse(Some("4x2")), parse(None));
+
+    // ok_or / ok_or_else: give None an error so ? can be used
+    println!("ok_or:     {:?} {:?}", some.ok_or("missing"), none.ok_or("missing"));
+    // ok_or_else builds the error lazily - use it when that costs something
+    println!("ok_or_else: {:?}", none.ok_or_else(|| format!("no value at {}", "row 3")));
+
+    println!();
+}
+
+// =============================================================================
+// PART 8: WHILE LET WITH NESTED PATTERNS
+// =============================================================================
+// `while let PATTERN = expr` loops until the pattern FAILS to match. That
+// makes the choice of pattern important: put too much in it and one odd
+// item ends the whole loop instead of being skipped.
+
+// Returns (how many scores were counted, their total)
+fn tally_scores(mut entries: Vec<Option<(String, i32)>>) -> (usize, i32) {
+    let (mut counted, mut total) = (0, 0);
+    // NOT `while let Some(Some((name, score))) = entries.pop()` - that would
+    // stop at the first None. Match the outer layer only, then skip.
+    while let Some(entry) = entries.pop() {
+        let Some((name, score)) = entry else { continue };
+        println!("  {name} scored {score}");
+        counted += 1;
+        total += score;
+    }
+    (counted, total)
+}
+
+// read_line returns Ok(bytes read), and Ok(0) means EOF. The range pattern
+// `Ok(1..)` ends the loop at EOF AND on an I/O error, in one place.
+fn read_all_lines(reader: &mut Cursor<&[u8]>) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut buf = String::new();
+    while let Ok(1..) = reader.read_line(&mut buf) {
+        lines.push(buf.trim_end().to_string());
+        buf.clear();  // read_line APPENDS, so reset between lines
+    }
+    lines
+}
+
+// Returns (sum of the Ok values, how many Err items were skipped)
+fn sum_ok_items(mut queue: VecDeque<Result<i32, String>>) -> (i32, usize) {
+    let (mut sum, mut skipped) = (0, 0);
+    while let Some(item) = queue.pop_front() {
+        match item {
+            Ok(n) => sum += n,
+            Err(e) => {
+                println!("  skipping bad item: {e}");
+                skipped += 1;
+            }
+        }
+    }
+    (sum, skipped)
+}
+
+fn while_let_destructuring() {
+    println!("--- Part 8: while let with Destructuring ---\n");
+
+    let entries = vec![
+        Some((String::from("Ann"), 90)),
+        None,  // Skipped, and the loop keeps going
+        Some((String::from("Bo"), 75)),
+    ];
+    let (counted, total) = tally_scores(entries);
+    println!("counted {counted} scores, total {total}");
+
+    let mut reader = Cursor::new("first\nsecond\nthird".as_bytes());
+    println!("lines: {:?}", read_all_lines(&mut reader));
+
+    let queue = VecDeque::from([Ok(10), Err(String::from("NaN")), Ok(5)]);
+    let (sum, skipped) = sum_ok_items(queue);
+    println!("sum of Ok items: {sum} ({skipped} skipped)");
+
+    println!();
+}
+
 // =============================================================================
 // KEY CONCEPTS SUMMARY
 // =============================================================================
@@ -247,4 +468,167 @@ fn if_let_syntax() {
 // | Variant(x)       | Extract inner value                  |
 // | other            | Catch-all, binds value               |
 // | _                | Catch-all, ignores value             |
+// | Ok(1..)          | Nested range pattern                 |
+//
+// CONVERSIONS:
+// | Trait            | Use when                             |
+// |------------------|--------------------------------------|
+// | From<T>          | Conversion can never fail            |
+// | TryFrom<T>       | Some inputs are invalid (Result)     |
+//
+// OPTION COMBINATORS (None always passes straight through):
+// | Method           | Some(x) becomes                      |
+// |------------------|--------------------------------------|
+// | and_then(f)      | f(x), which is itself an Option      |
+// | or_else(f)       | Some(x) - f only runs for None       |
+// | filter(p)        | Some(x) if p(&x), else None          |
+// | zip(other)       | Some((x, y)) if other is Some(y)     |
+// | ok_or(e)         | Ok(x) - None becomes Err(e)          |
 // =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_from_valid_bytes() {
+        assert_eq!(Severity::try_from(0u8), Ok(Severity::Low));
+        assert_eq!(Severity::try_from(1u8), Ok(Severity::Medium));
+        assert_eq!(Severity::try_from(2u8), Ok(Severity::High));
+        assert_eq!(Severity::try_from(3u8), Ok(Severity::Critical));
+    }
+
+    #[test]
+    fn test_severity_out_of_range_byte() {
+        assert_eq!(Severity::try_from(4u8), Err(4));
+        assert_eq!(parse_severity(255), Err(255));
+    }
+
+    #[test]
+    fn test_severity_round_trips_through_u8() {
+        for b in 0..=3u8 {
+            let s = parse_severity(b).unwrap();
+            assert_eq!(u8::from(s), b);
+        }
+    }
+
+    #[test]
+    fn test_severity_from_names_any_case() {
+        assert_eq!(Severity::try_from("low"), Ok(Severity::Low));
+        assert_eq!(Severity::try_from("MEDIUM"), Ok(Severity::Medium));
+        assert_eq!(Severity::try_from("High"), Ok(Severity::High));
+        assert_eq!(Severity::try_from("cRiTiCaL"), Ok(Severity::Critical));
+    }
+
+    #[test]
+    fn test_severity_invalid_name() {
+        assert_eq!(
+            Severity::try_from("urgent"),
+            Err(String::from("unknown severity: urgent"))
+        );
+    }
+
+    #[test]
+    fn test_and_then_safe_division() {
+        assert_eq!(Some(20).and_then(|n| safe_div(n, 4)), Some(5));
+        assert_eq!(Some(20).and_then(|n| safe_div(n, 0)), None);
+        assert_eq!(None.and_then(|n| safe_div(n, 4)), None);
+    }
+
+    #[test]
+    fn test_or_else_is_lazy() {
+        let mut calls = 0;
+        let mut fallback = || {
+            calls += 1;
+            Some(0)
+        };
+        assert_eq!(Some(7).or_else(&mut fallback), Some(7));
+        assert_eq!(None.or_else(&mut fallback), Some(0));
+        assert_eq!(calls, 1);  // Only the None case ran it
+    }
+
+    #[test]
+    fn test_filter_and_flatten() {
+        assert_eq!(Some(4).filter(|n| n % 2 == 0), Some(4));
+        assert_eq!(Some(3).filter(|n| n % 2 == 0), None);
+        assert_eq!(None::<i32>.filter(|n| n % 2 == 0), None);
+
+        assert_eq!(Some(Some(1)).flatten(), Some(1));
+        assert_eq!(Some(None::<i32>).flatten(), None);
+        assert_eq!(None::<Option<i32>>.flatten(), None);
+    }
+
+    #[test]
+    fn test_zip_and_unzip() {
+        assert_eq!(Some(1).zip(Some('a')), Some((1, 'a')));
+        assert_eq!(Some(1).zip(None::<char>), None);
+        assert_eq!(None::<i32>.zip(Some('a')), None);
+
+        assert_eq!(Some((1, 'a')).unzip(), (Some(1), Some('a')));
+        assert_eq!(None::<(i32, char)>.unzip(), (None, None));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let ok: Option<Result<i32, String>> = Some(Ok(5));
+        let err: Option<Result<i32, String>> = Some(Err(String::from("bad")));
+        let none: Option<Result<i32, String>> = None;
+        assert_eq!(ok.transpose(), Ok(Some(5)));
+        assert_eq!(err.transpose(), Err(String::from("bad")));
+        assert_eq!(none.transpose(), Ok(None));  // Absent is not an error
+    }
+
+    #[test]
+    fn test_ok_or_and_ok_or_else() {
+        assert_eq!(Some(1).ok_or("missing"), Ok(1));
+        assert_eq!(None::<i32>.ok_or("missing"), Err("missing"));
+
+        let mut built = false;
+        let result: Result<i32, String> = Some(1).ok_or_else(|| {
+            built = true;
+            String::from("missing")
+        });
+        assert_eq!(result, Ok(1));
+        assert!(!built);  // Error never constructed for Some
+        assert_eq!(None::<i32>.ok_or_else(|| String::from("missing")), Err(String::from("missing")));
+    }
+
+    #[test]
+    fn test_tally_scores_skips_none() {
+        let entries = vec![
+            None,
+            Some((String::from("a"), 10)),
+            None,
+            Some((String::from("b"), 20)),
+            Some((String::from("c"), 30)),
+            None,  // Popped FIRST - must not end the loop
+        ];
+        assert_eq!(tally_scores(entries), (3, 60));
+        assert_eq!(tally_scores(vec![None, None]), (0, 0));
+    }
+
+    #[test]
+    fn test_read_all_lines_stops_at_eof() {
+        let mut reader = Cursor::new("one\ntwo\r\nthree\n".as_bytes());
+        assert_eq!(read_all_lines(&mut reader), vec!["one", "two", "three"]);
+        // Already at EOF: a second pass reads nothing
+        assert!(read_all_lines(&mut reader).is_empty());
+
+        let mut empty = Cursor::new("".as_bytes());
+        assert_eq!(read_all_lines(&mut empty).len(), 0);
+    }
+
+    #[test]
+    fn test_sum_ok_items_skips_errors() {
+        let queue = VecDeque::from([
+            Err(String::from("x")),
+            Ok(1),
+            Err(String::from("y")),
+            Ok(2),
+            Ok(3),
+            Err(String::from("z")),
+        ]);
+        assert_eq!(sum_ok_items(queue), (6, 3));
+        assert_eq!(sum_ok_items(VecDeque::new()), (0, 0));
+    }
+}
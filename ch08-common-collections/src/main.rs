@@ -0,0 +1,1384 @@
+// =============================================================================
+// CHAPTER 8: COMMON COLLECTIONS - Vec, String, HashMap
+// =============================================================================
+// Collections store multiple values on the HEAP, so their size can grow or
+// shrink at runtime (unlike arrays and tuples, whose size is fixed).
+//
+// The three you'll use constantly:
+// 1. Vec<T>          - a growable list of values of the same type
+// 2. String          - a growable, UTF-8 encoded piece of text
+// 3. HashMap<K, V>   - a mapping from keys to values
+// =============================================================================
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use unicode_segmentation::UnicodeSegmentation;  // External crate for graphemes
+
+// =============================================================================
+// map! - A HashMap LITERAL MACRO
+// =============================================================================
+// vec![1, 2, 3] exists for vectors, but std has no equivalent for HashMap.
+// macro_rules! lets us write one:
+//
+//   $( ... ),*   repeat the pattern, separated by commas
+//   $(,)?        allow an optional trailing comma
+//
+// Keys go through .to_string(), so "blue" and String::from("blue") can be
+// mixed freely - the result is always a HashMap<String, V>.
+//
+// A macro must be defined BEFORE it is used in the file, so it lives up here.
+
+macro_rules! map {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]  // map! {} never inserts anything
+        let mut map = HashMap::new();
+        $(
+            map.insert($key.to_string(), $value);
+        )*
+        map
+    }};
+}
+
+fn main() {
+    println!("=== Chapter 8: Common Collections ===\n");
+
+    vectors();
+    strings();
+    hash_maps();
+    ordered_collections();
+    double_ended_queues();
+    hash_sets();
+    prefix_tree();
+    bloom_filter();
+    graph_traversal();
+    rope();
+    grid_iteration();
+    result_pipeline_demo();
+}
+
+// =============================================================================
+// PART 1: VECTORS - Vec<T>
+// =============================================================================
+
+fn vectors() {
+    println!("--- Part 1: Vectors ---\n");
+
+    // Creating: Vec::new() needs a type annotation until something is pushed
+    let mut v: Vec<i32> = Vec::new();
+    v.push(5);
+    v.push(6);
+
+    // vec! macro infers the type from the values
+    let mut numbers = vec![1, 2, 3, 4, 5];
+
+    // Two ways to read an element:
+    let third: &i32 = &numbers[2];         // Panics if out of bounds
+    println!("The third element is {third}");
+
+    match numbers.get(10) {                 // Returns Option<&T> instead
+        Some(n) => println!("The eleventh element is {n}"),
+        None => println!("There is no eleventh element."),
+    }
+
+    // Pushing may reallocate, so it can't happen while `third` borrows the
+    // vector - the borrow ended at its last use above
+    numbers.push(6);
+    println!("Numbers: {:?}", numbers);
+
+    // Iterating mutably - * dereferences to change the value in place
+    for value in &mut v {
+        *value += 50;
+    }
+    println!("After adding 50: {:?}", v);
+
+    // A Vec holds ONE type - use an enum to store different kinds of values
+    enum SpreadsheetCell {
+        Int(i32),
+        Float(f64),
+        Text(String),
+    }
+
+    let row = vec![
+        SpreadsheetCell::Int(3),
+        SpreadsheetCell::Text(String::from("blue")),
+        SpreadsheetCell::Float(10.12),
+    ];
+    for cell in &row {
+        match cell {
+            SpreadsheetCell::Int(i) => println!("Int cell: {i}"),
+            SpreadsheetCell::Float(f) => println!("Float cell: {f}"),
+            SpreadsheetCell::Text(t) => println!("Text cell: {t}"),
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 2: STRINGS - UTF-8 Encoded Text
+// =============================================================================
+// String is a Vec<u8> that is guaranteed to hold valid UTF-8.
+// &str is a borrowed view ("string slice") into some UTF-8 bytes.
+
+fn strings() {
+    println!("--- Part 2: Strings ---\n");
+
+    let mut s = String::from("foo");
+    s.push_str("bar");   // Append a &str
+    s.push('!');         // Append a single char
+    println!("{s}");
+
+    // + takes ownership of the left side: fn add(self, s: &str) -> String
+    let s1 = String::from("Hello, ");
+    let s2 = String::from("world");
+    let s3 = s1 + &s2;   // s1 is moved here and can no longer be used
+    println!("{s3}");
+
+    // format! borrows everything and is easier to read for many pieces
+    let tic_tac_toe = format!("{}-{}-{}", "tic", "tac", "toe");
+    println!("{tic_tac_toe}");
+
+    // Strings can't be indexed with s[0]: one "character" may be several
+    // bytes. There are three ways to look at the same text:
+    let hindi = "नमस्ते";
+    println!("bytes:     {}", hindi.len());
+    println!("chars:     {:?}", hindi.chars().collect::<Vec<_>>());
+    println!("graphemes: {:?}", hindi.graphemes(true).collect::<Vec<_>>());
+
+    println!();
+}
+
+// =============================================================================
+// PART 3: HASH MAPS - HashMap<K, V>
+// =============================================================================
+
+fn hash_maps() {
+    println!("--- Part 3: Hash Maps ---\n");
+
+    // Without the macro this takes one insert() call per entry:
+    //   let mut scores = HashMap::new();
+    //   scores.insert(String::from("Blue"), 10);
+    //   scores.insert(String::from("Yellow"), 50);
+    let mut scores = map! {
+        "Blue" => 10,
+        "Yellow" => 50,
+    };
+
+    // get() returns Option<&V>; copied() turns it into Option<i32>
+    let team_name = String::from("Blue");
+    let score = scores.get(&team_name).copied().unwrap_or(0);
+    println!("Blue: {score}");
+
+    // Overwriting: insert() with an existing key replaces the value
+    scores.insert(String::from("Blue"), 25);
+
+    // entry().or_insert() only inserts if the key is missing
+    scores.entry(String::from("Yellow")).or_insert(50);
+    scores.entry(String::from("Red")).or_insert(0);
+
+    // Sort the keys - HashMap iteration order is unspecified
+    let mut teams: Vec<_> = scores.iter().collect();
+    teams.sort();
+    println!("Scores: {:?}", teams);
+
+    // Updating based on the old value: or_insert() returns &mut V
+    let text = "hello world wonderful world";
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        let count = counts.entry(word).or_insert(0);
+        *count += 1;
+    }
+    println!("\"world\" appears {} times", counts["world"]);
+
+    println!();
+}
+
+// =============================================================================
+// PART 4: ORDERED COLLECTIONS - BTreeMap and BTreeSet
+// =============================================================================
+// HashMap/HashSet: O(1) lookups, but iteration order is unspecified.
+// BTreeMap/BTreeSet: O(log n) lookups, but keys are always kept SORTED -
+// so iteration is ordered and range queries come for free.
+
+const POEM: &str = "hello world
+the world is wide
+hello again my friend
+a wide and wonderful world";
+
+// Splits text into lowercase words with surrounding punctuation removed
+fn words(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|word| !word.is_empty())
+}
+
+// Inverted index: word -> the (1-based) line numbers it appears on.
+// This is how search engines find documents containing a word.
+fn inverted_index(text: &str) -> BTreeMap<String, Vec<usize>> {
+    let mut index: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, line) in text.lines().enumerate() {
+        for word in words(line) {
+            let lines = index.entry(word).or_default();
+            // A word used twice on one line is only recorded once
+            if lines.last() != Some(&(i + 1)) {
+                lines.push(i + 1);
+            }
+        }
+    }
+    index
+}
+
+// All indexed words in [from, to). range() walks only that slice of the
+// tree instead of scanning every key.
+//
+// The bounds are Strings to match the key type. A borrowed `from..to`
+// (Range<&str>) won't do: Range<&T> is only RangeBounds<T> for SIZED T,
+// and str isn't sized.
+fn words_in_range<'a>(index: &'a BTreeMap<String, Vec<usize>>, from: &str, to: &str) -> Vec<&'a str> {
+    index
+        .range(from.to_string()..to.to_string())
+        .map(|(word, _)| word.as_str())
+        .collect()
+}
+
+fn word_set(text: &str) -> BTreeSet<String> {
+    words(text).collect()
+}
+
+fn ordered_collections() {
+    println!("--- Part 4: BTreeMap and BTreeSet ---\n");
+
+    let index = inverted_index(POEM);
+    println!("\"world\" is on lines {:?}", index["world"]);
+    println!("Words from h to m: {:?}", words_in_range(&index, "h", "n"));
+
+    // Set operations return lazy iterators, already in sorted order
+    let first = word_set("hello world the world is wide");
+    let second = word_set("a wide and wonderful world");
+    println!("Union:        {:?}", first.union(&second).collect::<Vec<_>>());
+    println!("Intersection: {:?}", first.intersection(&second).collect::<Vec<_>>());
+    println!("Difference:   {:?}", first.difference(&second).collect::<Vec<_>>());
+
+    println!();
+}
+
+// =============================================================================
+// PART 5: DOUBLE-ENDED QUEUES - VecDeque<T>
+// =============================================================================
+// VecDeque is a ring buffer: O(1) push and pop at BOTH ends. Vec can only do
+// that at the back - Vec::remove(0) shifts every other element.
+
+// Maximum of every `window`-sized slice of data, in O(n) overall.
+//
+// The deque holds INDICES whose values are in decreasing order, so the front
+// is always the current window's maximum. Each new value pops smaller values
+// off the back (they can never be a maximum again), and the front is dropped
+// once it slides out of the window. Every index is pushed and popped at most
+// once - hence O(n) instead of the naive O(n * window).
+fn sliding_window_max(data: &[i32], window: usize) -> Vec<i32> {
+    assert!(window > 0, "window must be at least 1");
+    // A window wider than the data covers all of it: one global maximum
+    let window = window.min(data.len());
+    let mut deque: VecDeque<usize> = VecDeque::new();
+    let mut maxima = Vec::new();
+
+    for (i, &value) in data.iter().enumerate() {
+        while deque.back().is_some_and(|&j| data[j] <= value) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+
+        if deque.front().is_some_and(|&j| j + window <= i) {
+            deque.pop_front();  // Slid out of the window
+        }
+        if i + 1 >= window {
+            maxima.push(data[deque[0]]);
+        }
+    }
+    maxima
+}
+
+fn double_ended_queues() {
+    println!("--- Part 5: VecDeque ---\n");
+
+    let mut deque = VecDeque::new();
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);   // [1, 2, 3]
+    deque.push_front(0);   // [0, 1, 2, 3]
+    println!("Deque: {:?}", deque);
+    println!("pop_front: {:?}, pop_back: {:?}", deque.pop_front(), deque.pop_back());
+    println!("Left: {:?}", deque);
+
+    let data = [2, 1, 5, 3, 6, 4, 8, 7];
+    println!("Sliding max (3) of {:?}: {:?}", data, sliding_window_max(&data, 3));
+
+    println!();
+}
+
+// =============================================================================
+// PART 6: HASH SETS - HashSet<T>
+// =============================================================================
+// A HashSet<T> is a HashMap<T, ()>: unique values, O(1) membership tests.
+// Set algebra (difference, intersection, ...) compares two sets at once.
+
+// Which words were removed, added, and kept between two versions of a text.
+// Returns (only_before, only_after, in_both).
+fn text_diff(before: &str, after: &str) -> (HashSet<String>, HashSet<String>, HashSet<String>) {
+    let before: HashSet<String> = words(before).collect();
+    let after: HashSet<String> = words(after).collect();
+
+    // symmetric_difference = in exactly one of the sets = every changed word.
+    // difference then splits it into removed and added words.
+    let changed: HashSet<String> = before.symmetric_difference(&after).cloned().collect();
+    let only_before = before.difference(&after).cloned().collect();
+    let only_after = changed.difference(&only_before).cloned().collect();
+    let in_both = before.intersection(&after).cloned().collect();
+
+    (only_before, only_after, in_both)
+}
+
+// Word counts, most frequent first. Equal counts are ordered alphabetically
+// so the result doesn't depend on HashMap's random iteration order.
+fn word_frequency_sorted(text: &str) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in words(text) {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|(word_a, count_a), (word_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| word_a.cmp(word_b))
+    });
+    sorted
+}
+
+fn hash_sets() {
+    println!("--- Part 6: HashSet ---\n");
+
+    // insert() returns false if the value was already present
+    let mut seen = HashSet::new();
+    for word in ["red", "green", "red"] {
+        println!("insert({word:?}) -> new? {}", seen.insert(word));
+    }
+
+    let (removed, added, kept) = text_diff("the quick brown fox", "the slow brown dog");
+    // Collect into BTreeSet just to print in a stable order
+    println!("Removed: {:?}", removed.iter().collect::<BTreeSet<_>>());
+    println!("Added:   {:?}", added.iter().collect::<BTreeSet<_>>());
+    println!("Kept:    {:?}", kept.iter().collect::<BTreeSet<_>>());
+
+    println!("Top words: {:?}", &word_frequency_sorted(POEM)[..3]);
+
+    println!();
+}
+
+// =============================================================================
+// PART 7: NESTING COLLECTIONS - A Trie (Prefix Tree)
+// =============================================================================
+// A Trie stores words one character per level. Words that share a prefix
+// share the path for it:
+//
+//   (root) - a - p - p*- l - e*
+//                        \- y*        * = is_end: a word finishes here
+//
+// Every node is itself a Trie, kept in its parent's HashMap<char, Trie>.
+// A HashMap of Tries is fine for recursion - unlike a struct holding a Trie
+// directly, its size is known (the children live on the heap).
+
+#[derive(Default)]
+struct Trie {
+    children: HashMap<char, Trie>,
+    is_end: bool,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Trie::default()
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = self;
+        for c in word.chars() {
+            // entry().or_default() creates the child node if it's missing
+            node = node.children.entry(c).or_default();
+        }
+        node.is_end = true;
+    }
+
+    // Follows the path for `prefix`, if there is one
+    fn find(&self, prefix: &str) -> Option<&Trie> {
+        let mut node = self;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    fn search(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_end)
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    // Every stored word beginning with prefix, in alphabetical order
+    fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            let mut current = prefix.to_string();
+            node.collect_words(&mut current, &mut words);
+        }
+        words
+    }
+
+    // Depth-first walk; `current` is the path so far, pushed and popped as
+    // we go down and back up
+    fn collect_words(&self, current: &mut String, words: &mut Vec<String>) {
+        if self.is_end {
+            words.push(current.clone());
+        }
+        // HashMap order is random - sort the keys for a stable result
+        let mut keys: Vec<&char> = self.children.keys().collect();
+        keys.sort();
+        for c in keys {
+            current.push(*c);
+            self.children[c].collect_words(current, words);
+            current.pop();
+        }
+    }
+}
+
+fn prefix_tree() {
+    println!("--- Part 7: Trie ---\n");
+
+    let mut trie = Trie::new();
+    for word in ["apple", "app", "application", "apply", "banana"] {
+        trie.insert(word);
+    }
+    println!("search(\"app\"): {}", trie.search("app"));
+    println!("search(\"ap\"): {}", trie.search("ap"));
+    println!("starts_with(\"ban\"): {}", trie.starts_with("ban"));
+    println!("words_with_prefix(\"appl\"): {:?}", trie.words_with_prefix("appl"));
+
+    println!();
+}
+
+// =============================================================================
+// PART 8: TRADING ACCURACY FOR SPACE - A Bloom Filter
+// =============================================================================
+// A HashSet<String> stores every item. A Bloom filter stores only a Vec of
+// bits, and answers "might contain?":
+//   false -> DEFINITELY not inserted
+//   true  -> PROBABLY inserted (a small, tunable false-positive rate)
+//
+// insert() sets k bits chosen by k different hashes of the item;
+// might_contain() checks whether all k bits are set.
+//
+// Sizing for n items and a target false-positive rate p:
+//   bits   m = -n * ln(p) / ln(2)^2
+//   hashes k = (m / n) * ln(2)
+
+struct BloomFilter {
+    bits: Vec<bool>,
+    num_hashes: usize,
+}
+
+impl BloomFilter {
+    fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(expected_items > 0, "expected_items must be positive");
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false_positive_rate must be between 0 and 1"
+        );
+        let n = expected_items as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil() as usize;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as usize;
+        BloomFilter { bits: vec![false; num_bits], num_hashes }
+    }
+
+    // Hash function number `seed`: feeding the seed in first makes each one
+    // produce unrelated results. DefaultHasher::new() always starts from the
+    // same keys, so the positions are stable for the filter's lifetime.
+    fn bit_index(&self, item: &str, seed: usize) -> usize {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        item.hash(&mut hasher);
+        (hasher.finish() % self.bits.len() as u64) as usize
+    }
+
+    fn insert(&mut self, item: &str) {
+        for seed in 0..self.num_hashes {
+            let index = self.bit_index(item, seed);
+            self.bits[index] = true;
+        }
+    }
+
+    fn might_contain(&self, item: &str) -> bool {
+        (0..self.num_hashes).all(|seed| self.bits[self.bit_index(item, seed)])
+    }
+}
+
+fn bloom_filter() {
+    println!("--- Part 8: Bloom Filter ---\n");
+
+    let mut filter = BloomFilter::new(1000, 0.01);
+    println!("{} bits, {} hashes (a HashSet would store every string)", filter.bits.len(), filter.num_hashes);
+
+    for word in ["apple", "banana", "cherry"] {
+        filter.insert(word);
+    }
+    for word in ["apple", "cherry", "durian"] {
+        println!("might_contain({word:?}) = {}", filter.might_contain(word));
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 9: GRAPHS - An Adjacency List in a HashMap
+// =============================================================================
+// A directed graph as HashMap<node, Vec<neighbors>>. Which collection holds
+// the "nodes still to visit" decides the traversal order:
+//   VecDeque (FIFO queue) -> BFS: all nodes 1 edge away, then 2 edges, ...
+//   Vec      (LIFO stack) -> DFS: follow one path as deep as it goes first
+//
+//   0 --> 1 --> 3 --> 5          BFS from 0: 0 1 2 3 4 5
+//   |           ^     ^          DFS from 0: 0 1 3 5 2 4
+//   +---> 2 ----+     |
+//         |           |
+//         +---> 4 ----+
+//
+// Cycle detection colors every node:
+//   White = not seen yet, Gray = on the current DFS path, Black = finished.
+// Reaching a Gray node means we walked back into our own path: a cycle.
+//
+// Topological sort (Kahn's algorithm) orders nodes so every edge points
+// FORWARD - e.g. build steps where each runs after its dependencies:
+//   1. Count incoming edges (in-degree) for every node
+//   2. Queue all nodes with in-degree 0 (nothing left to wait for)
+//   3. Pop a node, output it, "remove" its edges by decrementing each
+//      neighbor's in-degree; queue neighbors that reach 0
+// If some nodes never reach 0 they are waiting on each other: a cycle.
+
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+#[derive(Default)]
+struct Graph {
+    adj: HashMap<usize, Vec<usize>>,
+}
+
+impl Graph {
+    fn new() -> Self {
+        Graph::default()
+    }
+
+    // A node with no edges (yet)
+    fn add_node(&mut self, node: usize) {
+        self.adj.entry(node).or_default();
+    }
+
+    // Directed edge from -> to. Both ends become nodes of the graph.
+    fn add_edge(&mut self, from: usize, to: usize) {
+        self.adj.entry(from).or_default().push(to);
+        self.adj.entry(to).or_default();
+    }
+
+    fn neighbors(&self, node: usize) -> &[usize] {
+        self.adj.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    fn bfs(&self, start: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &next in self.neighbors(node) {
+                // Mark when QUEUED, so no node enters the queue twice
+                if seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;  // Reached again through another path
+            }
+            order.push(node);
+            // Reversed, so the FIRST neighbor is popped (visited) first
+            for &next in self.neighbors(node).iter().rev() {
+                if !visited.contains(&next) {
+                    stack.push(next);
+                }
+            }
+        }
+        order
+    }
+
+    fn has_cycle(&self) -> bool {
+        let mut color: HashMap<usize, Color> = HashMap::new();
+        for &root in self.adj.keys() {
+            if color.get(&root).is_some_and(|&c| c != Color::White) {
+                continue;
+            }
+            // Stack of (node, index of the next neighbor to look at), so a
+            // node stays Gray until ALL of its neighbors are finished
+            let mut stack = vec![(root, 0)];
+            color.insert(root, Color::Gray);
+            while let Some((node, next_index)) = stack.last_mut() {
+                let node = *node;
+                match self.neighbors(node).get(*next_index) {
+                    Some(&next) => {
+                        *next_index += 1;
+                        match color.get(&next).copied().unwrap_or(Color::White) {
+                            Color::Gray => return true,  // Back edge
+                            Color::White => {
+                                color.insert(next, Color::Gray);
+                                stack.push((next, 0));
+                            }
+                            Color::Black => {}  // Already fully explored
+                        }
+                    }
+                    None => {
+                        color.insert(node, Color::Black);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut in_degree: HashMap<usize, usize> = self.adj.keys().map(|&node| (node, 0)).collect();
+        for neighbors in self.adj.values() {
+            for next in neighbors {
+                *in_degree.entry(*next).or_default() += 1;
+            }
+        }
+
+        // Sorted so the result doesn't depend on HashMap iteration order
+        let mut ready: Vec<usize> = in_degree.iter().filter(|(_, &d)| d == 0).map(|(&n, _)| n).collect();
+        ready.sort_unstable();
+        let mut queue = VecDeque::from(ready);
+
+        let mut order = Vec::with_capacity(self.adj.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &next in self.neighbors(node) {
+                let degree = in_degree.get_mut(&next).expect("every node has an in-degree");
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        // Nodes on a cycle never reach in-degree 0, so they are missing
+        (order.len() == self.adj.len()).then_some(order)
+    }
+}
+
+fn graph_traversal() {
+    println!("--- Part 9: Graph BFS / DFS ---\n");
+
+    let mut graph = Graph::new();
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (3, 5), (4, 5)] {
+        graph.add_edge(from, to);
+    }
+    println!("BFS from 0: {:?}", graph.bfs(0));
+    println!("DFS from 0: {:?}", graph.dfs(0));
+    println!("has cycle? {}", graph.has_cycle());
+
+    graph.add_node(6);  // No edges: free to go anywhere in the order
+    println!("topological order: {:?}", graph.topological_sort());
+
+    graph.add_edge(5, 2);  // 2 -> 4 -> 5 -> 2
+    println!("after adding 5 -> 2, has cycle? {}", graph.has_cycle());
+    println!("topological order: {:?}", graph.topological_sort());
+
+    println!();
+}
+
+// =============================================================================
+// PART 10: A ROPE - Strings Built from a Tree of Pieces
+// =============================================================================
+// `a + &b` on Strings copies bytes: joining many pieces one by one copies
+// the growing result again and again. A rope never copies on concat - it
+// just makes a tree node pointing at both halves:
+//
+//                Concat (len 11)
+//               /               \
+//        Leaf("hello ")      Leaf("world")
+//
+// The text is only assembled (copied once) when you call to_string().
+// Lengths are counted in chars, and each Concat caches its total, so len()
+// never has to walk the whole tree.
+
+#[derive(Debug)]
+enum Rope {
+    Leaf(String),
+    Concat { left: Box<Rope>, right: Box<Rope>, len: usize },
+}
+
+impl Rope {
+    fn new(s: &str) -> Rope {
+        Rope::Leaf(s.to_string())
+    }
+
+    fn concat(a: Rope, b: Rope) -> Rope {
+        // Skip empty pieces so split_at doesn't leave useless nodes behind
+        if a.is_empty() {
+            return b;
+        }
+        if b.is_empty() {
+            return a;
+        }
+        let len = a.len() + b.len();
+        Rope::Concat { left: Box::new(a), right: Box::new(b), len }  // Moves, no copy
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Rope::Leaf(s) => s.chars().count(),
+            Rope::Concat { len, .. } => *len,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Splits into the first `pos` chars and the rest. Only the one leaf that
+    // `pos` falls inside is copied; every other leaf moves into a new tree.
+    fn split_at(self, pos: usize) -> (Rope, Rope) {
+        assert!(pos <= self.len(), "split position {} out of bounds", pos);
+        match self {
+            Rope::Leaf(s) => {
+                let byte = s.char_indices().nth(pos).map_or(s.len(), |(i, _)| i);
+                let (left, right) = s.split_at(byte);
+                (Rope::new(left), Rope::new(right))
+            }
+            Rope::Concat { left, right, .. } => {
+                let left_len = left.len();
+                if pos <= left_len {
+                    let (a, b) = left.split_at(pos);
+                    (a, Rope::concat(b, *right))
+                } else {
+                    let (a, b) = right.split_at(pos - left_len);
+                    (Rope::concat(*left, a), b)
+                }
+            }
+        }
+    }
+
+    // The leaf strings, left to right
+    fn leaves(&self) -> Vec<&str> {
+        match self {
+            Rope::Leaf(s) => vec![s.as_str()],
+            Rope::Concat { left, right, .. } => {
+                let mut leaves = left.leaves();
+                leaves.extend(right.leaves());
+                leaves
+            }
+        }
+    }
+}
+
+// Implementing Display also provides .to_string() (via the blanket
+// `impl<T: Display> ToString for T`) - the single point where the pieces
+// are copied into one String
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for leaf in self.leaves() {
+            f.write_str(leaf)?;
+        }
+        Ok(())
+    }
+}
+
+fn rope() {
+    println!("--- Part 10: Rope ---\n");
+
+    let text = ["The ", "quick ", "brown ", "fox"]
+        .into_iter()
+        .map(Rope::new)
+        .fold(Rope::new(""), Rope::concat);
+    println!("rope: {:?} ({} chars, {} leaves)", text.to_string(), text.len(), text.leaves().len());
+
+    let (left, right) = text.split_at(10);
+    println!("split_at(10): {:?} + {:?}", left.to_string(), right.to_string());
+    println!("leaves: {:?} + {:?}", left.leaves(), right.leaves());
+
+    println!();
+}
+
+// =============================================================================
+// PART 11: ITERATING YOUR OWN COLLECTION - The Three IntoIterator Forms
+// =============================================================================
+// A `for` loop calls IntoIterator::into_iter() on whatever you give it, and
+// Vec has THREE impls, one for each way of looking at the items:
+//
+//   for x in v       -> Vec<T>        yields T       (consumes v)
+//   for x in &v      -> &Vec<T>       yields &T      (borrows v)
+//   for x in &mut v  -> &mut Vec<T>   yields &mut T  (borrows v mutably)
+//
+// Grid<T> gets the same three, walking the cells row by row. Each one just
+// flattens the matching iterator over the rows, so there's no new iterator
+// struct to write.
+
+#[derive(Debug)]
+struct Grid<T> {
+    data: Vec<Vec<T>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter().flatten()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Flatten<std::slice::Iter<'a, Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter().flatten()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::iter::Flatten<std::slice::IterMut<'a, Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut().flatten()
+    }
+}
+
+// Lets `.collect()` build a Grid from an iterator of rows
+impl<T> FromIterator<Vec<T>> for Grid<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let data: Vec<Vec<T>> = iter.into_iter().collect();
+        let rows = data.len();
+        let cols = data.first().map_or(0, Vec::len);
+        assert!(data.iter().all(|row| row.len() == cols), "every row of a Grid must have {} columns", cols);
+        Grid { data, rows, cols }
+    }
+}
+
+fn grid_iteration() {
+    println!("--- Part 11: Grid and IntoIterator ---\n");
+
+    let mut grid: Grid<i32> = (0..3).map(|r| (1..=4).map(|c| r * 4 + c).collect()).collect();
+    println!("{}x{} grid: {:?}", grid.rows, grid.cols, grid.data);
+
+    let total: i32 = (&grid).into_iter().sum();
+    println!("for x in &grid     -> sum = {}", total);
+
+    for cell in &mut grid {
+        *cell *= 10;
+    }
+    println!("for x in &mut grid -> {:?}", grid.data);
+
+    let cells: Vec<i32> = grid.into_iter().collect();  // grid is moved here
+    println!("for x in grid      -> {:?}", cells);
+
+    println!();
+}
+
+// =============================================================================
+// PART 12: RESULT COMBINATORS - A Lookup-and-Parse Pipeline
+// =============================================================================
+// Values pulled out of a collection are rarely ready to use: the key may be
+// missing, the text may not parse, the number may be out of range. Each
+// step below can fail, and the first failure skips every later step -
+// the same short-circuiting as `?`, written as one chain.
+
+#[derive(Debug, PartialEq)]
+enum PipelineError {
+    MissingKey(String),
+    NotANumber(String),
+    NotPositive(i32),
+}
+
+// Look up `key`, parse it, double it, and insist the result is positive
+fn doubled_setting(settings: &HashMap<&str, &str>, key: &str) -> Result<i32, PipelineError> {
+    settings
+        .get(key)                                                       // Option<&&str>
+        .ok_or(PipelineError::MissingKey(key.to_string()))              // None -> Err
+        .and_then(|raw| {
+            raw.trim()
+                .parse::<i32>()                                         // Result<i32, ParseIntError>
+                .map_err(|e| PipelineError::NotANumber(e.to_string()))  // Unify the error type
+        })
+        .map(|n| n.saturating_mul(2))                                   // Only touches Ok values
+        .and_then(|n| if n > 0 { Ok(n) } else { Err(PipelineError::NotPositive(n)) })
+}
+
+// The same pipeline, but any failure falls back to `default`
+fn doubled_setting_or(settings: &HashMap<&str, &str>, key: &str, default: i32) -> i32 {
+    doubled_setting(settings, key)
+        .unwrap_or_else(|e| {                                           // Closure gets the error
+            println!("    {:?} -> using default {}", e, default);
+            default
+        })
+}
+
+fn sample_settings() -> HashMap<&'static str, &'static str> {
+    HashMap::from([("workers", "4"), ("retries", " 3 "), ("timeout", "soon"), ("offset", "-5")])
+}
+
+fn result_pipeline_demo() {
+    println!("--- Part 12: Result Pipeline ---\n");
+
+    let settings = sample_settings();
+    for key in ["workers", "retries", "timeout", "offset", "threads"] {
+        println!("doubled_setting({:?}) = {:?}", key, doubled_setting(&settings, key));
+    }
+
+    println!("With a fallback of 1:");
+    let threads = doubled_setting_or(&settings, "threads", 1);
+    println!("    threads = {}", threads);
+
+    println!();
+}
+
+// =============================================================================
+// KEY CONCEPTS SUMMARY
+// =============================================================================
+//
+// | Collection    | Create               | Add              | Read             |
+// |---------------|----------------------|------------------|------------------|
+// | Vec<T>        | Vec::new(), vec![]   | push()           | v[i], v.get(i)   |
+// | String        | String::from(), ""   | push_str(), +    | chars(), bytes() |
+// | HashMap<K, V> | HashMap::new(), map! | insert(), entry  | m[&k], m.get(&k) |
+// | VecDeque<T>   | VecDeque::new()      | push_back/front  | pop_front/back   |
+//
+// RESULT COMBINATORS (an Err skips every later step):
+// - ok_or(e)         Option -> Result
+// - map_err(f)       convert the error type
+// - map(f)           transform the Ok value
+// - and_then(f)      next step that can itself fail
+// - unwrap_or_else   recover with a value computed from the error
+//
+// ITERATING (any collection with all three IntoIterator impls):
+// - for x in c       -> owned items, c is consumed
+// - for x in &c      -> &T, c is still usable afterwards
+// - for x in &mut c  -> &mut T, edit items in place
+//
+// OWNERSHIP:
+// - Inserting an owned value (String) into a collection MOVES it in
+// - Copy types (i32, bool...) are copied in
+// - get() hands out references, so the collection keeps ownership
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_contains_all_entries() {
+        let scores = map! { "Blue" => 10, "Yellow" => 50 };
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores["Blue"], 10);
+        assert_eq!(scores["Yellow"], 50);
+    }
+
+    #[test]
+    fn test_map_empty() {
+        let empty: HashMap<String, i32> = map! {};
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_map_trailing_comma() {
+        let with_comma = map! {
+            "a" => 1,
+            "b" => 2,
+        };
+        let without_comma = map! { "a" => 1, "b" => 2 };
+        assert_eq!(with_comma, without_comma);
+    }
+
+    #[test]
+    fn test_map_mixed_string_and_str_keys() {
+        let owned = String::from("owned");
+        let mixed = map! {
+            owned => true,
+            "borrowed" => false,
+        };
+        // Both keys became String via .to_string()
+        let expected: HashMap<String, bool> =
+            [("owned".to_string(), true), ("borrowed".to_string(), false)].into_iter().collect();
+        assert_eq!(mixed, expected);
+    }
+
+    #[test]
+    fn test_inverted_index() {
+        let index = inverted_index(POEM);
+        assert_eq!(index["world"], vec![1, 2, 4]);
+        assert_eq!(index["hello"], vec![1, 3]);
+        assert!(!index.contains_key("goodbye"));
+    }
+
+    #[test]
+    fn test_words_in_range() {
+        let index = inverted_index(POEM);
+        let words = words_in_range(&index, "h", "n");
+        assert_eq!(words, vec!["hello", "is", "my"]);
+        assert!(words.iter().all(|w| ("h".."n").contains(w)));
+    }
+
+    #[test]
+    fn test_btreeset_operations() {
+        let first = word_set("apple banana cherry");
+        let second = word_set("banana cherry date");
+
+        let union: Vec<_> = first.union(&second).map(String::as_str).collect();
+        let both: Vec<_> = first.intersection(&second).map(String::as_str).collect();
+        let only_first: Vec<_> = first.difference(&second).map(String::as_str).collect();
+
+        assert_eq!(union, vec!["apple", "banana", "cherry", "date"]);
+        assert_eq!(both, vec!["banana", "cherry"]);
+        assert_eq!(only_first, vec!["apple"]);
+    }
+
+    #[test]
+    fn test_sliding_window_max() {
+        assert_eq!(sliding_window_max(&[2, 1, 5, 3, 6, 4, 8, 7], 3), vec![5, 5, 6, 6, 8, 8]);
+        assert_eq!(sliding_window_max(&[4, 3, 2, 1], 1), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_sliding_window_larger_than_data() {
+        assert_eq!(sliding_window_max(&[3, 9, 2], 10), vec![9]);
+        assert_eq!(sliding_window_max(&[], 3), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_vecdeque_push_pop_order() {
+        let mut deque = VecDeque::new();
+        deque.push_back("b");
+        deque.push_front("a");
+        deque.push_back("c");
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+
+        assert_eq!(deque.pop_front(), Some("a"));
+        assert_eq!(deque.pop_back(), Some("c"));
+        assert_eq!(deque.pop_back(), Some("b"));
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    fn set_of(words: &[&str]) -> HashSet<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_text_diff() {
+        let (only_before, only_after, in_both) =
+            text_diff("The quick brown fox jumps", "the slow brown dog jumps high");
+        assert_eq!(only_before, set_of(&["quick", "fox"]));
+        assert_eq!(only_after, set_of(&["slow", "dog", "high"]));
+        assert_eq!(in_both, set_of(&["the", "brown", "jumps"]));
+    }
+
+    #[test]
+    fn test_word_frequency_sorted() {
+        let sorted = word_frequency_sorted("b a c a b a d");
+        assert_eq!(
+            sorted,
+            vec![
+                ("a".to_string(), 3),
+                ("b".to_string(), 2),
+                ("c".to_string(), 1),  // c and d tie - alphabetical order
+                ("d".to_string(), 1),
+            ]
+        );
+        assert!(sorted.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    fn sample_trie() -> Trie {
+        let mut trie = Trie::new();
+        for word in ["apple", "app", "application", "apply"] {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn test_trie_search_and_starts_with() {
+        let trie = sample_trie();
+        assert!(trie.search("app"));
+        assert!(trie.search("application"));
+        assert!(!trie.search("ap"));      // Only a prefix, not a word
+        assert!(!trie.search("apples"));  // Runs off the end of the tree
+        assert!(trie.starts_with("appl"));
+        assert!(!trie.starts_with("b"));
+    }
+
+    #[test]
+    fn test_trie_words_with_prefix() {
+        let trie = sample_trie();
+        assert_eq!(trie.words_with_prefix("appl"), vec!["apple", "application", "apply"]);
+        assert_eq!(trie.words_with_prefix(""), vec!["app", "apple", "application", "apply"]);
+        assert!(trie.words_with_prefix("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_bloom_filter_has_no_false_negatives() {
+        let mut filter = BloomFilter::new(1000, 0.01);
+        for i in 0..1000 {
+            filter.insert(&format!("item-{i}"));
+        }
+        assert!((0..1000).all(|i| filter.might_contain(&format!("item-{i}"))));
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_rate() {
+        // Fixed inputs, and DefaultHasher::new() uses fixed keys, so the
+        // result is the same every run
+        let target = 0.01;
+        let mut filter = BloomFilter::new(1000, target);
+        for i in 0..1000 {
+            filter.insert(&format!("item-{i}"));
+        }
+
+        let false_positives = (0..10_000)
+            .filter(|i| filter.might_contain(&format!("other-{i}")))
+            .count();
+        let rate = false_positives as f64 / 10_000.0;
+        // ~100 false positives expected; 50% tolerance is about 5 standard
+        // deviations of sampling noise, while a broken filter lands far off
+        assert!(rate < target * 1.5, "false-positive rate {rate} is not within 50% of {target}");
+    }
+
+    fn sample_dag() -> Graph {
+        let mut graph = Graph::new();
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (3, 5), (4, 5)] {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bfs_visits_level_by_level() {
+        let order = sample_dag().bfs(0);
+        assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+        // Levels: {0}, {1, 2}, {3, 4}, {5}
+        let level = |n: usize| match n { 0 => 0, 1 | 2 => 1, 3 | 4 => 2, _ => 3 };
+        assert!(order.windows(2).all(|w| level(w[0]) <= level(w[1])));
+    }
+
+    #[test]
+    fn test_dfs_goes_deep_first() {
+        let graph = sample_dag();
+        assert_eq!(graph.dfs(0), vec![0, 1, 3, 5, 2, 4]);
+        assert_eq!(graph.dfs(4), vec![4, 5]);  // Only what's reachable
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let mut graph = sample_dag();
+        assert!(!graph.has_cycle());  // 3 and 5 are reached twice, but no cycle
+
+        graph.add_edge(5, 2);  // Back edge: 2 -> 4 -> 5 -> 2
+        assert!(graph.has_cycle());
+
+        let mut self_loop = Graph::new();
+        self_loop.add_edge(7, 7);
+        assert!(self_loop.has_cycle());
+    }
+
+    #[test]
+    fn test_topological_sort_respects_dependencies() {
+        // Getting dressed: shirt -> tie -> jacket, trousers -> shoes, ...
+        let (shirt, tie, jacket, trousers, shoes) = (0, 1, 2, 3, 4);
+        let edges = [(shirt, tie), (tie, jacket), (trousers, shoes), (trousers, jacket), (shirt, trousers)];
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+
+        let order = graph.topological_sort().expect("graph is acyclic");
+        assert_eq!(order.len(), 5);
+        let position = |node| order.iter().position(|&n| n == node).unwrap();
+        for (from, to) in edges {
+            assert!(position(from) < position(to), "{} must come before {}", from, to);
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_none_for_cycle() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(3, 0);  // Feeds into the cycle but isn't part of it
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn test_topological_sort_trivial_graphs() {
+        assert_eq!(Graph::new().topological_sort(), Some(vec![]));
+
+        let mut single = Graph::new();
+        single.add_node(42);
+        assert_eq!(single.topological_sort(), Some(vec![42]));
+    }
+
+    const PIECES: [&str; 10] = ["zero ", "one ", "two ", "three ", "four ", "five ", "six ", "seven ", "eight ", "nine"];
+
+    fn ten_piece_rope() -> Rope {
+        PIECES.into_iter().map(Rope::new).fold(Rope::new(""), Rope::concat)
+    }
+
+    #[test]
+    fn test_rope_len_and_to_string() {
+        let rope = ten_piece_rope();
+        let naive: String = PIECES.concat();
+        assert_eq!(rope.len(), naive.chars().count());
+        assert_eq!(rope.to_string(), naive);
+    }
+
+    #[test]
+    fn test_rope_split_at() {
+        let (left, right) = ten_piece_rope().split_at(5);
+        assert_eq!(left.len(), 5);
+        assert_eq!(left.to_string(), "zero ");
+        assert_eq!(right.to_string(), PIECES[1..].concat());
+
+        // Splitting inside a leaf, and with multi-byte chars
+        let (left, right) = Rope::concat(Rope::new("héllo"), Rope::new("wörld")).split_at(7);
+        assert_eq!((left.to_string(), right.to_string()), ("héllowö".to_string(), "rld".to_string()));
+    }
+
+    #[test]
+    fn test_rope_concat_does_not_copy() {
+        let a = Rope::new("left half");
+        let b = Rope::new("right half");
+        let (a_ptr, b_ptr) = (a.leaves()[0].as_ptr(), b.leaves()[0].as_ptr());
+
+        let joined = Rope::concat(a, b);
+        // The leaves are the very same heap buffers, just moved into the tree
+        assert_eq!(joined.leaves()[0].as_ptr(), a_ptr);
+        assert_eq!(joined.leaves()[1].as_ptr(), b_ptr);
+        assert_eq!(joined.len(), 19);
+    }
+
+    fn sample_grid() -> Grid<i32> {
+        vec![vec![1, 2, 3], vec![4, 5, 6]].into_iter().collect()
+    }
+
+    #[test]
+    fn test_grid_from_iterator() {
+        let grid = sample_grid();
+        assert_eq!((grid.rows, grid.cols), (2, 3));
+
+        let empty: Grid<i32> = Vec::new().into_iter().collect();
+        assert_eq!((empty.rows, empty.cols), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "every row of a Grid must have 2 columns")]
+    fn test_grid_rejects_ragged_rows() {
+        let _: Grid<i32> = vec![vec![1, 2], vec![3]].into_iter().collect();
+    }
+
+    #[test]
+    fn test_grid_borrowing_iter_sums_in_row_order() {
+        let grid = sample_grid();
+        let mut seen = vec![];
+        for cell in &grid {
+            seen.push(*cell);
+        }
+        assert_eq!(seen, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((&grid).into_iter().sum::<i32>(), 21);
+        assert_eq!(grid.rows, 2);  // Still ours - only borrowed
+    }
+
+    #[test]
+    fn test_grid_mutable_iter_doubles_in_place() {
+        let mut grid = sample_grid();
+        for cell in &mut grid {
+            *cell *= 2;
+        }
+        assert_eq!(grid.data, vec![vec![2, 4, 6], vec![8, 10, 12]]);
+    }
+
+    #[test]
+    fn test_grid_consuming_iter_moves_items_out() {
+        let grid: Grid<String> = vec![vec![String::from("a"), String::from("b")], vec![String::from("c"), String::from("d")]]
+            .into_iter()
+            .collect();
+        let ptrs: Vec<*const u8> = (&grid).into_iter().map(|s| s.as_ptr()).collect();
+
+        let owned: Vec<String> = grid.into_iter().collect();
+        // grid.rows  // ERROR: borrow of moved value: `grid`
+        assert_eq!(owned, vec!["a", "b", "c", "d"]);
+        // Same heap buffers: the Strings were moved out, not cloned
+        assert_eq!(owned.iter().map(|s| s.as_ptr()).collect::<Vec<_>>(), ptrs);
+    }
+
+    #[test]
+    fn test_pipeline_happy_path() {
+        let settings = sample_settings();
+        assert_eq!(doubled_setting(&settings, "workers"), Ok(8));
+        assert_eq!(doubled_setting(&settings, "retries"), Ok(6));  // Whitespace trimmed
+    }
+
+    #[test]
+    fn test_pipeline_missing_key() {
+        assert_eq!(
+            doubled_setting(&sample_settings(), "threads"),
+            Err(PipelineError::MissingKey(String::from("threads")))
+        );
+    }
+
+    #[test]
+    fn test_pipeline_parse_failure() {
+        assert_eq!(
+            doubled_setting(&sample_settings(), "timeout"),
+            Err(PipelineError::NotANumber(String::from("invalid digit found in string")))
+        );
+    }
+
+    #[test]
+    fn test_pipeline_rejects_non_positive() {
+        let mut settings = sample_settings();
+        assert_eq!(doubled_setting(&settings, "offset"), Err(PipelineError::NotPositive(-10)));
+        settings.insert("zero", "0");
+        assert_eq!(doubled_setting(&settings, "zero"), Err(PipelineError::NotPositive(0)));
+    }
+
+    #[test]
+    fn test_pipeline_falls_back_to_default() {
+        let settings = sample_settings();
+        assert_eq!(doubled_setting_or(&settings, "workers", 1), 8);  // Default unused
+        assert_eq!(doubled_setting_or(&settings, "threads", 1), 1);
+        assert_eq!(doubled_setting_or(&settings, "timeout", 1), 1);
+        assert_eq!(doubled_setting_or(&settings, "offset", 1), 1);
+    }
+}
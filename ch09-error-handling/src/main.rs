@@ -0,0 +1,590 @@
+// =============================================================================
+// CHAPTER 9: ERROR HANDLING
+// =============================================================================
+// Rust has no exceptions. Recoverable errors are values of type Result<T, E>,
+// unrecoverable errors are panics.
+//
+// KEY CONCEPTS:
+// 1. Result<T, E> forces callers to deal with failure
+// 2. The ? operator returns early with the error, converting it via From
+// 3. Custom error types implement Display + std::error::Error
+// 4. source() links an error to the lower-level error that caused it
+// =============================================================================
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::num::ParseIntError;
+
+fn main() {
+    println!("=== Chapter 9: Error Handling ===\n");
+
+    custom_error_type();
+    error_context();
+    error_hierarchy();
+    boxed_dyn_error();
+    functional_error_handling();
+}
+
+// =============================================================================
+// PART 1: A CUSTOM ERROR ENUM
+// =============================================================================
+// One enum covers every way our code can fail. Each variant carries the
+// data needed to describe that failure.
+
+#[derive(Debug)]
+enum AppError {
+    IoError(io::Error),        // Wraps the underlying OS error
+    ParseError(String),        // File content wasn't a number
+    NotFound { file: String }, // Struct-like variant with a named field
+    Empty,                     // File was read fine but had no content
+}
+
+// Display is the user-facing message (used by {} and to_string())
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::IoError(e) => write!(f, "I/O error: {}", e),
+            AppError::ParseError(msg) => write!(f, "parse error: {}", msg),
+            AppError::NotFound { file } => write!(f, "file not found: {}", file),
+            AppError::Empty => write!(f, "file is empty"),
+        }
+    }
+}
+
+// Error requires Debug + Display. Every method has a default, but we
+// override source() so callers can reach the wrapped io::Error.
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// From<io::Error> is what lets `?` convert io::Error into AppError
+// automatically: `expr?` desugars to roughly
+//   match expr { Ok(v) => v, Err(e) => return Err(From::from(e)) }
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::IoError(e)
+    }
+}
+
+fn read_number_from_file(path: &str) -> Result<i32, AppError> {
+    // A missing file gets its own variant, every other I/O error is wrapped
+    let file = File::open(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AppError::NotFound { file: path.to_string() },
+        _ => AppError::from(e),
+    })?;
+
+    let mut line = String::new();
+    BufReader::new(file).read_line(&mut line)?;  // io::Error -> AppError via From
+
+    let line = line.trim();
+    line.parse::<i32>()
+        .map_err(|e| AppError::ParseError(format!("{:?}: {}", line, e)))
+}
+
+fn custom_error_type() {
+    println!("--- Part 1: Custom Error Type ---\n");
+
+    match read_number_from_file("does-not-exist.txt") {
+        Ok(n) => println!("Read number: {}", n),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Directories can be opened but not read - produces AppError::IoError
+    if let Err(e) = read_number_from_file(".") {
+        println!("Error: {}", e);
+        println!("Caused by: {:?}", e.source());
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 2: ADDING CONTEXT TO ERRORS
+// =============================================================================
+// "No such file or directory" alone doesn't say WHICH file. ContextError
+// wraps any error together with a message describing what we were doing.
+// It's generic over E, so it works for io::Error, ParseIntError, AppError...
+
+#[derive(Debug)]
+struct ContextError<E: Error> {
+    context: String,
+    source: E,
+}
+
+impl<E: Error> fmt::Display for ContextError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.source)
+    }
+}
+
+// source() must return a 'static trait object, hence the E: 'static bound
+impl<E: Error + 'static> Error for ContextError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+// EXTENSION TRAIT - adds a method to a type we don't own (Result).
+// We can't write `impl Result { ... }` outside std, but we CAN implement
+// our own trait for it.
+trait ResultExt<T, E: Error> {
+    fn context(self, msg: &str) -> Result<T, ContextError<E>>;
+}
+
+impl<T, E: Error> ResultExt<T, E> for Result<T, E> {
+    fn context(self, msg: &str) -> Result<T, ContextError<E>> {
+        self.map_err(|source| ContextError {
+            context: msg.to_string(),
+            source,
+        })
+    }
+}
+
+fn read_config(path: &str) -> Result<String, ContextError<io::Error>> {
+    // Reads like a sentence: "read the file, or explain which file failed"
+    fs::read_to_string(path).context(&format!("failed to read config from {}", path))
+}
+
+fn error_context() {
+    println!("--- Part 2: Error Context ---\n");
+
+    if let Err(e) = read_config("missing-config.toml") {
+        println!("Error: {}", e);                    // context: inner message
+        println!("Source: {}", e.source().unwrap()); // just the inner message
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 3: A TYPED ERROR HIERARCHY
+// =============================================================================
+// Larger programs layer their errors. Each layer only knows about the layer
+// directly below it, and source() links them into a chain:
+//
+//   DatabaseError            "database query failed"
+//     -> QueryError          "invalid LIMIT value"
+//          -> ParseIntError  "invalid digit found in string"
+//
+// Each Display prints ONLY its own level. Callers who want the full story
+// walk the chain with source().
+
+// Level 2: connection problems
+#[derive(Debug)]
+enum ConnectionError {
+    Refused(io::Error),
+}
+
+// Level 2: query problems
+#[derive(Debug)]
+enum QueryError {
+    InvalidLimit(ParseIntError),
+    Timeout(io::Error),
+}
+
+// Level 1: what the rest of the application sees
+#[derive(Debug)]
+enum DatabaseError {
+    Connection(ConnectionError),
+    Query(QueryError),
+}
+
+impl fmt::Display for ConnectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConnectionError::Refused(_) => write!(f, "connection refused by server"),
+        }
+    }
+}
+
+impl Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConnectionError::Refused(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryError::InvalidLimit(_) => write!(f, "invalid LIMIT value"),
+            QueryError::Timeout(_) => write!(f, "query timed out"),
+        }
+    }
+}
+
+impl Error for QueryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QueryError::InvalidLimit(e) => Some(e),
+            QueryError::Timeout(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DatabaseError::Connection(_) => write!(f, "database connection failed"),
+            DatabaseError::Query(_) => write!(f, "database query failed"),
+        }
+    }
+}
+
+impl Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DatabaseError::Connection(e) => Some(e),
+            DatabaseError::Query(e) => Some(e),
+        }
+    }
+}
+
+// From impls let `?` lift a lower-level error into the level above
+impl From<ConnectionError> for DatabaseError {
+    fn from(e: ConnectionError) -> Self {
+        DatabaseError::Connection(e)
+    }
+}
+
+impl From<QueryError> for DatabaseError {
+    fn from(e: QueryError) -> Self {
+        DatabaseError::Query(e)
+    }
+}
+
+// Simulates three stages: 1 = connect, 2 = parse the query, 3 = run it.
+// Any other value succeeds.
+fn simulate_query(fail_at: u8) -> Result<String, DatabaseError> {
+    if fail_at == 1 {
+        let cause = io::Error::new(io::ErrorKind::ConnectionRefused, "port 5432 closed");
+        return Err(ConnectionError::Refused(cause).into());
+    }
+
+    let limit = if fail_at == 2 { "ten" } else { "10" };
+    let limit: u32 = limit.parse().map_err(QueryError::InvalidLimit)?;
+
+    if fail_at == 3 {
+        let cause = io::Error::new(io::ErrorKind::TimedOut, "no response after 30s");
+        return Err(QueryError::Timeout(cause).into());
+    }
+
+    Ok(format!("fetched {} rows", limit))
+}
+
+// Print an error followed by every error in its source() chain
+fn print_error_chain(err: &dyn Error) {
+    println!("Error: {}", err);
+    let mut current = err.source();
+    while let Some(cause) = current {
+        println!("  caused by: {}", cause);
+        current = cause.source();
+    }
+}
+
+fn error_hierarchy() {
+    println!("--- Part 3: Error Hierarchy ---\n");
+
+    for fail_at in 0..=3 {
+        match simulate_query(fail_at) {
+            Ok(rows) => println!("Success: {}", rows),
+            Err(e) => print_error_chain(&e),
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 4: ? WITH Box<dyn Error>
+// =============================================================================
+// When a function can fail in several unrelated ways and the caller only
+// needs to REPORT the error (not react to each kind), Box<dyn Error> saves
+// writing a custom enum.
+//
+// Why `?` works for both ParseIntError and io::Error here:
+// - Both types implement std::error::Error
+// - std provides a blanket impl: impl<E: Error> From<E> for Box<dyn Error>
+// - So `?` boxes whichever error occurs via From, just like it did for AppError
+//
+// The catch: the concrete type is erased. Callers can only print it or try
+// downcast_ref::<T>() at runtime. In LIBRARY code prefer a concrete enum
+// (like AppError) so downstream code can `match` on each failure mode.
+// Box<dyn Error> is best suited to binaries, main(), and quick prototypes.
+
+fn parse_and_add(a: &str, b: &str) -> Result<i32, Box<dyn Error>> {
+    let a: i32 = a.trim().parse()?;  // ParseIntError -> Box<dyn Error>
+    let b: i32 = b.trim().parse()?;
+    // &str -> Box<dyn Error> too, so a plain message can join the `?` chain
+    Ok(a.checked_add(b).ok_or("overflow")?)
+}
+
+fn parse_from_file(path: &str) -> Result<i32, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;  // io::Error -> Box<dyn Error>
+    let number: i32 = contents.trim().parse()?; // ParseIntError -> Box<dyn Error>
+    Ok(number)
+}
+
+fn boxed_dyn_error() {
+    println!("--- Part 4: Box<dyn Error> ---\n");
+
+    println!("2 + 3 = {:?}", parse_and_add("2", "3").ok());
+
+    if let Err(e) = parse_and_add("2", "three") {
+        println!("Error: {}", e);
+    }
+
+    // Recovering the concrete type requires a runtime downcast
+    if let Err(e) = parse_from_file("no-such-number.txt") {
+        if let Some(io_err) = e.downcast_ref::<io::Error>() {
+            println!("I/O error of kind {:?}", io_err.kind());
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 5: FUNCTIONAL-STYLE ERROR HANDLING
+// =============================================================================
+// Instead of `?` and early returns, Result can be transformed with
+// combinators. Each one only runs on the variant it cares about:
+//
+//   map_err(f)   - Err(e) -> Err(f(e))      Ok passes through
+//   and_then(f)  - Ok(v)  -> f(v)           Err passes through (f may fail)
+//   map(f)       - Ok(v)  -> Ok(f(v))       Err passes through (f can't fail)
+//   or_else(f)   - Err(e) -> f(e)           Ok passes through (recovery)
+
+// Open, read, and validate - any failure short-circuits to Err
+fn read_validated(path: &str) -> Result<String, AppError> {
+    File::open(path)
+        // io::Error -> AppError, so every step shares one error type
+        .map_err(AppError::from)
+        // Only runs if open succeeded; reading can fail too
+        .and_then(|mut file| {
+            let mut contents = String::new();
+            file.read_to_string(&mut contents)
+                .map(|_| contents)
+                .map_err(AppError::from)
+        })
+        // Trimming can't fail, so map is enough
+        .map(|s| s.trim().to_string())
+        // Validation turns an Ok into an Err when the content is unusable
+        .and_then(|s| {
+            if s.is_empty() { Err(AppError::Empty) } else { Ok(s) }
+        })
+}
+
+// Same pipeline plus recovery - this function never returns Err
+fn process_file_functional(path: &str) -> Result<String, AppError> {
+    read_validated(path)
+        // Any error above is replaced by a fallback value
+        .or_else(|_| Ok("default".to_string()))
+}
+
+fn functional_error_handling() {
+    println!("--- Part 5: Functional Error Handling ---\n");
+
+    println!("Without recovery: {:?}", read_validated("missing.txt"));
+    println!("With recovery:    {:?}", process_file_functional("missing.txt"));
+
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    // A fresh uniquely named file per call, so tests can run in parallel.
+    // It's deleted when the NamedTempFile drops, even if the test fails.
+    fn temp_file(contents: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().expect("create temp file");
+        file.write_all(contents.as_bytes()).expect("write temp file");
+        file
+    }
+
+    #[test]
+    fn test_read_number_ok() {
+        let file = temp_file("42\n");
+        assert_eq!(read_number_from_file(file.path().to_str().unwrap()).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_not_found_variant() {
+        let err = read_number_from_file("/no/such/file.txt").unwrap_err();
+        assert!(matches!(err, AppError::NotFound { ref file } if file == "/no/such/file.txt"));
+    }
+
+    #[test]
+    fn test_parse_error_variant() {
+        let file = temp_file("forty-two\n");
+        let err = read_number_from_file(file.path().to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, AppError::ParseError(_)));
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_io_error_variant_has_source() {
+        let dir = std::env::temp_dir();
+        let err = read_number_from_file(dir.to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, AppError::IoError(_)));
+
+        let source = err.source().expect("IoError should expose its source");
+        assert!(source.downcast_ref::<io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_context_display_includes_both_messages() {
+        let err = read_config("/no/such/config.toml").unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains("failed to read config from /no/such/config.toml"));
+        assert!(message.contains(&err.source.to_string()));
+    }
+
+    #[test]
+    fn test_context_source_is_inner_error() {
+        let err = read_config("/no/such/config.toml").unwrap_err();
+        let source = err.source().unwrap();
+        let io_err = source.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_context_on_ok_is_untouched() {
+        let ok: Result<i32, io::Error> = Ok(7);
+        assert_eq!(ok.context("never used").unwrap(), 7);
+    }
+
+    // Walks DatabaseError -> level 2 -> level 3 and returns all three
+    fn chain(err: &DatabaseError) -> (&(dyn Error + 'static), &(dyn Error + 'static), &(dyn Error + 'static)) {
+        let level2 = err.source().unwrap();
+        let level3 = level2.source().unwrap();
+        assert!(level3.source().is_none(), "chain should end at level 3");
+        (err, level2, level3)
+    }
+
+    #[test]
+    fn test_hierarchy_connection_failure() {
+        let err = simulate_query(1).unwrap_err();
+        assert!(matches!(err, DatabaseError::Connection(ConnectionError::Refused(_))));
+
+        let (top, middle, root) = chain(&err);
+        assert_eq!(top.to_string(), "database connection failed");
+        assert_eq!(middle.to_string(), "connection refused by server");
+        assert_eq!(root.to_string(), "port 5432 closed");
+        assert_eq!(root.downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::ConnectionRefused);
+    }
+
+    #[test]
+    fn test_hierarchy_parse_failure() {
+        let err = simulate_query(2).unwrap_err();
+        assert!(matches!(err, DatabaseError::Query(QueryError::InvalidLimit(_))));
+
+        let (top, middle, root) = chain(&err);
+        assert_eq!(top.to_string(), "database query failed");
+        assert_eq!(middle.to_string(), "invalid LIMIT value");
+        assert!(root.downcast_ref::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn test_hierarchy_timeout_failure() {
+        let err = simulate_query(3).unwrap_err();
+        assert!(matches!(err, DatabaseError::Query(QueryError::Timeout(_))));
+
+        let (top, middle, root) = chain(&err);
+        assert_eq!(top.to_string(), "database query failed");
+        assert_eq!(middle.to_string(), "query timed out");
+        assert_eq!(root.downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn test_hierarchy_display_is_level_local() {
+        let err = simulate_query(3).unwrap_err();
+        let top = err.to_string();
+        assert!(!top.contains("timed out"));
+        assert!(!top.contains("no response"));
+    }
+
+    #[test]
+    fn test_hierarchy_success() {
+        assert_eq!(simulate_query(0).unwrap(), "fetched 10 rows");
+    }
+
+    #[test]
+    fn test_parse_and_add_ok() {
+        assert_eq!(parse_and_add("2", " 40 ").unwrap(), 42);
+    }
+
+    #[test]
+    fn test_parse_and_add_parse_error() {
+        let err = parse_and_add("2", "forty").unwrap_err();
+        assert!(err.downcast_ref::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn test_parse_and_add_overflow() {
+        let err = parse_and_add("2147483647", "1").unwrap_err();
+        assert_eq!(err.to_string(), "overflow");
+        assert!(err.downcast_ref::<ParseIntError>().is_none());
+    }
+
+    #[test]
+    fn test_parse_from_file_ok() {
+        let file = temp_file("17\n");
+        assert_eq!(parse_from_file(file.path().to_str().unwrap()).unwrap(), 17);
+    }
+
+    #[test]
+    fn test_parse_from_file_io_error() {
+        let err = parse_from_file("/no/such/number.txt").unwrap_err();
+        let io_err = err.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_parse_from_file_parse_error() {
+        let file = temp_file("seventeen\n");
+        let err = parse_from_file(file.path().to_str().unwrap()).unwrap_err();
+        assert!(err.downcast_ref::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn test_functional_happy_path() {
+        let file = temp_file("  hello  \n");
+        let path = file.path().to_str().unwrap();
+        assert_eq!(read_validated(path).unwrap(), "hello");
+        assert_eq!(process_file_functional(path).unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_functional_io_error() {
+        let err = read_validated("/no/such/functional.txt").unwrap_err();
+        assert!(matches!(err, AppError::IoError(ref e) if e.kind() == io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn test_functional_empty_file() {
+        let file = temp_file("   \n");
+        let err = read_validated(file.path().to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, AppError::Empty));
+        assert_eq!(err.to_string(), "file is empty");
+    }
+
+    #[test]
+    fn test_functional_recovery() {
+        let file = temp_file("");
+        assert_eq!(process_file_functional(file.path().to_str().unwrap()).unwrap(), "default");
+        assert_eq!(process_file_functional("/no/such/functional.txt").unwrap(), "default");
+    }
+}
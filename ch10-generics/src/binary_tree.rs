@@ -0,0 +1,144 @@
+// =============================================================================
+// GENERIC BINARY SEARCH TREE
+// =============================================================================
+// A recursive enum parameterized over T. The T: Ord bound lives on the impl
+// block, not the type, so the tree itself can be named for any T but only
+// ordered types get insert/contains.
+//
+// Box<BinaryTree<T>> is required: without it the enum would contain itself
+// directly and have infinite size.
+// =============================================================================
+
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub enum BinaryTree<T> {
+    Leaf,
+    Node {
+        value: T,
+        left: Box<BinaryTree<T>>,
+        right: Box<BinaryTree<T>>,
+    },
+}
+
+impl<T> BinaryTree<T> {
+    pub fn new() -> Self {
+        BinaryTree::Leaf
+    }
+
+    // Number of nodes on the longest root-to-leaf path (empty tree = 0)
+    pub fn height(&self) -> usize {
+        match self {
+            BinaryTree::Leaf => 0,
+            BinaryTree::Node { left, right, .. } => 1 + left.height().max(right.height()),
+        }
+    }
+
+    // Borrowing iterator - yields &T in sorted order
+    pub fn iter(&self) -> InorderIter<'_, T> {
+        let mut iter = InorderIter { stack: Vec::new() };
+        iter.push_left(self);
+        iter
+    }
+}
+
+impl<T: Ord> BinaryTree<T> {
+    pub fn insert(&mut self, value: T) {
+        match self {
+            // Replace the empty leaf with a new node holding two empty leaves
+            BinaryTree::Leaf => {
+                *self = BinaryTree::Node {
+                    value,
+                    left: Box::new(BinaryTree::Leaf),
+                    right: Box::new(BinaryTree::Leaf),
+                };
+            }
+            BinaryTree::Node { value: current, left, right } => match value.cmp(current) {
+                Ordering::Less => left.insert(value),
+                Ordering::Greater => right.insert(value),
+                Ordering::Equal => {} // Duplicates are ignored
+            },
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            BinaryTree::Leaf => false,
+            BinaryTree::Node { value: current, left, right } => match value.cmp(current) {
+                Ordering::Less => left.contains(value),
+                Ordering::Greater => right.contains(value),
+                Ordering::Equal => true,
+            },
+        }
+    }
+}
+
+// =============================================================================
+// IN-ORDER ITERATOR (no recursion)
+// =============================================================================
+// The stack holds nodes whose left subtree has been visited but whose own
+// value has not been yielded yet. The 'a lifetime ties every yielded &T to
+// the tree being iterated.
+
+pub struct InorderIter<'a, T> {
+    stack: Vec<&'a BinaryTree<T>>,
+}
+
+impl<'a, T> InorderIter<'a, T> {
+    // Walk down the left spine, pushing every node on the way
+    fn push_left(&mut self, mut tree: &'a BinaryTree<T>) {
+        while let BinaryTree::Node { left, .. } = tree {
+            self.stack.push(tree);
+            tree = left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for InorderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        match node {
+            BinaryTree::Node { value, right, .. } => {
+                // Everything in the right subtree comes after this value
+                self.push_left(right);
+                Some(value)
+            }
+            BinaryTree::Leaf => None, // push_left never pushes leaves
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tree() -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for value in [5, 3, 7, 1, 4] {
+            tree.insert(value);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_contains() {
+        let tree = sample_tree();
+        assert!(tree.contains(&3));
+        assert!(!tree.contains(&6));
+    }
+
+    #[test]
+    fn test_height() {
+        assert_eq!(sample_tree().height(), 3);
+        assert_eq!(BinaryTree::<i32>::new().height(), 0);
+    }
+
+    #[test]
+    fn test_inorder_iter() {
+        let tree = sample_tree();
+        let values: Vec<i32> = tree.iter().copied().collect();
+        assert_eq!(values, vec![1, 3, 4, 5, 7]);
+    }
+}
@@ -0,0 +1,139 @@
+// =============================================================================
+// GENERIC CIRCULAR (RING) BUFFER
+// =============================================================================
+// Fixed-capacity FIFO queue. `head` is where the next item is written,
+// `tail` is where the oldest item is read from; both wrap around with `%`.
+//
+// Slots are Option<T> so that empty slots need no placeholder value - this
+// keeps the buffer usable for ANY T, not just T: Default or T: Copy.
+//
+// When the buffer is full, push() overwrites the oldest item.
+// =============================================================================
+
+#[derive(Debug)]
+pub struct CircularBuffer<T> {
+    buf: Vec<Option<T>>,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl<T> CircularBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        // vec![None; n] would require T: Clone, so build it from an iterator
+        let buf = (0..capacity).map(|_| None).collect();
+        CircularBuffer { buf, head: 0, tail: 0, len: 0 }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.is_full() {
+            // Overwrite the oldest slot - the read position moves forward too
+            self.tail = (self.tail + 1) % self.capacity();
+        } else {
+            self.len += 1;
+        }
+        self.buf[self.head] = Some(item);
+        self.head = (self.head + 1) % self.capacity();
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // take() moves the value out and leaves None behind
+        let item = self.buf[self.tail].take();
+        self.tail = (self.tail + 1) % self.capacity();
+        self.len -= 1;
+        item
+    }
+}
+
+// =============================================================================
+// IntoIterator - consuming iteration, oldest to newest
+// =============================================================================
+// The iterator simply owns the buffer and keeps calling pop().
+
+pub struct IntoIter<T>(CircularBuffer<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+}
+
+impl<T> IntoIterator for CircularBuffer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_when_full_overwrites_oldest() {
+        let mut buffer = CircularBuffer::new(3);
+        buffer.push(1);
+        buffer.push(2);
+        buffer.push(3);
+        assert!(buffer.is_full());
+
+        buffer.push(4);  // 1 is overwritten
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_pop_empty() {
+        let mut buffer: CircularBuffer<i32> = CircularBuffer::new(2);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop(), None);
+    }
+
+    #[test]
+    fn test_push_pop_push_cycle() {
+        let mut buffer = CircularBuffer::new(2);
+        buffer.push("a");
+        buffer.push("b");
+        assert_eq!(buffer.pop(), Some("a"));
+        buffer.push("c");  // Wraps around into the slot "a" used
+        assert_eq!(buffer.pop(), Some("b"));
+        assert_eq!(buffer.pop(), Some("c"));
+        assert_eq!(buffer.pop(), None);
+        assert_eq!(buffer.capacity(), 2);
+    }
+
+    #[test]
+    fn test_into_iter_after_mixed_operations() {
+        let mut buffer = CircularBuffer::new(4);
+        for i in 1..=6 {
+            buffer.push(i);  // Holds 3, 4, 5, 6
+        }
+        buffer.pop();        // Removes 3
+        buffer.push(7);      // Holds 4, 5, 6, 7
+        assert_eq!(buffer.into_iter().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    }
+}
@@ -0,0 +1,107 @@
+// =============================================================================
+// CONST GENERICS - FixedSizeString<N>
+// =============================================================================
+// `const N: usize` makes a VALUE part of the type: FixedSizeString<8> and
+// FixedSizeString<16> are different types, and each holds its bytes in an
+// inline [u8; N] array - no heap allocation, and the size is known at
+// compile time (handy for embedded code or fixed-width records).
+//
+//   FixedSizeString<8> holding "hello":
+//     bytes: [h e l l o 0 0 0]   len: 5
+//
+// Only bytes[..len] are meaningful; the zero padding is never shown.
+// =============================================================================
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy)]
+pub struct FixedSizeString<const N: usize>([u8; N], usize);
+
+impl<const N: usize> FixedSizeString<N> {
+    pub fn as_str(&self) -> &str {
+        // Always valid: the bytes were copied from a whole &str
+        std::str::from_utf8(&self.0[..self.1]).expect("contents are valid UTF-8")
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+// Implementing FromStr (rather than a plain from_str method) also enables
+// "hello".parse::<FixedSizeString<8>>()
+impl<const N: usize> FromStr for FixedSizeString<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > N {
+            return Err(format!("{:?} is {} bytes, capacity is {}", s, s.len(), N));
+        }
+        let mut bytes = [0u8; N];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Ok(FixedSizeString(bytes, s.len()))
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedSizeString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedSizeString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FixedSizeString<{}>({:?})", N, self.as_str())
+    }
+}
+
+// Compare contents only, so leftover bytes past len can never matter
+impl<const N: usize> PartialEq for FixedSizeString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedSizeString<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedSizeString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_fits() {
+        let s = FixedSizeString::<8>::from_str("hello").unwrap();
+        assert_eq!(s.as_str(), "hello");
+        assert_eq!(s.capacity(), 8);
+        assert!(s == *"hello");  // PartialEq<str>
+    }
+
+    #[test]
+    fn test_from_str_too_long() {
+        assert!(FixedSizeString::<8>::from_str("toolongstr").is_err());
+        assert!(FixedSizeString::<8>::from_str("exactly8").is_ok());  // Boundary
+    }
+
+    #[test]
+    fn test_equal_contents_compare_equal() {
+        let a: FixedSizeString<8> = "same".parse().unwrap();
+        let b: FixedSizeString<8> = "same".parse().unwrap();
+        let c: FixedSizeString<8> = "other".parse().unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_display_has_no_padding() {
+        let s = FixedSizeString::<16>::from_str("hi").unwrap();
+        assert_eq!(s.to_string(), "hi");
+        assert_eq!(format!("[{}]", s), "[hi]");
+    }
+}
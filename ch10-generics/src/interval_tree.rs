@@ -0,0 +1,145 @@
+// =============================================================================
+// GENERIC INTERVAL TREE - "Which ranges contain this point?"
+// =============================================================================
+// Intervals are CLOSED: [start, end] includes both endpoints. Any T: Ord
+// works - integers, times, even strings.
+//
+// The tree is a binary search tree ordered by `start`, AUGMENTED so every
+// node also stores `max`: the largest `end` anywhere in its subtree.
+// That one extra field lets query() skip whole subtrees:
+//   - point > node.max    -> nothing below ends late enough: skip it all
+//   - point < node.start  -> everything on the right starts even later:
+//                            skip the right subtree
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interval<T: Ord + Clone> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval start must not be after its end");
+        Interval { start, end }
+    }
+
+    // Closed intervals overlap if each one starts before the other ends
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        self.start <= *point && *point <= self.end
+    }
+}
+
+#[derive(Debug)]
+struct Node<T: Ord + Clone> {
+    interval: Interval<T>,
+    max: T,  // Largest `end` in this subtree (including this node)
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+#[derive(Debug)]
+pub struct IntervalTree<T: Ord + Clone> {
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+impl<T: Ord + Clone> IntervalTree<T> {
+    pub fn new() -> Self {
+        IntervalTree { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Walk down by `start`, raising `max` on every node we pass through
+        let mut slot = &mut self.root;
+        while let Some(node) = slot {
+            if node.max < interval.end {
+                node.max = interval.end.clone();
+            }
+            slot = if interval.start < node.interval.start { &mut node.left } else { &mut node.right };
+        }
+        let max = interval.end.clone();
+        *slot = Some(Box::new(Node { interval, max, left: None, right: None }));
+        self.len += 1;
+    }
+
+    // Every stored interval containing `point`, ordered by start
+    pub fn query(&self, point: T) -> Vec<Interval<T>> {
+        let mut found = Vec::new();
+        Self::query_node(&self.root, &point, &mut found);
+        found
+    }
+
+    fn query_node(node: &Option<Box<Node<T>>>, point: &T, found: &mut Vec<Interval<T>>) {
+        let Some(node) = node else { return };
+        if node.max < *point {
+            return;  // Every interval down here ends before `point`
+        }
+        Self::query_node(&node.left, point, found);
+        if node.interval.contains(point) {
+            found.push(node.interval.clone());
+        }
+        if node.interval.start <= *point {
+            Self::query_node(&node.right, point, found);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tree() -> IntervalTree<i32> {
+        let mut tree = IntervalTree::new();
+        for (start, end) in [
+            (15, 20), (10, 30), (17, 19), (5, 12), (12, 15),
+            (30, 40), (1, 3), (40, 45), (50, 60), (55, 58),
+        ] {
+            tree.insert(Interval::new(start, end));
+        }
+        tree
+    }
+
+    #[test]
+    fn test_query_point_in_three_intervals() {
+        let tree = sample_tree();
+        assert_eq!(tree.len(), 10);
+        let found = tree.query(18);
+        assert_eq!(found, vec![Interval::new(10, 30), Interval::new(15, 20), Interval::new(17, 19)]);
+    }
+
+    #[test]
+    fn test_query_point_in_no_interval() {
+        assert!(sample_tree().query(47).is_empty());
+        assert!(sample_tree().query(100).is_empty());
+        assert!(IntervalTree::<i32>::new().query(1).is_empty());
+    }
+
+    #[test]
+    fn test_query_shared_endpoint() {
+        // 40 is the end of [30, 40] and the start of [40, 45]
+        let found = sample_tree().query(40);
+        assert_eq!(found, vec![Interval::new(30, 40), Interval::new(40, 45)]);
+    }
+
+    #[test]
+    fn test_interval_overlaps_and_contains() {
+        let a = Interval::new(1, 5);
+        assert!(a.overlaps(&Interval::new(5, 9)));   // Touching endpoints count
+        assert!(a.overlaps(&Interval::new(2, 3)));   // Fully inside
+        assert!(!a.overlaps(&Interval::new(6, 9)));
+        assert!(a.contains(&1) && a.contains(&5) && !a.contains(&6));
+    }
+}
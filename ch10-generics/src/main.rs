@@ -10,6 +10,23 @@
 // Syntax: <T> where T is a type parameter (can be any name, T is convention)
 // =============================================================================
 
+mod binary_tree;      // Generic recursive enum: BinaryTree<T>
+mod min_heap;         // Generic tuple struct: MinHeap<T>
+mod circular_buffer;  // Generic ring buffer: CircularBuffer<T>
+mod skip_list;        // Generic probabilistic sorted set: SkipList<T>
+mod tagged;           // Compile-time state markers: Tagged<T, Marker>
+mod interval_tree;    // Augmented BST for range queries: IntervalTree<T>
+mod fixed_string;     // Const generic inline string: FixedSizeString<N>
+
+use binary_tree::BinaryTree;
+use circular_buffer::CircularBuffer;
+use fixed_string::FixedSizeString;
+use interval_tree::{Interval, IntervalTree};
+use min_heap::MinHeap;
+use skip_list::SkipList;
+use tagged::{validate, Tagged};
+use std::fmt::{Debug, Display};
+
 // =============================================================================
 // GENERIC STRUCTS
 // =============================================================================
@@ -90,6 +107,110 @@ fn main() {
 
     // mix_up creates DoublePoint<i32, i32> (x from dp_1, y from dp_2)
     let _new_dp = dp_1.mix_up(dp_2);
+
+    // =========================================================================
+    // Generic Recursive Enum - BinaryTree<T>
+    // =========================================================================
+
+    let mut tree = BinaryTree::new();  // BinaryTree<i32>, inferred from insert
+    for value in [5, 3, 7, 1, 4] {
+        tree.insert(value);
+    }
+    println!("contains 4? {}", tree.contains(&4));
+    println!("height: {}", tree.height());
+    println!("in order: {:?}", tree.iter().collect::<Vec<_>>());
+
+    // =========================================================================
+    // Generic Tuple Struct - MinHeap<T>
+    // =========================================================================
+
+    let mut heap = MinHeap::from_slice(&['r', 'u', 's', 't']);  // MinHeap<char>
+    heap.push('a');
+    println!("heap size: {}, min: {:?}", heap.len(), heap.peek());
+    while let Some(c) = heap.pop() {
+        print!("{c} ");
+    }
+    println!("(heap empty: {})", heap.is_empty());
+
+    let mut by_push = MinHeap::new();  // Empty; T is inferred from the pushes
+    for n in [42, 7, 19] {
+        by_push.push(n);
+    }
+    println!("pushed one by one, min: {:?}", by_push.peek());
+
+    // =========================================================================
+    // Generic Ring Buffer - CircularBuffer<T>
+    // =========================================================================
+
+    let mut ring = CircularBuffer::new(3);  // CircularBuffer<String>
+    for word in ["one", "two", "three", "four"] {
+        ring.push(word.to_string());  // "four" overwrites "one"
+    }
+    println!("full: {}, capacity: {}, len: {}", ring.is_full(), ring.capacity(), ring.len());
+    println!("oldest: {:?}", ring.pop());
+    println!("rest: {:?}", ring.into_iter().collect::<Vec<_>>());
+
+    // =========================================================================
+    // Generic Sorted Set - SkipList<T>
+    // =========================================================================
+
+    let mut skip = SkipList::new();  // SkipList<&str>
+    for word in ["pear", "apple", "fig", "apple"] {
+        println!("insert {word}: new? {}", skip.insert(word));
+    }
+    skip.remove(&"fig");
+    println!("contains fig? {}, len: {}", skip.contains(&"fig"), skip.len());
+    println!("sorted: {:?}, empty: {}", skip.iter().collect::<Vec<_>>(), skip.is_empty());
+
+    // =========================================================================
+    // Where Clauses - Several Bounds per Type Parameter
+    // =========================================================================
+
+    print_if_greater_than_default(&[-2, 0, 3, 7]);      // i32 default: 0
+    print_if_greater_than_default(&[-0.5, 1.5]);        // f64 default: 0.0
+    print_if_greater_than_default(&["", "b", "a"]);     // &str default: ""
+
+    let pair = PairWrapper { first: 3.5, second: Some(vec!['x']) };
+    pair.show();
+
+    let (shown, debugged) = serialize_and_display("hi", "hi");
+    println!("Display: {shown}, Debug: {debugged}");
+
+    // =========================================================================
+    // PhantomData Markers - Tagged<T, Marker>
+    // =========================================================================
+
+    for input in ["user@example.com", ""] {
+        let raw = Tagged::new(input.to_string());  // Tagged<String, Unvalidated>
+        println!("raw length: {}", raw.len());       // Deref works in any state
+        match validate(raw) {
+            Ok(checked) => println!("validated: {}", checked.use_value()),
+            Err(e) => println!("rejected {input:?}: {e}"),
+        }
+    }
+
+    // =========================================================================
+    // Augmented BST - IntervalTree<T>
+    // =========================================================================
+
+    let mut meetings = IntervalTree::new();  // IntervalTree<&str>: "HH:MM" sorts correctly
+    for (start, end) in [("09:00", "10:00"), ("09:30", "11:00"), ("13:00", "14:00")] {
+        meetings.insert(Interval::new(start, end));
+    }
+    println!("{} meetings (empty: {})", meetings.len(), meetings.is_empty());
+    println!("busy at 09:45: {:?}", meetings.query("09:45"));
+    println!("free at 12:00? {}", meetings.query("12:00").is_empty());
+    let lunch = Interval::new("12:00", "13:00");
+    println!("lunch overlaps 13:00 meeting? {}", lunch.overlaps(&Interval::new("13:00", "14:00")));
+
+    // =========================================================================
+    // Const Generics - FixedSizeString<N>
+    // =========================================================================
+
+    let code: FixedSizeString<8> = "RUST-42".parse().unwrap();  // N = 8, chosen by the type
+    println!("{code} / {code:?}, capacity {}, len {}", code.capacity(), code.as_str().len());
+    println!("too long: {:?}", "a much longer string".parse::<FixedSizeString<8>>());
+    println!("size_of FixedSizeString<8> = {} bytes", std::mem::size_of::<FixedSizeString<8>>());
 }
 
 // =============================================================================
@@ -120,6 +241,61 @@ fn get_largest<T: PartialOrd + Copy>(var_list: Vec<T>) -> T {
     largest
 }
 
+// =============================================================================
+// WHERE CLAUSES - Complex Bounds Without the Clutter
+// =============================================================================
+// Once a type parameter needs several traits, inline bounds get hard to read:
+//   fn f<T: Display + PartialOrd + Default>(items: &[T])
+// A where clause moves them after the signature - same meaning, and each
+// parameter gets its own line.
+//
+// Display vs Debug:
+//   Display ({})   - user-facing output, must be implemented by hand
+//   Debug   ({:?}) - developer output, usually #[derive(Debug)]
+
+fn format_greater_than_default<T>(items: &[T]) -> String
+where
+    T: Display + PartialOrd + Default,
+{
+    let zero = T::default();  // Default: 0 for numbers, "" for &str, ...
+    let kept: Vec<String> = items.iter().filter(|item| **item > zero).map(|item| item.to_string()).collect();
+    format!("greater than {}: [{}]", zero, kept.join(", "))
+}
+
+fn print_if_greater_than_default<T>(items: &[T])
+where
+    T: Display + PartialOrd + Default,
+{
+    println!("{}", format_greater_than_default(items));
+}
+
+// Bounds on a STRUCT apply everywhere the struct is used - every impl and
+// every function taking a PairWrapper must repeat them. That's why bounds
+// usually go on impls instead; here they make PairWrapper's contract explicit.
+struct PairWrapper<T: Display, U: Debug> {
+    first: T,
+    second: U,
+}
+
+impl<T: Display, U: Debug> PairWrapper<T, U> {
+    fn describe(&self) -> String {
+        format!("first = {}, second = {:?}", self.first, self.second)
+    }
+
+    fn show(&self) {
+        println!("{}", self.describe());
+    }
+}
+
+// Same value, two traits: Display("hi") is hi, Debug("hi") is "hi" (quoted)
+fn serialize_and_display<T, U>(a: T, b: U) -> (String, String)
+where
+    T: Display,
+    U: Debug,
+{
+    (format!("{}", a), format!("{:?}", b))
+}
+
 // =============================================================================
 // MONOMORPHIZATION - Zero-Cost Generics
 // =============================================================================
@@ -148,3 +324,29 @@ fn get_largest<T: PartialOrd + Copy>(var_list: Vec<T>) -> T {
 // HashMap<K, V> - Key-value store:
 //   struct HashMap<K, V> { ... }
 // =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greater_than_default() {
+        assert_eq!(format_greater_than_default(&[-2, 0, 3, 7]), "greater than 0: [3, 7]");
+        assert_eq!(format_greater_than_default(&["", "b"]), "greater than : [b]");
+        assert!(format_greater_than_default(&[-1.5, 2.5]).contains("[2.5]"));
+    }
+
+    #[test]
+    fn test_pair_wrapper_uses_display_and_debug() {
+        let pair = PairWrapper { first: "text", second: "text" };
+        assert_eq!(pair.describe(), "first = text, second = \"text\"");
+        pair.show();
+    }
+
+    #[test]
+    fn test_serialize_and_display() {
+        let (shown, debugged) = serialize_and_display(42, vec![Some(1), None]);
+        assert_eq!(shown, "42");
+        assert_eq!(debugged, "[Some(1), None]");
+    }
+}
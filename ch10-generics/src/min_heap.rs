@@ -0,0 +1,139 @@
+// =============================================================================
+// GENERIC MIN-HEAP
+// =============================================================================
+// A binary heap stored in a flat Vec<T>. For the element at index i:
+//   parent      = (i - 1) / 2
+//   left child  = 2i + 1
+//   right child = 2i + 2
+//
+// Invariant: every parent is <= both of its children, so the minimum is
+// always at index 0. T: Ord is what lets us compare elements at all.
+// =============================================================================
+
+#[derive(Debug)]
+pub struct MinHeap<T: Ord>(Vec<T>);
+
+impl<T: Ord> MinHeap<T> {
+    pub fn new() -> Self {
+        MinHeap(Vec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Look at the minimum without removing it
+    pub fn peek(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    // Append at the end, then bubble up until the parent is smaller
+    pub fn push(&mut self, item: T) {
+        self.0.push(item);
+        self.sift_up(self.0.len() - 1);
+    }
+
+    // Swap the root with the last element, pop it, then restore the heap
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let last = self.0.len() - 1;
+        self.0.swap(0, last);
+        let min = self.0.pop();
+        self.sift_down(0);
+        min
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.0[i] >= self.0[parent] {
+                break;
+            }
+            self.0.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.0.len();
+        loop {
+            let left = 2 * i + 1;
+            let right = 2 * i + 2;
+            let mut smallest = i;
+
+            if left < len && self.0[left] < self.0[smallest] {
+                smallest = left;
+            }
+            if right < len && self.0[right] < self.0[smallest] {
+                smallest = right;
+            }
+            if smallest == i {
+                break;
+            }
+            self.0.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+// =============================================================================
+// FLOYD'S HEAPIFY - O(n) construction
+// =============================================================================
+// Pushing n items one by one costs O(n log n). Floyd's algorithm copies the
+// data in as-is and sifts down every non-leaf node, starting from the last
+// one (len / 2 - 1) and walking back to the root.
+//
+// T: Clone is needed here only because we copy out of a borrowed slice.
+
+impl<T: Ord + Clone> MinHeap<T> {
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut heap = MinHeap(data.to_vec());
+        for i in (0..heap.0.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain<T: Ord>(mut heap: MinHeap<T>) -> Vec<T> {
+        let mut out = Vec::new();
+        while let Some(item) = heap.pop() {
+            out.push(item);
+        }
+        out
+    }
+
+    #[test]
+    fn test_push_pop_order() {
+        let mut heap = MinHeap::new();
+        for item in [5, 3, 7, 1, 4] {
+            heap.push(item);
+        }
+        assert_eq!(heap.len(), 5);
+        assert_eq!(drain(heap), vec![1, 3, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let heap = MinHeap::from_slice(&[5, 3, 7, 1, 4]);
+        assert_eq!(drain(heap), vec![1, 3, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_peek_does_not_remove() {
+        let heap = MinHeap::from_slice(&[5, 3, 7, 1, 4]);
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.len(), 5);
+    }
+}
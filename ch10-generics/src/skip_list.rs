@@ -0,0 +1,260 @@
+// =============================================================================
+// GENERIC SKIP LIST
+// =============================================================================
+// A sorted linked list with "express lanes". Level 0 links every node; each
+// higher level links a random subset of the level below, about half as many:
+//
+//   level 2:  head ----------------------> 7 ----------------> None
+//   level 1:  head ------> 3 ------------> 7 ------> 12 -----> None
+//   level 0:  head -> 1 -> 3 -> 4 -> 6 -> 7 -> 9 -> 12 -> 15 -> None
+//
+// A search starts on the top level and drops down whenever the next step
+// would overshoot - O(log n) on average, like a balanced tree, but balanced
+// by coin flips instead of rotations.
+//
+// WHY INDICES INSTEAD OF Box: a node on level 2 is pointed to from three
+// places at once. Box<T> means exactly ONE owner, so the nodes live in a Vec
+// ("arena") and links are indices into it. Removed slots are recycled.
+// =============================================================================
+
+const MAX_LEVEL: usize = 16;  // Plenty for ~2^16 items
+
+struct SkipNode<T> {
+    value: T,
+    forward: Vec<Option<usize>>,  // forward[i] = next node on level i
+}
+
+pub struct SkipList<T: Ord> {
+    head: Vec<Option<usize>>,          // First node on each level
+    nodes: Vec<Option<SkipNode<T>>>,   // None = free slot
+    free: Vec<usize>,                  // Free slots to reuse
+    level: usize,                      // Number of levels currently in use
+    len: usize,
+}
+
+impl<T: Ord> SkipList<T> {
+    pub fn new() -> Self {
+        SkipList {
+            head: vec![None; MAX_LEVEL],
+            nodes: Vec::new(),
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let preds = self.predecessors(value);
+        self.next_matches(preds[0], value).is_some()
+    }
+
+    // Returns false (and changes nothing) if the value is already present
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut preds = self.predecessors(&value);
+        if self.next_matches(preds[0], &value).is_some() {
+            return false;
+        }
+
+        let new_level = random_level();
+        if new_level > self.level {
+            // Brand-new levels start from the head
+            for pred in &mut preds[self.level..new_level] {
+                *pred = None;
+            }
+            self.level = new_level;
+        }
+
+        // Splice in on every level the node takes part in:
+        // pred -> next  becomes  pred -> node -> next
+        let forward = (0..new_level).map(|l| self.next(preds[l], l)).collect();
+        let node = SkipNode { value, forward };
+        let index = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = Some(node);
+                slot
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        for (l, &pred) in preds.iter().enumerate().take(new_level) {
+            self.set_next(pred, l, Some(index));
+        }
+
+        self.len += 1;
+        true
+    }
+
+    // Returns false if the value wasn't there
+    pub fn remove(&mut self, value: &T) -> bool {
+        let preds = self.predecessors(value);
+        let Some(target) = self.next_matches(preds[0], value) else {
+            return false;
+        };
+
+        // Take the node out of the arena, then bypass it on each level
+        let node = self.nodes[target].take().expect("linked nodes are live");
+        for (l, &next) in node.forward.iter().enumerate() {
+            self.set_next(preds[l], l, next);
+        }
+        self.free.push(target);
+
+        // Drop levels that are now empty
+        while self.level > 1 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        self.len -= 1;
+        true
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self, next: self.head[0] }
+    }
+
+    // For each level, the last node whose value is < `value` (None = head).
+    // These are exactly the links insert/remove need to rewire.
+    fn predecessors(&self, value: &T) -> [Option<usize>; MAX_LEVEL] {
+        let mut preds = [None; MAX_LEVEL];
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(current, level) {
+                if self.node(next).value < *value {
+                    current = Some(next);  // Move right
+                } else {
+                    break;                 // Would overshoot - go down
+                }
+            }
+            preds[level] = current;
+        }
+        preds
+    }
+
+    // The node right after `pred` on level 0, if it holds `value`
+    fn next_matches(&self, pred: Option<usize>, value: &T) -> Option<usize> {
+        self.next(pred, 0).filter(|&i| self.node(i).value == *value)
+    }
+
+    fn next(&self, pred: Option<usize>, level: usize) -> Option<usize> {
+        match pred {
+            None => self.head[level],
+            Some(i) => self.node(i).forward[level],
+        }
+    }
+
+    fn set_next(&mut self, pred: Option<usize>, level: usize, target: Option<usize>) {
+        match pred {
+            None => self.head[level] = target,
+            Some(i) => self.nodes[i].as_mut().expect("linked nodes are live").forward[level] = target,
+        }
+    }
+
+    fn node(&self, index: usize) -> &SkipNode<T> {
+        self.nodes[index].as_ref().expect("linked nodes are live")
+    }
+}
+
+// Level 1 always; each extra level with probability 1/2
+fn random_level() -> usize {
+    let mut level = 1;
+    while level < MAX_LEVEL && rand::random::<f64>() < 0.5 {
+        level += 1;
+    }
+    level
+}
+
+// =============================================================================
+// In-order iteration - just walk level 0
+// =============================================================================
+
+pub struct Iter<'a, T: Ord> {
+    list: &'a SkipList<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T: Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.list.node(self.next?);
+        self.next = node.forward[0];
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SkipList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // 1000 DISTINCT random integers, in random order
+    fn random_values() -> Vec<u32> {
+        let mut seen = HashSet::new();
+        let mut values = Vec::new();
+        while values.len() < 1000 {
+            let value = rand::random::<u32>() % 1_000_000;
+            if seen.insert(value) {
+                values.push(value);
+            }
+        }
+        values
+    }
+
+    #[test]
+    fn test_insert_remove_contains() {
+        let values = random_values();
+        let mut list = SkipList::new();
+        for &value in &values {
+            assert!(list.insert(value));
+        }
+        assert_eq!(list.len(), 1000);
+        assert!(values.iter().all(|v| list.contains(v)));
+
+        let (removed, kept) = values.split_at(500);
+        for value in removed {
+            assert!(list.remove(value));
+        }
+        assert_eq!(list.len(), 500);
+        assert!(removed.iter().all(|v| !list.contains(v)));
+        assert!(kept.iter().all(|v| list.contains(v)));
+    }
+
+    #[test]
+    fn test_iter_is_sorted() {
+        let mut list = SkipList::new();
+        for value in random_values() {
+            list.insert(value);
+        }
+        let items: Vec<u32> = list.iter().copied().collect();
+        assert_eq!(items.len(), 1000);
+        assert!(items.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_duplicates_and_missing() {
+        let mut list = SkipList::new();
+        assert!(list.insert("b"));
+        assert!(!list.insert("b"));  // Already present
+        assert!(!list.remove(&"a"));
+        assert!(list.remove(&"b"));
+        assert!(list.is_empty());
+        assert_eq!(list.iter().next(), None);
+    }
+}
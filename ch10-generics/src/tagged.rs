@@ -0,0 +1,94 @@
+// =============================================================================
+// TYPE-TAGGED VALUES WITH PhantomData
+// =============================================================================
+// Tagged<T, Marker> stores only a T. The Marker type parameter exists purely
+// at compile time to record a STATE - here, whether a string was validated:
+//
+//   Tagged<String, Unvalidated>  --validate()-->  Tagged<String, Validated>
+//
+// Rust rejects unused type parameters, so the struct holds a PhantomData<Marker>:
+// a zero-sized field that tells the compiler "act as if I own a Marker".
+// size_of::<Tagged<String, Validated>>() == size_of::<String>().
+//
+// Methods can be implemented for ONE marker only, so "use an unvalidated
+// value" becomes a compile error instead of a runtime check.
+// =============================================================================
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+// Marker types: never instantiated, only used as type parameters
+pub struct Validated;
+pub struct Unvalidated;
+
+pub struct Tagged<T, Marker> {
+    value: T,
+    _marker: PhantomData<Marker>,
+}
+
+impl<T> Tagged<T, Unvalidated> {
+    // The only public constructor produces an Unvalidated value; the fields
+    // are private, so a Validated one can only come from validate()
+    pub fn new(value: T) -> Self {
+        Tagged { value, _marker: PhantomData }
+    }
+}
+
+// Reading the inner value is fine in either state
+impl<T, Marker> Deref for Tagged<T, Marker> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl Tagged<String, Validated> {
+    // Exists ONLY for the Validated tag
+    pub fn use_value(&self) -> &str {
+        &self.value
+    }
+}
+
+pub fn validate(t: Tagged<String, Unvalidated>) -> Result<Tagged<String, Validated>, String> {
+    if t.value.is_empty() {
+        return Err(String::from("value must not be empty"));
+    }
+    // Same data, new tag - no copy of the String
+    Ok(Tagged { value: t.value, _marker: PhantomData })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_string_becomes_validated() {
+        let raw = Tagged::new(String::from("hello"));
+        let checked: Tagged<String, Validated> = validate(raw).unwrap();
+        assert_eq!(checked.use_value(), "hello");
+        assert_eq!(checked.len(), 5);  // Deref gives access to String methods
+    }
+
+    #[test]
+    fn test_empty_string_is_rejected() {
+        assert!(validate(Tagged::new(String::new())).is_err());
+    }
+
+    #[test]
+    fn test_unvalidated_still_derefs() {
+        let raw = Tagged::new(String::from("raw"));
+        assert_eq!(*raw, "raw");
+
+        // Does not compile - use_value only exists for Validated:
+        //
+        // raw.use_value();
+        // ERROR: no method named `use_value` found for struct
+        //        `Tagged<String, Unvalidated>` in the current scope
+    }
+
+    #[test]
+    fn test_marker_is_zero_sized() {
+        assert_eq!(std::mem::size_of::<Tagged<String, Validated>>(), std::mem::size_of::<String>());
+    }
+}
@@ -11,7 +11,9 @@
 // 4. Syntax: 'a (apostrophe + name, 'a is convention)
 // =============================================================================
 
+use std::any::Any;
 use std::fmt::Display;
+use std::thread;
 
 fn main() {
     println!("Hello, world!");
@@ -53,6 +55,42 @@ fn main() {
         "Comparing strings!",
     );
     println!("The longest string is {result2}");
+
+    // =========================================================================
+    // MULTIPLE LIFETIMES IN ONE STRUCT
+    // =========================================================================
+    let parts: Vec<&str> = StrSplit::new("a::b::c", "::").collect();
+    println!("Split parts: {:?}", parts);
+
+    let path = String::from("usr/local/bin");
+    println!("First path segment: {}", until_char(&path, '/'));
+
+    // =========================================================================
+    // VARIANCE - WHEN ONE LIFETIME CAN STAND IN FOR ANOTHER
+    // =========================================================================
+    let local = String::from("local");
+    let short: &str = shorten_lifetime("I am 'static");
+    println!("Shortened: {short}");
+
+    call_with_long_lived(print_str, &local);
+    println!("Mixed lifetimes: {:?}", extend_with_shorter(&local));
+
+    // =========================================================================
+    // 'static BOUNDS
+    // =========================================================================
+    let greeting = String::from("hello from a thread");
+    // `move` transfers ownership: the closure now owns `greeting`, so it
+    // contains no references to main's stack and satisfies 'static
+    let handle = spawn_with_static(move || greeting.to_uppercase());
+    println!("Thread returned: {}", handle.join().unwrap());
+
+    let stored = store_forever(String::from("owned data is 'static"));
+    println!("Stored value is a String: {}", stored.is::<String>());
+
+    // =========================================================================
+    // LIFETIME ELISION - WHAT THE COMPILER FILLS IN
+    // =========================================================================
+    lifetime_elision_demo();
 }
 
 // =============================================================================
@@ -87,6 +125,123 @@ impl<'a> Person<'a> {
     }
 }
 
+// =============================================================================
+// ELISION DERIVATIONS - Each Signature Written Both Ways
+// =============================================================================
+// Each pair below is the SAME signature: first as you'd normally write it,
+// then with the lifetimes the rules above fill in. (Clippy would flag the
+// spelled-out ones as needless - that's the point, so it's allowed here.)
+
+// (1) One reference in, reference out - rule 2: the output gets the input's
+//     lifetime
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("")
+}
+
+#[allow(clippy::needless_lifetimes)]
+fn first_line_explicit<'a>(text: &'a str) -> &'a str {
+    text.lines().next().unwrap_or("")
+}
+
+struct Document {
+    title: String,
+    body: String,
+}
+
+impl Document {
+    // (2) Method returning a borrowed field - rule 3: output gets self's
+    //     lifetime
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn title_explicit<'s>(&'s self) -> &'s str {
+        &self.title
+    }
+
+    // (3) &self plus another &str - rule 1 gives them DIFFERENT lifetimes,
+    //     then rule 3 picks self's for the output. Correct here, because the
+    //     returned line always comes from self.body, never from `needle`.
+    fn line_containing(&self, needle: &str) -> &str {
+        self.body.lines().find(|line| line.contains(needle)).unwrap_or("")
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn line_containing_explicit<'s, 'n>(&'s self, needle: &'n str) -> &'s str {
+        self.body.lines().find(|line| line.contains(needle)).unwrap_or("")
+    }
+}
+
+// (4) Two reference inputs and no self: rule 1 gives two lifetimes, rule 2
+//     needs exactly one, rule 3 needs self - so NO rule says what the output
+//     borrows from, and the lifetimes must be written out:
+//
+//     fn word_after(text: &str, marker: &str) -> &str
+//     ERROR: missing lifetime specifier - "this function's return type
+//            contains a borrowed value, but the signature does not say
+//            whether it is borrowed from `text` or `marker`"
+//
+// Only `text` needs 'a; `marker` keeps its own anonymous lifetime, so the
+// caller may drop it as soon as the call returns.
+fn word_after<'a>(text: &'a str, marker: &str) -> &'a str {
+    let mut words = text.split_whitespace();
+    words.find(|w| *w == marker);
+    words.next().unwrap_or("")
+}
+
+// (5) first_word from ch04-ownership - one input, so rule 2 applies
+fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if byte == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    s  // The whole string is one word
+}
+
+// What the compiler actually checks for first_word
+#[allow(clippy::needless_lifetimes)]
+fn first_word_explicit<'a>(s: &'a str) -> &'a str {
+    let bytes = s.as_bytes();
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if byte == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    s  // The whole string is one word
+}
+
+fn lifetime_elision_demo() {
+    let text = String::from("first line\nsecond line");
+    println!("(1) first_line: {:?} == {:?}", first_line(&text), first_line_explicit(&text));
+
+    let doc = Document {
+        title: String::from("Elision"),
+        body: String::from("rule one: inputs\nrule two: one input\nrule three: self"),
+    };
+    println!("(2) title: {:?} == {:?}", doc.title(), doc.title_explicit());
+
+    let found = {
+        let needle = String::from("three");
+        doc.line_containing(&needle)
+    };  // needle is dropped here, but `found` borrows only from `doc`
+    println!("(3) line_containing: {:?} (explicit: {:?})", found, doc.line_containing_explicit("two"));
+
+    let next = {
+        let marker = String::from("the");
+        word_after("over the moon", &marker)
+    };  // Same idea: marker's lifetime never reaches the result
+    println!("(4) word_after: {:?}", next);
+
+    println!("(5) first_word: {:?} == {:?}", first_word("hello world"), first_word_explicit("hello world"));
+}
+
 // =============================================================================
 // FUNCTION WITH LIFETIME ANNOTATIONS
 // =============================================================================
@@ -113,6 +268,126 @@ where
     if x.len() > y.len() { x } else { y }
 }
 
+// =============================================================================
+// TWO LIFETIMES: StrSplit<'h, 'd>
+// =============================================================================
+// An iterator over the pieces of a string between delimiters.
+// It borrows TWO things that may live for different lengths of time:
+// - 'h: the haystack - every yielded &str points into it
+// - 'd: the delimiter - only needed while searching
+//
+// With a single lifetime ('a for both), the yielded items would be tied to
+// the SHORTER of the two, so a temporary delimiter would make the results
+// unusable once it is dropped. See until_char() below.
+
+struct StrSplit<'h, 'd> {
+    // None once the final segment has been yielded. An empty &str isn't
+    // enough to mean "done": "a::" must still yield a trailing "".
+    remainder: Option<&'h str>,
+    delimiter: &'d str,
+}
+
+impl<'h, 'd> StrSplit<'h, 'd> {
+    fn new(haystack: &'h str, delimiter: &'d str) -> Self {
+        // "" is found at index 0 of every string, so the remainder would
+        // never shrink and next() would yield "" forever
+        assert!(!delimiter.is_empty(), "StrSplit delimiter must not be empty");
+        StrSplit { remainder: Some(haystack), delimiter }
+    }
+}
+
+// Item = &'h str: results borrow from the haystack ONLY, never the delimiter
+impl<'h, 'd> Iterator for StrSplit<'h, 'd> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+        match remainder.find(self.delimiter) {
+            Some(index) => {
+                self.remainder = Some(&remainder[index + self.delimiter.len()..]);
+                Some(&remainder[..index])
+            }
+            None => {
+                // No more delimiters - the rest is the final segment
+                self.remainder = None;
+                Some(remainder)
+            }
+        }
+    }
+}
+
+// The delimiter is a local String that is dropped when this function
+// returns, yet the &str we return is still valid: it has lifetime 'h.
+fn until_char(s: &str, c: char) -> &str {
+    let delimiter = c.to_string();
+    StrSplit::new(s, &delimiter)
+        .next()
+        .expect("StrSplit always yields at least one item")
+}
+
+// =============================================================================
+// VARIANCE AND SUBTYPING
+// =============================================================================
+// Lifetimes have a subtyping relation: if 'long: 'short ("'long outlives
+// 'short"), then 'long is a SUBTYPE of 'short - a longer-lived reference
+// can be used anywhere a shorter-lived one is expected.
+//
+// Variance says how that relation carries through a type constructor:
+// - Covariant     (&'a T, Vec<T>, Box<T>):  sub -> sub      (same direction)
+// - Contravariant (fn(T) in its argument):  sub -> super    (flipped)
+// - Invariant     (&mut T in T, Cell<T>):   no substitution at all
+
+// COVARIANCE: &'long str can be returned as &'short str.
+// No conversion happens - the compiler just "forgets" some of the lifetime.
+fn shorten_lifetime<'long: 'short, 'short>(x: &'long str) -> &'short str {
+    x
+}
+
+fn print_str(s: &str) {
+    println!("print_str got: {s}");
+}
+
+// CONTRAVARIANCE: function ARGUMENTS flip the relation.
+// A function that accepts any &'short str can safely be handed a
+// &'long str, so fn(&'short str) is a subtype of fn(&'long str).
+fn call_with_long_lived<'long: 'short, 'short>(f: fn(&'short str), s: &'long str) {
+    let g: fn(&'long str) = f;  // OK: fn(&'short) can stand in for fn(&'long)
+    g(s);
+
+    // The reverse is rejected - a function that needs &'long str can't be
+    // trusted with a reference that might die sooner:
+    // let h: fn(&'short str) = g;  // ERROR: lifetime may not live long enough
+}
+
+// Vec<T> is covariant in T, so a Vec<&'static str> can be stored where a
+// Vec<&'short str> is expected: every &'static str is also a valid
+// &'short str, and the vector now just promises less about its elements.
+fn extend_with_shorter<'short>(short: &'short str) -> Vec<&'short str> {
+    let statics: Vec<&'static str> = vec!["static-a", "static-b"];
+    let mut mixed: Vec<&'short str> = statics;  // Covariance - no copy made
+    mixed.push(short);
+    mixed
+}
+
+// INVARIANCE: &'a mut T is covariant in 'a but INVARIANT in T.
+// If &mut T were covariant in T, we could write a short-lived reference
+// through a pointer typed for a long-lived one:
+//
+// struct Invariant<'a, T>(&'a mut T);
+//
+// fn overwrite<'a>(slot: Invariant<'_, &'a str>, value: &'a str) {
+//     *slot.0 = value;
+// }
+//
+// let mut forever: &'static str = "static";
+// {
+//     let temp = String::from("temporary");
+//     overwrite(Invariant(&mut forever), &temp);
+//     // ERROR: `temp` does not live long enough - 'a must be exactly
+//     // 'static here, it cannot shrink to fit `temp`
+// }
+// println!("{forever}");  // Would read freed memory if it compiled
+
 // =============================================================================
 // COMMON LIFETIME PATTERNS
 // =============================================================================
@@ -146,6 +421,44 @@ where
 // it means the reference CAN live that long if needed.
 // =============================================================================
 
+// =============================================================================
+// T: 'static AS A BOUND
+// =============================================================================
+// `T: 'static` does NOT mean "T lives forever". It means T contains no
+// references shorter than 'static - so a value of type T could be kept
+// alive as long as you like without anything dangling.
+//
+//   String, Vec<i32>, Box<u8>   -> 'static (owned, no borrows)
+//   &'static str                -> 'static
+//   &'a str (for a local 'a)    -> NOT 'static
+
+// thread::spawn requires F: 'static because the new thread may keep running
+// after the current function - even the whole caller - has returned.
+// Any borrow of a local could then point at a freed stack frame.
+fn spawn_with_static<F>(f: F) -> thread::JoinHandle<String>
+where
+    F: FnOnce() -> String + Send + 'static,
+{
+    thread::spawn(f)
+}
+
+// Without `move`, the closure BORROWS `name` and is therefore not 'static:
+//
+// let name = String::from("local");
+// let handle = spawn_with_static(|| name.clone());
+// // ERROR: closure may outlive the current function, but it borrows `name`
+// // help: to force the closure to take ownership, use the `move` keyword
+//
+// Fix: spawn_with_static(move || name.clone())
+
+// Box<dyn Any> can only hold 'static types. TypeId can't tell lifetimes
+// apart, so downcasting a borrowed value could hand back a reference that
+// claims to live longer than it really does.
+// Calling store_forever(&local_string) would fail - &'a String isn't 'static.
+fn store_forever<T: 'static>(val: T) -> Box<dyn Any + 'static> {
+    Box::new(val)
+}
+
 // =============================================================================
 // WHAT LIFETIMES DON'T DO
 // =============================================================================
@@ -159,3 +472,76 @@ where
 //     s.as_str()  // ERROR! s is dropped, reference would be invalid
 // }
 // =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_str_split() {
+        let parts: Vec<&str> = StrSplit::new("a::b::c", "::").collect();
+        assert_eq!(parts, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_str_split_no_delimiter() {
+        let parts: Vec<&str> = StrSplit::new("abc", "::").collect();
+        assert_eq!(parts, vec!["abc"]);
+    }
+
+    #[test]
+    fn test_str_split_empty_haystack() {
+        let parts: Vec<&str> = StrSplit::new("", "::").collect();
+        assert_eq!(parts, vec![""]);
+    }
+
+    #[test]
+    fn test_str_split_trailing_delimiter() {
+        let parts: Vec<&str> = StrSplit::new("a::", "::").collect();
+        assert_eq!(parts, vec!["a", ""]);
+    }
+
+    #[test]
+    #[should_panic(expected = "StrSplit delimiter must not be empty")]
+    fn test_str_split_empty_delimiter_rejected() {
+        StrSplit::new("abc", "");
+    }
+
+    #[test]
+    fn test_until_char_outlives_delimiter() {
+        assert_eq!(until_char("hello world", ' '), "hello");
+    }
+
+    #[test]
+    fn test_elided_and_explicit_forms_agree() {
+        let text = "alpha beta\ngamma";
+        assert_eq!(first_line(text), first_line_explicit(text));
+        assert_eq!(first_line(""), "");
+
+        let doc = Document { title: String::from("T"), body: String::from("x\ny z") };
+        assert_eq!(doc.title(), doc.title_explicit());
+        assert_eq!(doc.line_containing("z"), doc.line_containing_explicit("z"));
+        assert_eq!(doc.line_containing("missing"), "");
+
+        for s in ["hello world", "single", ""] {
+            assert_eq!(first_word(s), first_word_explicit(s));
+        }
+    }
+
+    #[test]
+    fn test_result_outlives_unrelated_argument() {
+        let doc = Document { title: String::from("T"), body: String::from("one\ntwo") };
+        let line = {
+            let needle = String::from("tw");
+            doc.line_containing(&needle)
+        };
+        assert_eq!(line, "two");
+
+        let word = {
+            let marker = String::from("b");
+            word_after("a b c", &marker)
+        };
+        assert_eq!(word, "c");
+        assert_eq!(word_after("a b", "b"), "");  // Marker is the last word
+    }
+}
@@ -7,9 +7,14 @@
 // 2. Constraining generic types (trait bounds)
 // 3. Returning types that implement a trait (impl Trait)
 // 4. Default implementations that can be overridden
+// 5. Static (impl Trait / generics) vs dynamic (dyn Trait) dispatch
 // =============================================================================
 
+use std::cell::Cell;
 use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use summary_derive::Summary;  // The derive macro - the Summary TRAIT is defined below
 
 fn main() {
     let news = NewsArticle {
@@ -33,16 +38,52 @@ fn main() {
 
     // Using impl Trait return type
     println!("{}", returns_trait_struct().summarize());
+
+    // Higher-rank trait bounds: the closure must work for ANY lifetime
+    println!("{}", apply_to_local(|s| s.to_uppercase()));
+
+    // With a named lifetime, the closure may keep the reference around
+    let text = String::from("borrowed from main");
+    let holder = Holder { saved: Cell::new(None) };
+    apply_with_lifetime(&text, |s| {
+        holder.saved.set(Some(s));
+        s.len().to_string()
+    });
+    println!("Holder kept: {:?}", holder.saved.get());
+
+    // Generic associated types: an iterator whose items borrow from itself
+    let mut lines = OwnedLines::new(String::from("first\nsecond\nthird"));
+    while let Some(line) = lines.next() {
+        println!("Owned line: {line}");
+    }
+    let mut borrowed = LineIter::new("alpha\nbeta");
+    while let Some(line) = borrowed.next() {
+        println!("Borrowed line: {line}");
+    }
+
+    // Static vs dynamic dispatch: same results, different call mechanics
+    dispatch_comparison();
+
+    // Blanket impl: every PartialOrd type gets clamp_to() for free
+    println!("150.clamp_to(0, 100) = {}", 150.clamp_to(0, 100));
+    println!("'z'.clamp_to('a', 'm') = {}", 'z'.clamp_to('a', 'm'));
+    println!("normalize(75, 50, 150) = {}", normalize(75, 50, 150));
 }
 
 // =============================================================================
 // DEFINING STRUCTS
 // =============================================================================
 
+// #[derive(Summary)] writes `impl Summary for NewsArticle` at compile time
+// (see summary-derive/). The format string keeps the wording of the
+// hand-written impl it replaced; #[summary(skip)] leaves the long content out.
+#[derive(Summary)]
+#[summary(format = "(Read {headline} from {location}... by {author})")]
 pub struct NewsArticle {
     pub headline: String,
     pub location: String,
     pub author: String,
+    #[summary(skip)]
     pub content: String,
 }
 
@@ -109,14 +150,8 @@ pub trait Debug: std::fmt::Debug {
 // IMPLEMENTING TRAITS FOR TYPES
 // =============================================================================
 
-// NewsArticle overrides the default summarize()
-impl Summary for NewsArticle {
-    fn summarize(&self) -> String {
-        format!("(Read {} from {}... by {})", self.headline, self.location, self.author)
-    }
-}
-
-// Tweet also overrides summarize()
+// NewsArticle's summarize() comes from #[derive(Summary)] above.
+// Tweet overrides the default summarize() by hand
 impl Summary for Tweet {
     fn summarize(&self) -> String {
         format!("{}: {}", self.username, self.content)
@@ -180,6 +215,217 @@ fn returns_trait_struct() -> impl Summary {
     }
 }
 
+// =============================================================================
+// HIGHER-RANK TRAIT BOUNDS (HRTB) - for<'a>
+// =============================================================================
+// `for<'a> Fn(&'a str) -> String` reads "for EVERY lifetime 'a, F implements
+// Fn(&'a str) -> String". The closure must handle references of any
+// lifetime - including ones that only exist inside the callee.
+
+fn apply_to_local<F>(f: F) -> String
+where
+    F: for<'a> Fn(&'a str) -> String,
+{
+    // This String lives only inside this function. No lifetime parameter
+    // on apply_to_local could name it - only for<'a> covers it.
+    let local = String::from("created inside apply_to_local");
+    f(&local)
+}
+
+// Named lifetime: the CALLER picks one specific 'a and passes data that
+// lives that long. The closure only needs to work for that one lifetime.
+fn apply_with_lifetime<'a, F: Fn(&'a str) -> String>(s: &'a str, f: F) -> String {
+    f(s)
+}
+
+// Cell lets a Fn closure (which only gets &self) store a value
+struct Holder<'a> {
+    saved: Cell<Option<&'a str>>,
+}
+
+// A closure that STORES its argument can't satisfy for<'a>: the reference
+// might die before the holder does.
+//
+// let holder = Holder { saved: Cell::new(None) };
+// apply_to_local(|s| {
+//     holder.saved.set(Some(s));  // ERROR: borrowed data escapes outside of closure
+//     s.to_uppercase()
+// });
+//
+// WHEN IS for<'a> NEEDED?
+// - Usually never written by hand: `F: Fn(&str) -> String` is sugar for
+//   `F: for<'a> Fn(&'a str) -> String` (elision in Fn sugar inserts it).
+// - Write it explicitly when the bound isn't Fn sugar, e.g.
+//   `where for<'a> &'a T: IntoIterator` - there is nowhere else to declare 'a.
+// - Use a named lifetime parameter instead when the reference comes FROM
+//   the caller and the result (or something else) must stay tied to it.
+
+// =============================================================================
+// GENERIC ASSOCIATED TYPES (GATs)
+// =============================================================================
+// A regular associated type is ONE type per impl:
+//
+//   trait Iterator { type Item; fn next(&mut self) -> Option<Self::Item>; }
+//
+// Item can't mention the lifetime of the `&mut self` borrow in next(),
+// because that lifetime is different on every call. So an Iterator can
+// never hand out a reference INTO ITSELF.
+//
+// A GAT gives the associated type its own parameter, so each call to
+// next() can pick Item<'a> with 'a = the length of that particular borrow.
+// `where Self: 'a` says the iterator must outlive any item borrowed from it.
+
+trait StreamingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>>;
+}
+
+/// Yields lines as slices of a borrowed &str
+struct LineIter<'s> {
+    data: &'s str,
+    pos: usize,
+}
+
+impl<'s> LineIter<'s> {
+    fn new(data: &'s str) -> Self {
+        LineIter { data, pos: 0 }
+    }
+}
+
+impl<'s> StreamingIterator for LineIter<'s> {
+    type Item<'a> = &'a str where Self: 'a;
+
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>> {
+        // Nothing left after the last newline means no more lines, like
+        // str::lines: "a\n" is one line, not "a" plus an empty one
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let rest = &self.data[self.pos..];
+        let end = rest.find('\n').unwrap_or(rest.len());
+        self.pos += end + 1;  // Skip past the newline (or past the end)
+        Some(&rest[..end])
+    }
+}
+
+// LineIter borrows its data, so a plain Iterator with Item = &'s str would
+// work too. The case that TRULY needs a GAT is an iterator that OWNS its
+// data: the items can only borrow from `self`, and only a GAT can name
+// that borrow's lifetime. With `type Item = &'??? str` there is no
+// lifetime to write - that's what makes this impossible without GATs.
+//
+// The trade-off: each item keeps `self` mutably borrowed, so an item must
+// be dropped before the next call. No collect() into a Vec<&str>.
+struct OwnedLines {
+    data: String,
+    pos: usize,
+}
+
+impl OwnedLines {
+    fn new(data: String) -> Self {
+        OwnedLines { data, pos: 0 }
+    }
+}
+
+impl StreamingIterator for OwnedLines {
+    type Item<'a> = &'a str;
+
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let rest = &self.data[self.pos..];
+        let end = rest.find('\n').unwrap_or(rest.len());
+        self.pos += end + 1;
+        Some(&rest[..end])  // Borrows from self.data - tied to &'a mut self
+    }
+}
+
+// =============================================================================
+// STATIC VS DYNAMIC DISPATCH - impl Trait, <T: Trait>, dyn Trait
+// =============================================================================
+// `t: &impl Transformer` is sugar for `<T: Transformer>(t: &T)` - the two
+// compile to exactly the same thing. The compiler MONOMORPHIZES: it stamps
+// out a separate copy of the function for every concrete type used, so the
+// call to transform() is a direct call that can be inlined.
+//
+// `t: &dyn Transformer` is a FAT POINTER: (data pointer, vtable pointer).
+// There is ONE copy of the function, and each transform() call looks up the
+// method in the vtable at runtime - an indirect call that usually can't be
+// inlined.
+//
+// PREFER impl Trait / generics: hot loops, small functions, when the type is
+//   known at compile time. Cost: bigger binary, longer compile times.
+// PREFER dyn Trait: mixing types in one collection (Vec<Box<dyn Trait>>),
+//   choosing the type at runtime, or keeping the binary small. Cost: an
+//   indirect call and no inlining.
+
+trait Transformer {
+    fn transform(&self, x: i32) -> i32;
+}
+
+struct Doubler;
+struct Tripler;
+
+impl Transformer for Doubler {
+    fn transform(&self, x: i32) -> i32 {
+        x.wrapping_mul(2)
+    }
+}
+
+impl Transformer for Tripler {
+    fn transform(&self, x: i32) -> i32 {
+        x.wrapping_mul(3)
+    }
+}
+
+fn apply_static<T: Transformer>(t: &T, x: i32) -> i32 {
+    t.transform(x)
+}
+
+fn apply_impl(t: &impl Transformer, x: i32) -> i32 {
+    t.transform(x)
+}
+
+fn apply_dyn(t: &dyn Transformer, x: i32) -> i32 {
+    t.transform(x)
+}
+
+const DISPATCH_CALLS: i32 = 1_000_000;
+
+// black_box hides values from the optimizer, so it can't compute the whole
+// loop at compile time. Timings are only meaningful with --release.
+fn time_calls(mut call: impl FnMut(i32) -> i32) -> Duration {
+    let start = Instant::now();
+    let mut acc = 0i32;
+    for i in 0..DISPATCH_CALLS {
+        acc = acc.wrapping_add(call(black_box(i)));
+    }
+    black_box(acc);
+    start.elapsed()
+}
+
+fn dispatch_comparison() {
+    let doubler = Doubler;
+    let tripler = Tripler;
+    println!("Doubler(21) = {}, Tripler(21) = {}", apply_static(&doubler, 21), apply_static(&tripler, 21));
+
+    let static_time = time_calls(|x| apply_static(&doubler, x));
+    let impl_time = time_calls(|x| apply_impl(&doubler, x));
+    // Passing the &dyn through black_box stops the compiler from seeing that
+    // it is always a Doubler and turning the call back into a direct one
+    let dyn_time = time_calls(|x| apply_dyn(black_box(&doubler as &dyn Transformer), x));
+
+    let relative = |d: Duration| d.as_secs_f64() / static_time.as_secs_f64().max(f64::EPSILON);
+    println!("{} calls each:", DISPATCH_CALLS);
+    println!("  <T: Transformer>  {:?} (1.00x)", static_time);
+    println!("  impl Transformer  {:?} ({:.2}x)", impl_time, relative(impl_time));
+    println!("  dyn Transformer   {:?} ({:.2}x)", dyn_time, relative(dyn_time));
+}
+
 // =============================================================================
 // TRAIT BOUNDS SYNTAX COMPARISON
 // =============================================================================
@@ -214,3 +460,142 @@ fn returns_trait_struct() -> impl Summary {
 //
 // This is why you can call .to_string() on any Display type!
 // =============================================================================
+
+// Our own blanket impl. The whole body lives in the DEFAULT method, so the
+// impl block is empty - it just says "every PartialOrd type is a Clamp".
+// (Named clamp_to because Ord::clamp already exists and would shadow it
+// for integers; f64 has an inherent clamp too.)
+trait Clamp: Sized + PartialOrd {
+    fn clamp_to(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: PartialOrd> Clamp for T {}
+
+// Clamp to [min, max], then map that range onto [0.0, 1.0]
+fn normalize<T: Clamp + Copy + Into<f64>>(v: T, min: T, max: T) -> f64 {
+    let (lo, hi) = (min.into(), max.into());
+    if hi <= lo {
+        return 0.0; // Empty range: avoid dividing by zero
+    }
+    (v.clamp_to(min, max).into() - lo) / (hi - lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_iter_yields_subslices() {
+        let text = "one\ntwo\nthree";
+        let bounds = text.as_bytes().as_ptr_range();
+        let mut iter = LineIter::new(text);
+        let mut lines = vec![];
+
+        while let Some(line) = iter.next() {
+            // Same memory as `text`, not a copy
+            assert!(bounds.contains(&line.as_ptr()));
+            // Each item borrows `iter` until the next call, so copy it out
+            lines.push(line.to_string());
+        }
+        assert_eq!(lines, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_line_iter_matches_str_lines() {
+        for text in ["", "\n", "one\n", "one\ntwo\n", "one\n\ntwo", "\n\n"] {
+            let expected: Vec<&str> = text.lines().collect();
+
+            let mut borrowed = LineIter::new(text);
+            let mut lines = vec![];
+            while let Some(line) = borrowed.next() {
+                lines.push(line.to_string());
+            }
+            assert_eq!(lines, expected, "LineIter on {text:?}");
+
+            let mut owned = OwnedLines::new(text.to_string());
+            let mut lines = vec![];
+            while let Some(line) = owned.next() {
+                lines.push(line.to_string());
+            }
+            assert_eq!(lines, expected, "OwnedLines on {text:?}");
+        }
+    }
+
+    #[test]
+    fn test_owned_lines() {
+        let mut iter = OwnedLines::new(String::from("a\nb"));
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next(), Some("b"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_derived_summary_matches_hand_written() {
+        let news = NewsArticle {
+            headline: String::from("Breaking News!"),
+            location: String::from("New York"),
+            author: String::from("Jane Doe"),
+            content: String::from("Something important happened..."),
+        };
+        // Exactly what the old hand-written impl returned
+        assert_eq!(news.summarize(), "(Read Breaking News! from New York... by Jane Doe)");
+        assert!(!news.summarize().contains(&news.content));  // Skipped field
+    }
+
+    #[test]
+    fn test_all_dispatch_styles_agree() {
+        let transformers: [&dyn Transformer; 2] = [&Doubler, &Tripler];
+        for x in [-7, 0, 1, 100] {
+            assert_eq!(apply_static(&Doubler, x), apply_impl(&Doubler, x));
+            assert_eq!(apply_static(&Tripler, x), apply_impl(&Tripler, x));
+            assert_eq!(apply_dyn(transformers[0], x), x * 2);
+            assert_eq!(apply_dyn(transformers[1], x), x * 3);
+        }
+    }
+
+    #[test]
+    fn test_clamp_to_i32() {
+        assert_eq!((-5).clamp_to(0, 10), 0);
+        assert_eq!(0.clamp_to(0, 10), 0);
+        assert_eq!(7.clamp_to(0, 10), 7);
+        assert_eq!(10.clamp_to(0, 10), 10);
+        assert_eq!(42.clamp_to(0, 10), 10);
+    }
+
+    #[test]
+    fn test_clamp_to_f64() {
+        assert_eq!((-0.5).clamp_to(0.0, 1.0), 0.0);
+        assert_eq!(0.0.clamp_to(0.0, 1.0), 0.0);
+        assert_eq!(0.25.clamp_to(0.0, 1.0), 0.25);
+        assert_eq!(1.0.clamp_to(0.0, 1.0), 1.0);
+        assert_eq!(3.5.clamp_to(0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_clamp_to_char() {
+        assert_eq!('A'.clamp_to('a', 'z'), 'a');
+        assert_eq!('a'.clamp_to('a', 'z'), 'a');
+        assert_eq!('q'.clamp_to('a', 'z'), 'q');
+        assert_eq!('z'.clamp_to('a', 'z'), 'z');
+        assert_eq!('~'.clamp_to('a', 'z'), 'z');
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(50, 50, 150), 0.0);
+        assert_eq!(normalize(100, 50, 150), 0.5);
+        assert_eq!(normalize(150, 50, 150), 1.0);
+        assert_eq!(normalize(-10, 50, 150), 0.0);
+        assert_eq!(normalize(999, 50, 150), 1.0);
+        assert_eq!(normalize(2.5f32, 0.0, 10.0), 0.25);
+        assert_eq!(normalize(3u8, 3, 3), 0.0);
+    }
+}
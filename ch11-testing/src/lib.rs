@@ -1,16 +1,25 @@
+#[derive(Debug, PartialEq)]  // Needed by assert_eq!/assert_ne! to compare and print
 pub struct Rectangle {
     pub width: u32,
     pub height: u32,
 }
 
 impl Rectangle {
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    pub fn perimeter(&self) -> u32 {
+        2 * (self.width + self.height)
+    }
+
     pub fn isLargerThan(&self, other: &Rectangle) -> bool {
         self.width * self.height > other.width * other.height
     }
 
     pub fn is_small(&self) -> bool {
         if(self.width < 10) {
-            panic!("rect small");
+            panic!("rect small: width {} is less than 10", self.width);
         }
         self.width * self.height < 100
     }
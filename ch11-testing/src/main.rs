@@ -41,4 +41,55 @@ mod test {
             Err(String::from("unexpected"))
         }
     }
-}
\ No newline at end of file
+
+    // `expected` only has to be a SUBSTRING of the panic message - the full
+    // message here is "rect small: width 3 is less than 10"
+    #[test]
+    #[should_panic(expected = "rect small")]
+    fn test_panic_partial_message() {
+        let rect = Rectangle { width: 3, height: 3 };
+        rect.is_small();
+    }
+
+    fn checked_area(rect: &Rectangle) -> Result<u32, String> {
+        if rect.width == 0 || rect.height == 0 {
+            Err(format!("degenerate rectangle: {:?}", rect))
+        } else {
+            Ok(rect.area())
+        }
+    }
+
+    // Returning Result lets a test use ? - an Err fails the test
+    #[test]
+    fn test_result_ok_and_err() -> Result<(), String> {
+        let area = checked_area(&Rectangle { width: 30, height: 50 })?;  // Ok path
+        assert_eq!(area, 1500);
+
+        let flat = Rectangle { width: 0, height: 50 };
+        match checked_area(&flat) {
+            Err(_) => Ok(()),
+            Ok(area) => Err(format!("expected an error, got area {}", area)),  // Manual Err
+        }
+    }
+
+    #[test]
+    fn test_rectangles_not_equal() {
+        let rect1 = Rectangle { width: 30, height: 50 };
+        let rect2 = Rectangle { width: 50, height: 30 };  // Same area, different shape
+        assert_ne!(rect1, rect2);
+    }
+
+    // Several assertions in one test: concise, but the FIRST failing assert
+    // panics and ends the test - any later failures stay hidden until it is
+    // fixed. Separate single-assertion tests would report all of them at once.
+    #[test]
+    fn test_rectangle_measurements() {
+        let big = Rectangle { width: 30, height: 50 };
+        let small = Rectangle { width: 10, height: 20 };
+        assert_eq!(big.area(), 1500);
+        assert_eq!(big.perimeter(), 160);
+        assert_eq!(small.perimeter(), 60);
+        assert!(big.isLargerThan(&small));
+        assert!(!small.isLargerThan(&big));
+    }
+}
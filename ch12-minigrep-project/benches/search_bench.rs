@@ -0,0 +1,74 @@
+// =============================================================================
+// BENCHMARKS - search vs search_case_insensitive
+// =============================================================================
+// Run with: cargo bench -p ch12-minigrep-project
+//
+// Criterion runs each closure many times, discards warm-up noise and reports
+// a statistically sound estimate. Setting a Throughput turns "time per
+// iteration" into bytes/second, which is easier to compare across inputs.
+//
+// black_box stops the optimizer from deleting work whose result is unused.
+//
+// What to expect: search_case_insensitive allocates a lowercased copy of
+// EVERY line (and, as written in lib.rs, of the query too, once per line),
+// so it is noticeably slower than the allocation-free case-sensitive search.
+// =============================================================================
+
+use ch12_minigrep_project::{search, search_case_insensitive};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+const LINES: usize = 10_000;
+const QUERY: &str = "Rust";
+
+// Deterministic text so runs are comparable; roughly 1 in 4 lines matches
+fn synthetic_text(lines: usize) -> String {
+    const WORDS: [&str; 8] = ["Rust", "safety", "speed", "ownership", "borrow", "trait", "lifetime", "rust"];
+    (0..lines)
+        .map(|i| format!("line {i}: {} {} {}", WORDS[i % 8], WORDS[(i * 3 + 1) % 8], WORDS[(i * 5 + 2) % 8]))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Same as search_case_insensitive, but the caller lowercases the query ONCE
+fn search_prelowercased(file: &str, lowercase_query: &str) -> Vec<String> {
+    file.lines()
+        .filter(|line| line.to_lowercase().contains(lowercase_query))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn bench_search(c: &mut Criterion) {
+    let text = synthetic_text(LINES);
+    let mut group = c.benchmark_group("search");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+
+    group.bench_function("case_sensitive", |b| {
+        b.iter(|| search(black_box(&text), black_box(QUERY)))
+    });
+    group.bench_function("case_insensitive", |b| {
+        b.iter(|| search_case_insensitive(black_box(&text), black_box(QUERY)))
+    });
+
+    group.finish();
+}
+
+// Isolates the cost of re-lowercasing the query for every line
+fn bench_lowercase_overhead(c: &mut Criterion) {
+    let text = synthetic_text(LINES);
+    let mut group = c.benchmark_group("to_lowercase_overhead");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+
+    group.bench_function("query_lowercased_per_line", |b| {
+        b.iter(|| search_case_insensitive(black_box(&text), black_box(QUERY)))
+    });
+    group.bench_function("query_lowercased_once", |b| {
+        let lowercase_query = QUERY.to_lowercase();  // Outside the timed loop
+        b.iter(|| search_prelowercased(black_box(&text), black_box(&lowercase_query)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_search, bench_lowercase_overhead);
+criterion_main!(benches);
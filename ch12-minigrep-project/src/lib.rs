@@ -29,10 +29,140 @@ pub fn search_case_insensitive(file: &str, query: &str) -> Vec<String> {
 mod test {
     use super::*;
 
+    // Vec<String> from literals, without a .to_string() on every element
+    macro_rules! vec_of_strings {
+        ($($s:expr),* $(,)?) => {
+            vec![$($s.to_string()),*]
+        };
+    }
+
+    // Like assert!(col.contains(..)) but the failure message shows the whole
+    // collection. Compares with ==, so a &str can be looked up in a Vec<String>.
+    // The optional message must be a string literal (it goes through concat!).
+    macro_rules! assert_contains {
+        ($col:expr, $item:expr $(, $msg:expr)?) => {
+            match (&$col, &$item) {
+                (col, item) => assert!(
+                    col.iter().any(|element| element == item),
+                    concat!("expected {:?} to contain {:?}" $(, ": ", $msg)?),
+                    col,
+                    item,
+                ),
+            }
+        };
+    }
+
     #[test]
     fn test_search() {
         let file = "hello world\nhello rust\nhello\n";
-        let query = "hello";
-        assert_eq!(vec!["hello world", "hello rust", "hello"], search(file, query));
+        let results = search(file, "hello");
+        assert_eq!(results, vec_of_strings!["hello world", "hello rust", "hello"]);
+        assert_contains!(results, "hello rust", "every matching line is returned");
+    }
+
+    #[test]
+    fn test_search_case_insensitive() {
+        let results = search_case_insensitive("Rust\nrusty\ntrust me\nPython", "RUST");
+        assert_eq!(results, vec_of_strings!["Rust", "rusty", "trust me"]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"expected ["hello world"] to contain "goodbye""#)]
+    fn test_assert_contains_shows_collection_on_failure() {
+        let results = search("hello world\nbye\n", "hello");
+        assert_contains!(results, "goodbye");
+    }
+}
+// Property-based tests: instead of hand-picked examples, proptest generates
+// hundreds of random inputs and checks that a PROPERTY holds for all of them.
+// When one fails, it shrinks the input to the smallest failing case.
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    // Up to 20 lines of any characters except '\n' (so unicode and '\r' too)
+    fn text_strategy() -> impl Strategy<Value = String> {
+        prop::collection::vec("[^\n]{0,30}", 0..20).prop_map(|lines| lines.join("\n"))
+    }
+
+    // A tiny alphabet makes real matches likely; \PC adds arbitrary unicode
+    fn query_strategy() -> impl Strategy<Value = String> {
+        prop_oneof!["[a-cA-C]{1,2}", "\\PC{0,5}"]
+    }
+
+    // True if results appear in lines in the same order, each line used at
+    // most once - i.e. results is a subset of lines with nothing invented or
+    // duplicated
+    fn is_subsequence(results: &[String], lines: &[&str]) -> bool {
+        let mut lines = lines.iter();
+        results.iter().all(|result| lines.any(|line| *line == result.as_str()))
+    }
+
+    proptest! {
+        #[test]
+        fn search_results_contain_query(file in text_strategy(), query in query_strategy()) {
+            for line in search(&file, &query) {
+                prop_assert!(line.contains(&query));
+            }
+        }
+
+        // The ORIGINAL line keeps its case, so compare lowercased versions
+        #[test]
+        fn case_insensitive_results_contain_lowercased_query(file in text_strategy(), query in query_strategy()) {
+            let lowercase_query = query.to_lowercase();
+            for line in search_case_insensitive(&file, &query) {
+                prop_assert!(line.to_lowercase().contains(&lowercase_query));
+            }
+        }
+
+        #[test]
+        fn results_are_a_subsequence_of_lines(file in text_strategy(), query in query_strategy()) {
+            let lines: Vec<&str> = file.lines().collect();
+            prop_assert!(is_subsequence(&search(&file, &query), &lines));
+            prop_assert!(is_subsequence(&search_case_insensitive(&file, &query), &lines));
+        }
+
+        // Duplicate lines in the file legitimately give duplicate results,
+        // so uniqueness is only checked on files whose lines are unique
+        #[test]
+        fn no_result_appears_twice(
+            lines in prop::collection::btree_set("[a-zA-Zé ]{1,10}", 0..15),
+            query in query_strategy()
+        ) {
+            let file = lines.into_iter().collect::<Vec<_>>().join("\n");
+            for results in [search(&file, &query), search_case_insensitive(&file, &query)] {
+                let unique: HashSet<&String> = results.iter().collect();
+                prop_assert_eq!(unique.len(), results.len());
+            }
+        }
+
+        // Adversarial: a query longer than every line can never match
+        #[test]
+        fn query_longer_than_any_line_matches_nothing(file in text_strategy(), suffix in "\\PC{1,5}") {
+            let query = format!("{file}{suffix}");
+            prop_assert!(search(&file, &query).is_empty());
+        }
+
+        // Adversarial: lines() strips the '\n's, so no line can contain one
+        #[test]
+        fn query_with_newline_matches_nothing(file in text_strategy(), query in "[a-z]{0,3}\n[a-z]{0,3}") {
+            prop_assert!(search(&file, &query).is_empty());
+            prop_assert!(search_case_insensitive(&file, &query).is_empty());
+        }
+
+        // Non-ASCII letters with simple upper/lowercase pairs, in both inputs
+        #[test]
+        fn unicode_matches_are_found_case_insensitively(
+            file in prop::collection::vec("[αβΑΒéÉ日本 ]{0,12}", 0..10).prop_map(|l| l.join("\n")),
+            query in "[αβΑΒéÉ日]{1,2}"
+        ) {
+            let sensitive = search(&file, &query);
+            let insensitive = search_case_insensitive(&file, &query);
+            // Every exact match is also a case-insensitive match
+            let lines: Vec<&str> = insensitive.iter().map(String::as_str).collect();
+            prop_assert!(is_subsequence(&sensitive, &lines));
+        }
     }
-}
\ No newline at end of file
+}
@@ -8,24 +8,25 @@ fn main() {
 
     match finderConfig {
         Ok(config) => {
-            println!("query is {}", config.query);
-            println!("file is {}", config.file);
-
             if let Err(e) = run(config){
                 eprintln!("error is {}", e);
                 process::exit(1);
             }
         },
-        Err(e) => eprintln!("error is {}", e)
+        Err(e) => {
+            // Bad arguments fail the same way as a failed run: message on
+            // stderr and a non-zero exit status, so scripts can detect it
+            eprintln!("error is {}", e);
+            process::exit(1);
+        }
     }
 }
 
 fn run(finder: Finder) -> Result<(), Box<dyn std::error::Error>> {
     let contents = fs::read_to_string(finder.file)?;
-    println!("contents is {}", contents);
 
+    // stdout carries only matching lines, so the output can be piped
     if (finder.isSensitive) {
-        println!("case sensitive");
         for line in search(&contents, &finder.query) {
             println!("{}", line);
         }
@@ -56,7 +57,8 @@ impl Finder{
             None => return Err(String::from("no query"))
         };
 
-        let isSensitive = env::var("IGNORE_CASE").is_ok();
+        // Case sensitive unless IGNORE_CASE is set (to any value)
+        let isSensitive = env::var("IGNORE_CASE").is_err();
 
         Ok(Finder {
             query: query,
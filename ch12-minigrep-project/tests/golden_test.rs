@@ -0,0 +1,46 @@
+// =============================================================================
+// GOLDEN-FILE TESTS - Searching Several Files at Once
+// =============================================================================
+// Each test formats its results as `<file>:<line>` and compares the whole
+// output with a committed file under tests/golden/ (see test_helpers).
+
+mod test_helpers;
+
+use ch12_minigrep_project::{search, search_case_insensitive};
+use test_helpers::assert_golden;
+
+// Fixtures are inline so the scenario doesn't depend on the file system
+const FILES: [(&str, &str); 3] = [
+    (
+        "poem.txt",
+        "I'm nobody! Who are you?\nAre you nobody, too?\nThen there's a pair of us - don't tell!\n",
+    ),
+    (
+        "rust.txt",
+        "Rust is fast.\nRust is safe.\nTrust the borrow checker.\n",
+    ),
+    (
+        "todo.txt",
+        "write tests\nreview PR\nrun the benchmarks\n",
+    ),
+];
+
+fn search_all(query: &str, search_fn: fn(&str, &str) -> Vec<String>) -> String {
+    let mut output = String::new();
+    for (name, contents) in FILES {
+        for line in search_fn(contents, query) {
+            output.push_str(&format!("{name}:{line}\n"));
+        }
+    }
+    output
+}
+
+#[test]
+fn three_file_search() {
+    assert_golden("three_file_search", &search_all("ust", search));
+}
+
+#[test]
+fn three_file_search_case_insensitive() {
+    assert_golden("three_file_search_case_insensitive", &search_all("ARE", search_case_insensitive));
+}
@@ -0,0 +1,79 @@
+// =============================================================================
+// INTEGRATION TESTS - Running the Real minigrep Binary
+// =============================================================================
+// Files in tests/ are compiled as separate crates and only see the public
+// API - here we go one step further and launch the compiled binary itself,
+// exactly as a user would from the shell.
+//
+// CARGO_BIN_EXE_<name> is set by Cargo for integration tests and points at
+// the freshly built binary.
+//
+// Fixture files are tempfile::NamedTempFile values: the file is deleted when
+// the value is dropped, which also happens while unwinding from a failed
+// assert - so no test leaves files behind.
+// =============================================================================
+
+use std::io::Write;
+use std::process::{Command, Output};
+use tempfile::NamedTempFile;
+
+const POEM: &str = "\
+I'm nobody! Who are you?
+Are you nobody, too?
+Then there's a pair of us - don't tell!
+They'd banish us, you know.
+How dreary to be somebody!
+";
+
+fn fixture(contents: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("create temp file");
+    file.write_all(contents.as_bytes()).expect("write temp file");
+    file
+}
+
+// Arguments are <file> <query>; IGNORE_CASE is cleared unless a test sets it,
+// so the developer's own shell environment can't change the results
+fn minigrep(args: &[&str], ignore_case: bool) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_ch12-minigrep-project"));
+    command.args(args).env_remove("IGNORE_CASE");
+    if ignore_case {
+        command.env("IGNORE_CASE", "1");
+    }
+    command.output().expect("failed to run minigrep")
+}
+
+#[test]
+fn prints_exactly_the_matching_lines() {
+    let file = fixture(POEM);
+    let output = minigrep(&[file.path().to_str().unwrap(), "nobody"], false);
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "I'm nobody! Who are you?\nAre you nobody, too?\n"
+    );
+}
+
+#[test]
+fn missing_arguments_fail_with_an_error() {
+    let output = minigrep(&[], false);
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("error"));
+}
+
+#[test]
+fn ignore_case_env_var_enables_case_insensitive_search() {
+    let file = fixture(POEM);
+    let path = file.path().to_str().unwrap();
+
+    let sensitive = minigrep(&[path, "are"], false);
+    assert_eq!(String::from_utf8_lossy(&sensitive.stdout), "I'm nobody! Who are you?\n");
+
+    let insensitive = minigrep(&[path, "are"], true);
+    assert_eq!(
+        String::from_utf8_lossy(&insensitive.stdout),
+        "I'm nobody! Who are you?\nAre you nobody, too?\n"
+    );
+}
@@ -0,0 +1,68 @@
+// =============================================================================
+// GOLDEN-FILE TESTING HELPERS
+// =============================================================================
+// A golden file stores the expected output of a test. The test produces its
+// output and compares it with tests/golden/<test_name>.txt.
+//
+// When output changes ON PURPOSE, regenerate the files instead of editing
+// them by hand, then review the change with `git diff`:
+//
+//   UPDATE_GOLDEN=1 cargo test -p ch12-minigrep-project
+//
+// This lives in tests/test_helpers/mod.rs rather than tests/test_helpers.rs
+// so that Cargo doesn't compile it as a test crate of its own.
+// =============================================================================
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn golden_path(test_name: &str) -> PathBuf {
+    // CARGO_MANIFEST_DIR keeps the path correct whatever directory the
+    // tests are run from
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{test_name}.txt"))
+}
+
+pub fn assert_golden(test_name: &str, actual: &str) {
+    let path = golden_path(test_name);
+
+    if env::var("UPDATE_GOLDEN").is_ok_and(|value| value == "1") {
+        fs::create_dir_all(path.parent().unwrap()).expect("create golden directory");
+        fs::write(&path, actual).expect("write golden file");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!("cannot read {}: {e}\nrun with UPDATE_GOLDEN=1 to create it", path.display())
+    });
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    if expected_lines == actual_lines {
+        return;
+    }
+
+    // Diff-style report: "-" is the golden file, "+" is what the test produced
+    let mut diff = String::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(i);
+        let actual_line = actual_lines.get(i);
+        if expected_line == actual_line {
+            continue;
+        }
+        diff.push_str(&format!("line {}:\n", i + 1));
+        if let Some(line) = expected_line {
+            diff.push_str(&format!("  - {line}\n"));
+        }
+        if let Some(line) = actual_line {
+            diff.push_str(&format!("  + {line}\n"));
+        }
+    }
+    panic!(
+        "output does not match golden file {}\n{diff}run with UPDATE_GOLDEN=1 to accept the new output",
+        path.display()
+    );
+}
@@ -0,0 +1,280 @@
+// =============================================================================
+// CHAPTER 13: CLOSURES - Anonymous Functions That Capture Their Environment
+// =============================================================================
+// A closure is a function you can store in a variable or pass around, and
+// unlike a fn item it can use variables from the scope where it's created.
+//
+// KEY CONCEPTS:
+// 1. Closures capture variables the LEAST restrictive way that works:
+//    by shared reference, then by mutable reference, then by value
+// 2. `move` forces capture by value, so the closure owns its data
+// 3. What the body DOES decides which of FnOnce / FnMut / Fn it implements
+// =============================================================================
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== Chapter 13: Closures ===\n");
+
+    // =========================================================================
+    // PART 1: CAPTURE MODES
+    // =========================================================================
+    capture_modes();
+
+    // =========================================================================
+    // PART 2: FnOnce, FnMut AND Fn
+    // =========================================================================
+    fn_trait_hierarchy();
+}
+
+// =============================================================================
+// PART 1: CAPTURE MODES - Borrow, Borrow Mutably, or Move
+// =============================================================================
+// The compiler looks at what the closure BODY does with each variable:
+//
+//   only reads it        -> captures &x       (x still usable outside)
+//   modifies it          -> captures &mut x   (x locked while closure lives)
+//   `move` keyword       -> captures x itself (x moved into the closure)
+//
+// The rules are the ordinary borrowing rules - the closure is just a struct
+// holding those references or values.
+
+// Accepts only closures that borrow nothing short-lived. A closure that
+// holds a reference to a local can't be 'static, since the local dies first.
+fn requires_static<F: Fn() + 'static>(f: F) {
+    f();
+}
+
+// The returned closure owns a clone of the Rc. Cell lets it change the value
+// through that SHARED handle, so the caller can watch it between calls -
+// impossible with a plain `|| count += 1`, which locks `count` entirely.
+fn shared_incrementer(counter: Rc<Cell<i32>>) -> impl FnMut() {
+    move || counter.set(counter.get() + 1)
+}
+
+fn capture_modes() {
+    println!("--- Part 1: Capture Modes ---\n");
+
+    // 1. Immutable capture: the closure holds &x
+    let x = 5;
+    let print_x = || println!("closure sees x = {x}");
+    print_x();
+    println!("x is still usable outside: {x}");  // Shared borrows can coexist
+    print_x();
+
+    // 2. Mutable capture: the closure holds &mut count, so it must be `mut`
+    let mut count = 0;
+    let mut increment = || count += 1;
+    increment();
+    // println!("{count}");  // ERROR: cannot borrow `count` as immutable
+    //                       //        because it is also borrowed as mutable
+    increment();
+    println!("count after two calls: {count}");  // OK - increment's last use was above
+
+    // 3. Move capture: the closure OWNS name
+    let name = String::from("Ferris");
+    let greet = move || println!("hello, {name}");
+    greet();
+    // println!("{name}");  // ERROR: borrow of moved value: `name`
+
+    // 'static bound: only a move closure can take a local along with it
+    let local = 42;
+    requires_static(move || println!("move closure owns its copy of local = {local}"));
+    // requires_static(|| println!("{local}"));
+    // ERROR: closure may outlive the current function, but it borrows `local`
+    //        help: use the `move` keyword
+    requires_static(|| println!("capturing nothing is fine too"));
+
+    // Shared state the closure AND the caller can see
+    let counter = Rc::new(Cell::new(0));
+    let mut bump = shared_incrementer(Rc::clone(&counter));
+    bump();
+    bump();
+    println!("counter seen from outside: {}", counter.get());
+
+    println!();
+}
+
+// =============================================================================
+// PART 2: FnOnce, FnMut AND Fn - What a Caller May Do With a Closure
+// =============================================================================
+// The three traits form a hierarchy:
+//
+//   FnOnce  - can be called at least once       (every closure)
+//     ^
+//   FnMut   - can be called repeatedly, may mutate its captures
+//     ^
+//   Fn      - can be called repeatedly, even through a shared &
+//
+// Every Fn is also FnMut, and every FnMut is also FnOnce. So a bound of
+// FnOnce accepts the MOST closures, and a bound of Fn the fewest - pick the
+// loosest bound your function actually needs.
+
+// Calls f exactly once, so any closure at all is accepted
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// Calls f n times; f may change its own state between calls
+fn call_mut<F: FnMut() -> i32>(mut f: F, n: usize) -> Vec<i32> {
+    (0..n).map(|_| f()).collect()
+}
+
+// f only needs &self, so it could even be shared between threads or calls
+fn call_fn<F: Fn(i32) -> i32>(f: F, inputs: &[i32]) -> Vec<i32> {
+    inputs.iter().map(|&x| f(x)).collect()
+}
+
+// A plain function: no captures, so it implements all three traits
+fn square(x: i32) -> i32 {
+    x * x
+}
+
+fn fn_trait_hierarchy() {
+    println!("--- Part 2: FnOnce, FnMut and Fn ---\n");
+
+    // FnOnce only: the body gives away the String it owns, so a second call
+    // would have nothing left to return
+    let message = String::from("moved out of the closure");
+    let consume = move || message;
+    println!("call_once: {}", call_once(consume));
+    // call_once(consume);  // ERROR: use of moved value: `consume`
+
+    // FnMut but not Fn: the body modifies its captured counter
+    let mut next = 0;
+    let counter = move || {
+        next += 1;
+        next
+    };
+    println!("call_mut x3: {:?}", call_mut(counter, 3));
+
+    // Fn: reads its capture but never changes it
+    let offset = 100;
+    println!("call_fn with closure: {:?}", call_fn(|x| x + offset, &[1, 2, 3]));
+
+    // A fn item fits every bound - Fn here, FnMut for Iterator::map,
+    // FnOnce for Option::map
+    println!("call_fn with square: {:?}", call_fn(square, &[1, 2, 3]));
+    println!("Iterator::map(square): {:?}", (4..=6).map(square).collect::<Vec<_>>());
+    println!("Option::map(square): {:?}", "7".parse().ok().map(square));
+
+    println!();
+}
+
+// =============================================================================
+// KEY CONCEPTS SUMMARY
+// =============================================================================
+//
+// | Body does       | Captured as | Outer variable while closure lives  |
+// |-----------------|-------------|-------------------------------------|
+// | reads x         | &x          | readable                            |
+// | modifies x      | &mut x      | locked until the closure's last use |
+// | move || ...     | x (owned)   | gone (unless x is Copy)             |
+//
+// 'static closures can't borrow locals - add `move` so they own them.
+//
+// | Trait   | Body may               | Callable        | Call takes  |
+// |---------|------------------------|-----------------|-------------|
+// | FnOnce  | move captures out      | once            | self        |
+// | FnMut   | mutate captures        | many times      | &mut self   |
+// | Fn      | only read captures     | many, shared    | &self       |
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_incrementer_visible_after_each_call() {
+        let counter = Rc::new(Cell::new(0));
+        let mut bump = shared_incrementer(Rc::clone(&counter));
+
+        for expected in 1..=3 {
+            bump();
+            assert_eq!(counter.get(), expected);
+        }
+    }
+
+    #[test]
+    fn test_move_closure_owns_its_capture() {
+        let counter = Rc::new(Cell::new(10));
+        let bump = shared_incrementer(Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 2);  // One clone lives in `bump`
+
+        drop(bump);
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert_eq!(counter.get(), 10);
+    }
+
+    #[test]
+    fn test_mutable_capture_releases_after_last_use() {
+        let mut count = 0;
+        let mut increment = || count += 1;
+        increment();
+        increment();
+        increment();
+        assert_eq!(count, 3);
+    }
+
+    // Compile-time probes: each only accepts closures implementing one trait
+    fn accepts_fn_once<F: FnOnce(i32) -> i32>(f: F) -> i32 {
+        f(3)
+    }
+
+    fn accepts_fn_mut<F: FnMut(i32) -> i32>(mut f: F) -> i32 {
+        f(3)
+    }
+
+    fn accepts_fn<F: Fn(i32) -> i32>(f: F) -> i32 {
+        f(3)
+    }
+
+    #[test]
+    fn test_consuming_closure_is_fn_once() {
+        let owned = String::from("gone");
+        let consume = move || owned;
+        assert_eq!(call_once(consume), "gone");
+
+        // Moving a capture out means it can't be called twice, so it can't be
+        // passed where repeated calls are allowed:
+        //
+        // let name = String::from("x");
+        // let consume = move || { drop(name); 1 };
+        // call_mut(consume, 2);
+        // ERROR: expected a closure that implements the `FnMut` trait, but
+        //        this closure only implements `FnOnce`
+    }
+
+    #[test]
+    fn test_counter_closure_is_fn_mut() {
+        let mut count = 0;
+        let counter = || {
+            count += 10;
+            count
+        };
+        assert_eq!(call_mut(counter, 3), vec![10, 20, 30]);
+        assert_eq!(count, 30);  // Captured by &mut, so the change is visible
+
+        let mut total = 0;
+        assert_eq!(accepts_fn_mut(|x| { total += x; total }), 3);
+        // accepts_fn(|x| { total += x; total });
+        // ERROR: cannot assign to `total`, as it is a captured variable in a
+        //        `Fn` closure
+    }
+
+    #[test]
+    fn test_call_fn_maps_every_input() {
+        let factor = 3;
+        assert_eq!(call_fn(|x| x * factor, &[1, -2, 0]), vec![3, -6, 0]);
+        assert_eq!(call_fn(square, &[]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_pure_function_implements_all_three() {
+        assert_eq!(accepts_fn_once(square), 9);
+        assert_eq!(accepts_fn_mut(square), 9);
+        assert_eq!(accepts_fn(square), 9);
+        assert_eq!(call_fn(square, &[2, 3]), vec![4, 9]);
+    }
+}
@@ -0,0 +1,198 @@
+// =============================================================================
+// CHAPTER 15: REFERENCE CYCLES - A Tree with Weak Parent Links
+// =============================================================================
+// In a tree, parents own their children, but children also want to reach
+// their parent. If both links were Rc, parent and child would keep each
+// other alive forever (a reference cycle = a memory leak).
+//
+// The fix: make ownership point ONE way.
+// 1. parent -> child:  Rc<Node>    (strong: keeps the child alive)
+// 2. child -> parent:  Weak<Node>  (weak: doesn't keep the parent alive)
+//
+// A Weak must be upgrade()d to an Option<Rc<T>> before use, and the upgrade
+// fails once every strong reference is gone.
+// =============================================================================
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+struct Node {
+    value: i32,
+    parent: RefCell<Weak<Node>>,        // Weak::new() for the root
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+fn main() {
+    println!("=== Chapter 15: Reference Cycles ===\n");
+
+    // =========================================================================
+    // PART 1: PARENT AND CHILD LINKS
+    // =========================================================================
+    tree_links();
+}
+
+// =============================================================================
+// PART 1: PARENT AND CHILD LINKS - Walking Up the Tree
+// =============================================================================
+
+fn new_root(value: i32) -> Rc<Node> {
+    Rc::new(Node {
+        value,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(vec![]),
+    })
+}
+
+// Creates a child, hands parent a strong reference to it, and gives the
+// child a weak reference back. The returned Rc is a SECOND strong reference
+// to the child (the first lives in parent.children).
+fn add_child(parent: &Rc<Node>, value: i32) -> Rc<Node> {
+    let child = Rc::new(Node {
+        value,
+        parent: RefCell::new(Rc::downgrade(parent)),
+        children: RefCell::new(vec![]),
+    });
+    parent.children.borrow_mut().push(Rc::clone(&child));
+    child
+}
+
+// None only for the root. A root's parent is Weak::new(), and all
+// Weak::new() values compare equal with ptr_eq - that's how we tell "no
+// parent" apart from "parent already dropped".
+fn parent_of(node: &Rc<Node>) -> Option<Rc<Node>> {
+    let parent = node.parent.borrow();
+    if parent.ptr_eq(&Weak::new()) {
+        return None;
+    }
+    // A child is kept alive by its parent's `children`, so a live child with
+    // a dead parent means someone detached it without clearing the link
+    Some(parent.upgrade().expect("parent was dropped while its child is still in use"))
+}
+
+// Follows parent links up to the root
+fn root(node: &Rc<Node>) -> Rc<Node> {
+    let mut current = Rc::clone(node);
+    while let Some(parent) = parent_of(&current) {
+        current = parent;
+    }
+    current
+}
+
+// Number of parent hops to the root (the root itself is depth 0)
+fn depth(node: &Rc<Node>) -> usize {
+    let mut hops = 0;
+    let mut current = Rc::clone(node);
+    while let Some(parent) = parent_of(&current) {
+        hops += 1;
+        current = parent;
+    }
+    hops
+}
+
+fn print_counts(label: &str, node: &Rc<Node>) {
+    println!(
+        "  {label} (value {}): strong = {}, weak = {}",
+        node.value,
+        Rc::strong_count(node),
+        Rc::weak_count(node)
+    );
+}
+
+fn tree_links() {
+    println!("--- Part 1: Parent and Child Links ---\n");
+
+    let trunk = new_root(1);
+    let branch = add_child(&trunk, 2);
+    let leaf = add_child(&branch, 3);
+    add_child(&branch, 4);  // Owned only by branch.children
+
+    println!("leaf depth = {}, root value = {}", depth(&leaf), root(&leaf).value);
+    println!("Counts with the whole tree alive:");
+    print_counts("trunk", &trunk);    // strong 1: just `trunk`; weak 1: branch's parent link
+    print_counts("branch", &branch);  // strong 2: `branch` + trunk.children; weak 2: two children
+    print_counts("leaf", &leaf);      // strong 2: `leaf` + branch.children; weak 0
+
+    // Detach the branch subtree: trunk stops owning it, then drop our handle
+    trunk.children.borrow_mut().clear();
+    drop(branch);
+
+    println!("After dropping the branch subtree:");
+    print_counts("trunk", &trunk);    // weak 0: the branch that pointed here is gone
+    print_counts("leaf", &leaf);      // strong 1: only our `leaf` handle is left
+    println!("leaf's parent upgrade: {:?}", leaf.parent.borrow().upgrade().map(|p| p.value));
+
+    println!();
+}
+
+// =============================================================================
+// KEY CONCEPTS SUMMARY
+// =============================================================================
+//
+// | Link            | Type       | Counted in    | Keeps target alive? |
+// |-----------------|------------|---------------|---------------------|
+// | parent -> child | Rc<Node>   | strong_count  | yes                 |
+// | child -> parent | Weak<Node> | weak_count    | no                  |
+//
+// - Rc::downgrade(&rc) makes a Weak; weak.upgrade() gives Option<Rc<T>>
+// - A value is dropped when strong_count hits 0, whatever weak_count is
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // root(1) -> mid(2) -> leaf(3)
+    fn three_levels() -> (Rc<Node>, Rc<Node>, Rc<Node>) {
+        let top = new_root(1);
+        let mid = add_child(&top, 2);
+        let leaf = add_child(&mid, 3);
+        (top, mid, leaf)
+    }
+
+    #[test]
+    fn test_root_and_depth_from_each_level() {
+        let (top, mid, leaf) = three_levels();
+        assert!(Rc::ptr_eq(&root(&leaf), &top));
+        assert!(Rc::ptr_eq(&root(&mid), &top));
+        assert!(Rc::ptr_eq(&root(&top), &top));
+        assert_eq!((depth(&top), depth(&mid), depth(&leaf)), (0, 1, 2));
+    }
+
+    #[test]
+    fn test_add_child_links_both_ways() {
+        let (top, mid, _leaf) = three_levels();
+        assert_eq!(top.children.borrow().len(), 1);
+        assert!(Rc::ptr_eq(&top.children.borrow()[0], &mid));
+        assert!(Rc::ptr_eq(&mid.parent.borrow().upgrade().unwrap(), &top));
+    }
+
+    #[test]
+    fn test_counts_before_and_after_dropping_subtree() {
+        let (top, mid, leaf) = three_levels();
+        assert_eq!((Rc::strong_count(&top), Rc::weak_count(&top)), (1, 1));
+        assert_eq!((Rc::strong_count(&mid), Rc::weak_count(&mid)), (2, 1));
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (2, 0));
+
+        // root() holds temporary clones only while it runs
+        root(&leaf);
+        assert_eq!(Rc::strong_count(&top), 1);
+
+        top.children.borrow_mut().clear();
+        assert_eq!(Rc::strong_count(&mid), 1);  // Only our handle now
+
+        drop(mid);  // Frees mid, which drops its `children` Vec too
+        assert_eq!((Rc::strong_count(&top), Rc::weak_count(&top)), (1, 0));
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (1, 0));
+        assert!(leaf.parent.borrow().upgrade().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "parent was dropped while its child is still in use")]
+    fn test_root_panics_on_dangling_parent() {
+        let (top, mid, leaf) = three_levels();
+        top.children.borrow_mut().clear();
+        drop(mid);
+        root(&leaf);
+    }
+}
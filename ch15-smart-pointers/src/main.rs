@@ -9,14 +9,21 @@
 // 2. Rc<T>      - Reference counting for multiple ownership
 // 3. RefCell<T> - Interior mutability (runtime borrow checking)
 // 4. Weak<T>    - Non-owning reference to prevent cycles
+// 5. Cow<T>     - Borrow when possible, clone only when modifying
+// 6. Arc<T>     - Shared, immutable structure in a persistent list
+// 7. Audit<T>   - A custom smart pointer that records every change
 // =============================================================================
 
 mod lib;  // Reference cycle demonstration in lib.rs
 use crate::lib::List::{Cons, Nil};
 
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{BorrowMutError, RefCell};
+use std::mem;
+use std::sync::Arc;
+use std::thread;
 
 // =============================================================================
 // TRAIT FOR DEMONSTRATING INTERIOR MUTABILITY
@@ -202,6 +209,107 @@ fn main() {
 
     // WARNING: This would cause stack overflow due to infinite cycle:
     // println!("ref_1 next item = {:?}", ref_1.tail());
+
+    // =========================================================================
+    // PART 10: Cow<T> - Clone on Write
+    // =========================================================================
+    // Cow ("clone on write") is an enum holding EITHER a borrow or an owned
+    // value:
+    //   Cow::Borrowed(&'a B)  - no allocation, just points at existing data
+    //   Cow::Owned(B::Owned)  - e.g. a String for str, a Vec<u8> for [u8]
+    //
+    // A function that only SOMETIMES needs to modify its input can return
+    // Cow and skip the allocation in the common "already fine" case.
+    // Cow implements Deref, so callers use it like a &str / &[u8] either way.
+
+    let shout = ensure_uppercase(Cow::Borrowed("ALREADY LOUD"));
+    assert!(matches!(shout, Cow::Borrowed(_)));  // Nothing was copied
+    println!("{:?}", shout);
+
+    let quiet = ensure_uppercase(Cow::Borrowed("make me loud"));
+    assert!(matches!(quiet, Cow::Owned(_)));  // A new String was allocated
+    println!("{:?}", quiet);
+
+    let terminated = process_bytes(Cow::Borrowed(b"data\0"));
+    let unterminated = process_bytes(Cow::Borrowed(b"data"));
+    println!("{:?} {:?}", terminated, unterminated);
+
+    // =========================================================================
+    // PART 11: Rc::try_unwrap / Arc::try_unwrap - Getting Ownership Back
+    // =========================================================================
+    // Rc::try_unwrap(rc) moves the value OUT of the Rc, but only if this is
+    // the last strong reference:
+    //   strong_count == 1  -> Ok(value)   (no clone needed)
+    //   strong_count  > 1  -> Err(rc)     (the Rc is handed back untouched)
+
+    let greeting = Rc::new(String::from("hello"));
+    let extra_owner = Rc::clone(&greeting);
+    let greeting = Rc::try_unwrap(greeting).unwrap_err();  // 2 owners: Err
+    drop(extra_owner);
+    let owned: String = Rc::try_unwrap(greeting).unwrap();  // 1 owner: Ok
+    println!("Recovered owned String: {}", owned);
+
+    // A builder that shares lines while building, then wants owned Strings
+    let header = Rc::new(String::from("# Report"));
+    let mut report = ReportBuilder::new();
+    report.add(Rc::clone(&header));
+    report.add(Rc::new(String::from("all systems nominal")));
+    drop(header);  // Now every line has exactly one owner: the builder
+    println!("Report lines: {:?}", report.finish());
+
+    // Arc works the same across threads: once every worker has been joined,
+    // their clones are gone and the original data can be taken back
+    let numbers = Arc::new(vec![1, 2, 3, 4, 5, 6]);
+    let handles: Vec<_> = (0..2)
+        .map(|half| {
+            let numbers = Arc::clone(&numbers);
+            thread::spawn(move || numbers[half * 3..half * 3 + 3].iter().sum::<i32>())
+        })
+        .collect();
+    let sums: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    let numbers: Vec<i32> = Arc::try_unwrap(numbers).expect("all threads joined");
+    println!("Half sums {:?} of {:?} - Vec recovered without cloning", sums, numbers);
+
+    // =========================================================================
+    // PART 12: Persistent List - Sharing Structure with Arc
+    // =========================================================================
+    // A persistent (immutable) list is never modified: prepend() builds a NEW
+    // node pointing at the old list, which stays valid and unchanged.
+    // Because nothing is ever mutated, lists can SHARE their tails:
+    //
+    //   three: 3 --+
+    //              +--> 2 --> 1 --> Nil
+    //   four:  4 --+
+    //
+    // Arc (not Rc) makes the shared nodes usable from several threads.
+
+    let shared_tail = Arc::new(PersistentList::Nil).prepend(1).prepend(2);
+    let three = shared_tail.prepend(3);
+    let four = shared_tail.prepend(4);
+    println!("heads: {:?} and {:?}", three.head(), four.head());
+    println!("three: {:?}", three.to_vec());
+    println!("four:  {:?}", four.to_vec());
+    println!(
+        "tails are the same allocation? {}",
+        Arc::ptr_eq(three.tail().unwrap(), four.tail().unwrap())
+    );
+    println!("[2, 1] is shared by {} owners", Arc::strong_count(&shared_tail));
+
+    // =========================================================================
+    // PART 13: Audit<T> - A Smart Pointer with a Change Log
+    // =========================================================================
+    // Audit<T> derefs to T for reading, but has no DerefMut: the ONLY way to
+    // change the value is set(), which records why. The log sits in a
+    // RefCell, so notes can also be added through a shared &Audit<T>.
+
+    let mut balance = Audit::new(100);
+    balance.set(80, "withdrawal");
+    balance.annotate("checked by teller").expect("no log guard is alive here");
+    balance.set(130, "deposit");
+    println!("balance: {} (via Deref: {})", balance.get(), *balance);
+    for entry in balance.get_log().iter() {
+        println!("  log: {}", entry);
+    }
 }
 
 // =============================================================================
@@ -242,6 +350,250 @@ mod tests {
         tracker.set_value(90);
         assert_eq!(mock.msgs.borrow().len(), 1);
     }
+
+    // A Messenger that wraps ANOTHER Messenger (a decorator): it records
+    // everything it sees, but only forwards messages that contain none of
+    // the blocked keywords. Both layers can then be checked independently.
+    struct SnoopyMessenger<M: Messenger> {
+        inner: RefCell<Box<M>>,
+        log: RefCell<Vec<String>>,
+        blocked_keywords: Vec<String>,
+    }
+
+    impl<M: Messenger> SnoopyMessenger<M> {
+        fn new(inner: M, blocked_keywords: &[&str]) -> Self {
+            SnoopyMessenger {
+                inner: RefCell::new(Box::new(inner)),
+                log: RefCell::new(vec![]),
+                blocked_keywords: blocked_keywords.iter().map(|k| k.to_string()).collect(),
+            }
+        }
+    }
+
+    impl<M: Messenger> Messenger for SnoopyMessenger<M> {
+        fn send(&self, msg: &str) {
+            self.log.borrow_mut().push(msg.to_string());  // Log EVERY message
+
+            let blocked = self.blocked_keywords.iter().any(|k| msg.contains(k.as_str()));
+            if !blocked {
+                self.inner.borrow().send(msg);
+            }
+        }
+    }
+
+    #[test]
+    fn test_snoopy_passes_allowed_messages_through() {
+        let snoopy = SnoopyMessenger::new(MockMessenger::new(), &["exceeded"]);
+        let mut tracker = Email::new(&snoopy, 100);
+        tracker.set_value(90);
+
+        assert_eq!(*snoopy.log.borrow(), vec!["Email quota at 90%"]);
+        assert_eq!(*snoopy.inner.borrow().msgs.borrow(), vec!["Email quota at 90%"]);
+    }
+
+    #[test]
+    fn test_snoopy_drops_blocked_messages() {
+        let snoopy = SnoopyMessenger::new(MockMessenger::new(), &["exceeded"]);
+        let mut tracker = Email::new(&snoopy, 100);
+        tracker.set_value(150);
+
+        assert_eq!(*snoopy.log.borrow(), vec!["Email quota exceeded"]);
+        assert!(snoopy.inner.borrow().msgs.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_snoopy_counts_differ_when_some_blocked() {
+        let snoopy = SnoopyMessenger::new(MockMessenger::new(), &["exceeded", "low"]);
+        let mut tracker = Email::new(&snoopy, 100);
+        for value in [10, 90, 150, 90] {
+            tracker.set_value(value);
+        }
+
+        let logged = snoopy.log.borrow().len();
+        let delivered = snoopy.inner.borrow().msgs.borrow().len();
+        assert_eq!(logged, 4);
+        assert_eq!(delivered, 2);  // Only the two "90%" messages got through
+        assert_ne!(logged, delivered);
+    }
+
+    #[test]
+    fn test_ensure_uppercase_borrows_when_unchanged() {
+        let result = ensure_uppercase(Cow::Borrowed("HELLO, WORLD 42"));
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(result, "HELLO, WORLD 42");
+    }
+
+    #[test]
+    fn test_ensure_uppercase_allocates_when_changed() {
+        let result = ensure_uppercase(Cow::Borrowed("Hello"));
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "HELLO");
+    }
+
+    #[test]
+    fn test_process_bytes_borrowed_and_owned() {
+        let already = process_bytes(Cow::Borrowed(&[1, 2, SENTINEL]));
+        assert!(matches!(already, Cow::Borrowed(_)));
+        assert_eq!(&*already, &[1, 2, SENTINEL]);
+
+        let appended = process_bytes(Cow::Borrowed(&[1, 2]));
+        assert!(matches!(appended, Cow::Owned(_)));
+        assert_eq!(&*appended, &[1, 2, SENTINEL]);
+
+        assert_eq!(&*process_bytes(Cow::Owned(vec![])), &[SENTINEL]);
+    }
+
+    #[test]
+    fn test_try_unwrap_ok_with_single_owner() {
+        let rc = Rc::new(String::from("only me"));
+        assert_eq!(Rc::try_unwrap(rc), Ok(String::from("only me")));
+    }
+
+    #[test]
+    fn test_try_unwrap_err_while_clone_lives() {
+        let rc = Rc::new(String::from("shared"));
+        let clone = Rc::clone(&rc);
+
+        let rc = Rc::try_unwrap(rc).unwrap_err();  // Err gives the Rc back
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(clone);
+        assert_eq!(Rc::try_unwrap(rc), Ok(String::from("shared")));
+    }
+
+    #[test]
+    fn test_report_builder_clones_only_shared_lines() {
+        let shared = Rc::new(String::from("shared"));
+        let mut report = ReportBuilder::new();
+        report.add(Rc::clone(&shared));
+        report.add(Rc::new(String::from("unique")));
+
+        assert_eq!(report.finish(), vec!["shared", "unique"]);
+        assert_eq!(Rc::strong_count(&shared), 1);  // Our copy is untouched
+    }
+
+    #[test]
+    fn test_arc_try_unwrap_after_join() {
+        let data = Arc::new(vec![1, 2, 3]);
+        let worker = {
+            let data = Arc::clone(&data);
+            thread::spawn(move || data.len())
+        };
+        assert_eq!(worker.join().unwrap(), 3);
+        assert_eq!(Arc::try_unwrap(data), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_audit_logs_each_set_in_order() {
+        let mut audit = Audit::new(1);
+        audit.set(2, "first");
+        audit.set(3, "second");
+        audit.set(4, "third");
+
+        assert_eq!(*audit.get(), 4);
+        assert_eq!(
+            *audit.get_log(),
+            vec!["first: replaced value", "second: replaced value", "third: replaced value"]
+        );
+    }
+
+    #[test]
+    fn test_audit_log_borrow_blocks_mutation() {
+        let mut audit = Audit::new(String::from("draft"));
+        audit.set(String::from("final"), "publish");
+
+        let log = audit.get_log();
+        // While `log` is alive the RefCell is borrowed, so a second, mutable
+        // borrow is refused at runtime...
+        assert!(audit.log.try_borrow_mut().is_err());
+        // ...and set() is refused at COMPILE time, since `log` borrows `audit`:
+        //
+        // audit.set(String::from("edit"), "sneaky");
+        // ERROR: cannot borrow `audit` as mutable because it is also
+        //        borrowed as immutable
+        assert_eq!(log.len(), 1);
+        drop(log);
+
+        audit.set(String::from("edit"), "after");  // Fine once the guard is gone
+        assert_eq!(audit.get_log().len(), 2);
+        assert_eq!(audit.len(), 4);  // Deref to String
+    }
+
+    #[test]
+    fn test_audit_annotate_fails_while_log_is_borrowed() {
+        let audit = Audit::new(0);
+        let log = audit.get_log();
+        // annotate() only needs &audit, so the compiler allows this call -
+        // the conflict is caught at runtime and reported as an Err
+        assert!(audit.annotate("too early").is_err());
+        assert!(log.is_empty());
+        drop(log);
+
+        assert!(audit.annotate("on time").is_ok());
+        assert_eq!(*audit.get_log(), vec!["note: on time"]);
+    }
+
+    #[test]
+    fn test_persistent_lists_share_tail() {
+        let tail = Arc::new(PersistentList::Nil).prepend(1).prepend(2);
+        let three = tail.prepend(3);
+        let four = tail.prepend(4);
+
+        assert!(Arc::ptr_eq(three.tail().unwrap(), four.tail().unwrap()));
+        assert_eq!(three.to_vec(), vec![3, 2, 1]);
+        assert_eq!(four.to_vec(), vec![4, 2, 1]);
+    }
+
+    #[test]
+    fn test_persistent_list_head_at_each_position() {
+        let list = Arc::new(PersistentList::Nil).prepend('c').prepend('b').prepend('a');
+        let mut node = &list;
+        for expected in ['a', 'b', 'c'] {
+            assert_eq!(*node.head().unwrap(), expected);
+            node = node.tail().unwrap();
+        }
+        assert!(node.head().is_none());
+        assert!(node.tail().is_none());
+    }
+
+    #[test]
+    fn test_dropping_long_list_does_not_overflow() {
+        let mut list = Arc::new(PersistentList::Nil);
+        for i in 0..200_000 {
+            list = list.prepend(i);
+        }
+        assert_eq!(list.head(), Some(&199_999));
+        drop(list);  // Would overflow the test thread's stack if recursive
+    }
+
+    #[test]
+    fn test_dropping_long_list_stops_at_shared_tail() {
+        let mut shared = Arc::new(PersistentList::Nil);
+        for i in 0..100_000 {
+            shared = shared.prepend(i);
+        }
+        let mut long = Arc::clone(&shared);
+        for i in 0..100_000 {
+            long = long.prepend(i);
+        }
+        assert_eq!(Arc::strong_count(&shared), 2);
+
+        drop(long);
+        assert_eq!(Arc::strong_count(&shared), 1);  // Untouched, still whole
+        assert_eq!(shared.to_vec().len(), 100_000);
+    }
+
+    #[test]
+    fn test_dropping_one_list_keeps_the_other() {
+        let four = {
+            let tail = Arc::new(PersistentList::Nil).prepend(1).prepend(2);
+            let three = tail.prepend(3);
+            let four = tail.prepend(4);
+            drop(three);
+            four
+        };  // `tail` and `three` are gone
+        assert_eq!(four.to_vec(), vec![4, 2, 1]);
+        assert_eq!(Arc::strong_count(four.tail().unwrap()), 1);  // Now owned only by four
+    }
 }
 
 // =============================================================================
@@ -293,6 +645,177 @@ impl Drop for DropExample {
     }
 }
 
+// =============================================================================
+// CLONE-ON-WRITE HELPERS
+// =============================================================================
+// Characters without case (digits, spaces, punctuation) count as uppercase
+// here: "ABC 123" needs no changes.
+fn ensure_uppercase<'a>(s: Cow<'a, str>) -> Cow<'a, str> {
+    if s.chars().any(char::is_lowercase) {
+        Cow::Owned(s.to_uppercase())
+    } else {
+        s  // Still Borrowed if it came in Borrowed
+    }
+}
+
+const SENTINEL: u8 = 0;
+
+// Appends a terminating SENTINEL byte unless the data already ends with one
+fn process_bytes<'a>(data: Cow<'a, [u8]>) -> Cow<'a, [u8]> {
+    if data.last() == Some(&SENTINEL) {
+        return data;
+    }
+    // into_owned() copies a Borrowed slice into a Vec; an Owned Vec is
+    // reused as-is
+    let mut bytes = data.into_owned();
+    bytes.push(SENTINEL);
+    Cow::Owned(bytes)
+}
+
+// =============================================================================
+// BUILDER THAT SHARES WITH Rc, THEN UNWRAPS
+// =============================================================================
+struct ReportBuilder {
+    lines: Vec<Rc<String>>,
+}
+
+impl ReportBuilder {
+    fn new() -> Self {
+        ReportBuilder { lines: Vec::new() }
+    }
+
+    fn add(&mut self, line: Rc<String>) {
+        self.lines.push(line);
+    }
+
+    // Moves each String out when the builder is its only owner, and falls
+    // back to cloning when someone else still holds the same line.
+    // (Rc::unwrap_or_clone does exactly this in one call.)
+    fn finish(self) -> Vec<String> {
+        self.lines
+            .into_iter()
+            .map(|line| Rc::try_unwrap(line).unwrap_or_else(|shared| (*shared).clone()))
+            .collect()
+    }
+}
+
+// =============================================================================
+// PERSISTENT CONS LIST
+// =============================================================================
+#[derive(Debug)]
+enum PersistentList<T: Clone> {
+    Nil,
+    Cons(T, Arc<PersistentList<T>>),
+}
+
+impl<T: Clone> PersistentList<T> {
+    // `self: &Arc<Self>` lets the new node hold a clone of the Arc we were
+    // called on - an O(1) reference count bump, no copying of the list
+    fn prepend(self: &Arc<Self>, value: T) -> Arc<Self> {
+        Arc::new(PersistentList::Cons(value, Arc::clone(self)))
+    }
+
+    fn head(&self) -> Option<&T> {
+        match self {
+            PersistentList::Cons(value, _) => Some(value),
+            PersistentList::Nil => None,
+        }
+    }
+
+    fn tail(&self) -> Option<&Arc<Self>> {
+        match self {
+            PersistentList::Cons(_, tail) => Some(tail),
+            PersistentList::Nil => None,
+        }
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        let mut values = Vec::new();
+        let mut node = self;
+        while let PersistentList::Cons(value, tail) = node {
+            values.push(value.clone());
+            node = tail;
+        }
+        values
+    }
+}
+
+// The default drop is recursive: dropping a node drops its tail Arc, which
+// drops the next node, ... - one stack frame per element, so a list of
+// ~100k nodes overflows the stack. Instead, unlink the nodes in a loop.
+//
+// A type with Drop can't be destructured by move, so each tail is swapped
+// out for a clone of one shared Nil placeholder (a count bump, not an
+// allocation). A node whose tail is Nil returns immediately, which is what
+// stops the unlinked nodes - and the placeholder itself - from recursing.
+impl<T: Clone> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let PersistentList::Cons(_, tail) = self else { return };
+        if matches!(**tail, PersistentList::Nil) {
+            return;  // Nothing below us: the default drop is already shallow
+        }
+        let nil = Arc::new(PersistentList::Nil);
+        let mut next = mem::replace(tail, Arc::clone(&nil));
+        // try_unwrap succeeds only if we held the LAST reference. If another
+        // list still shares the rest, dropping our Arc just decrements the
+        // count and that list keeps its nodes - so stop there.
+        while let Ok(mut node) = Arc::try_unwrap(next) {
+            match &mut node {
+                PersistentList::Cons(_, tail) => next = mem::replace(tail, Arc::clone(&nil)),
+                PersistentList::Nil => break,
+            }
+            // `node` drops here with a Nil tail, so its drop() returns at once
+        }
+    }
+}
+
+// =============================================================================
+// AUDIT<T> - MUTATION HISTORY
+// =============================================================================
+struct Audit<T> {
+    value: T,
+    log: RefCell<Vec<String>>,
+}
+
+impl<T> Audit<T> {
+    fn new(value: T) -> Self {
+        Audit { value, log: RefCell::new(Vec::new()) }
+    }
+
+    fn get(&self) -> &T {
+        &self.value
+    }
+
+    fn set(&mut self, val: T, tag: &str) {
+        self.value = val;
+        // &mut self means no Ref from get_log() can be alive, so this
+        // borrow_mut() can never panic
+        self.log.borrow_mut().push(format!("{tag}: replaced value"));
+    }
+
+    // Needs only &self - this is what the RefCell is for. Unlike set(), a
+    // guard from get_log() CAN be alive here, and borrow_mut() would panic,
+    // so hand the failure back to the caller instead.
+    fn annotate(&self, note: &str) -> Result<(), BorrowMutError> {
+        self.log.try_borrow_mut()?.push(format!("note: {note}"));
+        Ok(())
+    }
+
+    // Returns the RefCell's Ref guard: it derefs to the Vec, and the log
+    // stays immutably borrowed for as long as the guard lives
+    fn get_log(&self) -> impl Deref<Target = Vec<String>> + '_ {
+        self.log.borrow()
+    }
+}
+
+impl<T> Deref for Audit<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
 // =============================================================================
 // SMART POINTER SUMMARY
 // =============================================================================
@@ -305,6 +828,13 @@ impl Drop for DropExample {
 // | RefCell<T>   | Single       | Runtime      | NO          | Interior mutability          |
 // | Mutex<T>     | Single       | Runtime      | YES         | Interior mutability (threads)|
 // | Weak<T>      | Non-owning   | N/A          | NO          | Breaking cycles              |
+// | Cow<'a, B>   | Either       | Compile time | If B is     | Clone only when modifying    |
+//
+// Custom smart pointers: Deref without DerefMut = read freely, but every
+// write goes through a method you control.
+//
+// Persistent data structures: Arc<Node> links + never mutating = cheap
+// "copies" that share everything they have in common.
 //
 // Common Combinations:
 // - Rc<RefCell<T>>  : Multiple owners with mutation (single-threaded)
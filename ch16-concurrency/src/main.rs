@@ -5,12 +5,30 @@
 // 1. Spawning threads with thread::spawn
 // 2. Message passing with channels (mpsc)
 // 3. Shared state with Mutex and Arc
+// 4. Read-heavy shared state with RwLock
+// 5. Coordinating threads with Barrier
+// 6. Waiting for a condition with Condvar
+// 7. Lock-free counters with atomics
+// 8. Fan-out / fan-in work distribution over channels
+// 9. A reusable ThreadPool
+// 10. A lock-free stack built on AtomicPtr (with unsafe code)
+// 11. Building a Spinlock from AtomicBool and UnsafeCell
+// 12. Broadcasting one message to many receivers
+// 13. Rate limiting with a shared TokenBucket
 // =============================================================================
 
+use std::cell::UnsafeCell;
+use std::collections::{HashMap, VecDeque};
+use std::ops::{Deref, DerefMut};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::sync::mpsc;         // mpsc = "multiple producer, single consumer"
 use std::sync::{Arc, Mutex}; // Arc = Atomic Reference Counting (thread-safe Rc)
+use std::sync::RwLock;       // RwLock = many readers OR one writer
+use std::sync::Barrier;      // Barrier = wait until N threads arrive
+use std::sync::Condvar;      // Condvar = sleep until another thread signals
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::ptr;
 
 fn main() {
     // =========================================================================
@@ -166,6 +184,59 @@ fn main() {
     // All threads have finished - counter should be 10
     println!("Result: {}", *counter.lock().unwrap());
 
+    // =========================================================================
+    // PART 6: Read-Heavy Shared State with RwLock
+    // =========================================================================
+    rwlock_cache();
+
+    // =========================================================================
+    // PART 7: Synchronizing Phases with Barrier
+    // =========================================================================
+    let log = barrier_phases(4);
+    let first_phase_2 = log.iter().position(|entry| entry.contains("Phase 2")).unwrap();
+    assert!(log[..first_phase_2].iter().all(|entry| entry.contains("Phase 1")));
+    println!("Barrier log: {:?}", log);
+
+    // =========================================================================
+    // PART 8: Producer-Consumer with Condvar
+    // =========================================================================
+    println!("Consumed: {:?}", condvar_pipeline());
+
+    // =========================================================================
+    // PART 9: Atomic Counter vs Arc<Mutex<usize>>
+    // =========================================================================
+    atomic_vs_mutex();
+
+    // =========================================================================
+    // PART 10: Fan-Out / Fan-In with Channels
+    // =========================================================================
+    println!("Doubled: {:?}", fan_out_fan_in((1..=10).collect(), 3));
+
+    // =========================================================================
+    // PART 11: A Reusable ThreadPool
+    // =========================================================================
+    thread_pool_example();
+
+    // =========================================================================
+    // PART 12: Lock-Free Stack with AtomicPtr
+    // =========================================================================
+    lock_free_stack_example();
+
+    // =========================================================================
+    // PART 13: A Hand-Rolled Spinlock
+    // =========================================================================
+    spinlock_example();
+
+    // =========================================================================
+    // PART 14: Broadcast Channel
+    // =========================================================================
+    broadcast_example();
+
+    // =========================================================================
+    // PART 15: Rate Limiting with a TokenBucket
+    // =========================================================================
+    token_bucket_example();
+
     // =========================================================================
     // KEY TAKEAWAYS:
     // =========================================================================
@@ -175,5 +246,961 @@ fn main() {
     // 4. Use Arc (not Rc) when sharing ownership across threads
     // 5. Arc<Mutex<T>> is the common pattern for shared mutable state
     // 6. Rust's ownership system prevents data races at compile time!
+    // 7. Use RwLock instead of Mutex when reads vastly outnumber writes
+    // 8. Barrier makes every thread finish one phase before any starts the next
+    // 9. Condvar lets a thread sleep until a condition on shared data holds
+    // 10. Atomics replace Mutex for simple counters and flags
+    // 11. One channel per worker + one shared result channel = fan-out/fan-in
+    // 12. A ThreadPool reuses a fixed set of threads for many jobs
+    // 13. Lock-free code trades locks for compare-exchange loops - and unsafe
+    // 14. A lock is just an atomic flag + UnsafeCell + a guard that unlocks on Drop
+    // 15. mpsc delivers each message once; broadcast = one Sender per subscriber
+    // 16. Keep time-dependent logic testable by passing the elapsed Duration in
     // =========================================================================
 }
+
+// =============================================================================
+// PART 6: RwLock - Many Readers OR One Writer
+// =============================================================================
+// Mutex allows ONE accessor at a time, even if everyone only reads.
+// RwLock allows:
+// - any number of simultaneous readers via read()  -> RwLockReadGuard
+// - exactly one writer via write()                 -> RwLockWriteGuard
+//
+// A cache is the classic use case: lookups are frequent, inserts are rare.
+
+type Cache = Arc<RwLock<HashMap<String, u64>>>;
+
+const CACHE_READERS: usize = 5;
+const CACHE_WRITERS: u64 = 2;
+const KEYS_PER_WRITER: u64 = 3;
+
+// Spawns the writers and readers; the caller decides when to join them
+fn spawn_cache_threads(cache: &Cache) -> Vec<thread::JoinHandle<()>> {
+    let mut handles = vec![];
+
+    for writer in 0..CACHE_WRITERS {
+        let cache = Arc::clone(cache);
+        handles.push(thread::spawn(move || {
+            for n in 0..KEYS_PER_WRITER {
+                // write() blocks until every reader has released its guard
+                let mut map = cache.write().unwrap();
+                map.insert(format!("w{writer}-k{n}"), writer * 100 + n);
+                println!("{:?} WRITE w{writer}-k{n}", thread::current().id());
+            } // write guard dropped at the end of each iteration
+        }));
+    }
+
+    for _ in 0..CACHE_READERS {
+        let cache = Arc::clone(cache);
+        handles.push(thread::spawn(move || {
+            // Several threads can hold a read guard at the same time
+            let map = cache.read().unwrap();
+            let hit = map.get("w0-k0");
+            println!("{:?} READ  w0-k0 -> {:?} ({} entries)", thread::current().id(), hit, map.len());
+        }));
+    }
+
+    handles
+}
+
+// Copies the cache out into a Vec sorted by key
+fn sorted_entries(cache: &Cache) -> Vec<(String, u64)> {
+    let map = cache.read().unwrap();
+    let mut entries: Vec<(String, u64)> = map.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    entries.sort();
+    entries
+}
+
+fn rwlock_cache() {
+    let cache: Cache = Arc::new(RwLock::new(HashMap::new()));
+
+    for handle in spawn_cache_threads(&cache) {
+        handle.join().unwrap();
+    }
+
+    println!("Final cache: {:?}", sorted_entries(&cache));
+}
+
+// =============================================================================
+// PART 7: Barrier - Wait Until Everyone Arrives
+// =============================================================================
+// Barrier::new(n) blocks each thread calling wait() until n threads have
+// called it. Then all n are released together. Nobody can start phase 2
+// while another thread is still in phase 1.
+
+fn barrier_phases(workers: usize) -> Vec<String> {
+    let barrier = Arc::new(Barrier::new(workers));
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut handles = vec![];
+
+    for id in 0..workers {
+        let barrier = Arc::clone(&barrier);
+        let log = Arc::clone(&log);
+
+        handles.push(thread::spawn(move || {
+            // Stagger the threads so phase 1 finishes at different times
+            thread::sleep(Duration::from_millis(id as u64 * 5));
+            log.lock().unwrap().push(format!("worker {id}: Phase 1 done"));
+
+            barrier.wait();  // The last thread to arrive releases everyone
+
+            log.lock().unwrap().push(format!("worker {id}: Phase 2 started"));
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // All threads are joined, so this is the only Arc left
+    Arc::try_unwrap(log).unwrap().into_inner().unwrap()
+}
+
+// =============================================================================
+// PART 8: Condvar - Producer-Consumer Queue
+// =============================================================================
+// A Condvar is always paired with a Mutex. The consumer sleeps (without
+// spinning) until the producer calls notify_one().
+//
+// wait_while(guard, condition):
+// - releases the lock and sleeps while condition(&mut data) is true
+// - re-acquires the lock before returning
+// - re-checks the condition, so spurious wakeups are handled for us
+
+const SENTINEL: i32 = -1;  // Tells the consumer there is nothing more to come
+
+fn condvar_pipeline() -> Vec<i32> {
+    let pair = Arc::new((Mutex::new(VecDeque::new()), Condvar::new()));
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    let producer_pair = Arc::clone(&pair);
+    let producer = thread::spawn(move || {
+        let (queue, condvar) = &*producer_pair;
+        for item in (1..=10).chain([SENTINEL]) {
+            queue.lock().unwrap().push_back(item);
+            condvar.notify_one();  // Wake the consumer if it is waiting
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+
+    let consumer_pair = Arc::clone(&pair);
+    let consumer_results = Arc::clone(&results);
+    let consumer = thread::spawn(move || {
+        let (queue, condvar) = &*consumer_pair;
+        loop {
+            let item = {
+                let guard = queue.lock().unwrap();
+                let mut guard = condvar.wait_while(guard, |q| q.is_empty()).unwrap();
+                guard.pop_front().unwrap()
+            }; // Lock released before doing any work with the item
+
+            if item == SENTINEL {
+                break;
+            }
+            consumer_results.lock().unwrap().push(item);
+        }
+    });
+
+    producer.join().unwrap();
+    consumer.join().unwrap();
+
+    Arc::try_unwrap(results).unwrap().into_inner().unwrap()
+}
+
+// =============================================================================
+// PART 9: Atomics vs Mutex for a Shared Counter
+// =============================================================================
+// AtomicUsize::fetch_add is a single CPU instruction - no lock, no guard,
+// no blocking. Arc<Mutex<usize>> makes every increment acquire and release
+// a lock, so threads queue up behind each other.
+//
+// MEMORY ORDERING
+// ---------------
+// Every atomic operation takes an Ordering that says how it may be
+// reordered relative to OTHER memory operations:
+//
+// - Relaxed: only this variable's updates are atomic. No ordering
+//   guarantees with respect to anything else.
+// - SeqCst:  all SeqCst operations appear in one global order that every
+//   thread agrees on. Strongest and slowest.
+// - Acquire/Release: pairs up a store in one thread with a load in another,
+//   so everything written before the Release is visible after the Acquire.
+//
+// Relaxed is enough HERE because each increment is independent: no thread
+// reads the counter to decide what to do next, and nothing else is
+// published through it. join() already synchronizes the final read.
+//
+// Stronger ordering is needed when the atomic GUARDS other data, e.g. a
+// `ready` flag set after writing a buffer: the writer must store with
+// Release and the reader load with Acquire, or the reader may see the flag
+// before the buffer contents.
+
+const COUNTER_THREADS: usize = 10;
+const INCREMENTS_PER_THREAD: usize = 1_000;
+
+fn count_with_atomic() -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..COUNTER_THREADS {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..INCREMENTS_PER_THREAD {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.load(Ordering::Relaxed)
+}
+
+fn count_with_mutex() -> usize {
+    let counter = Arc::new(Mutex::new(0usize));
+    let mut handles = vec![];
+
+    for _ in 0..COUNTER_THREADS {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..INCREMENTS_PER_THREAD {
+                *counter.lock().unwrap() += 1;
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    *counter.lock().unwrap()
+}
+
+fn atomic_vs_mutex() {
+    let start = Instant::now();
+    let atomic_total = count_with_atomic();
+    let atomic_time = start.elapsed();
+
+    let start = Instant::now();
+    let mutex_total = count_with_mutex();
+    let mutex_time = start.elapsed();
+
+    println!("AtomicUsize:       {atomic_total} in {atomic_time:?}");
+    println!("Arc<Mutex<usize>>: {mutex_total} in {mutex_time:?}");
+    println!(
+        "Speedup: {:.2}x",
+        mutex_time.as_secs_f64() / atomic_time.as_secs_f64()
+    );
+}
+
+// =============================================================================
+// PART 10: Fan-Out / Fan-In
+// =============================================================================
+// FAN-OUT: the main thread owns one Sender per worker and deals items out
+//          round-robin (item i goes to worker i % n_workers).
+// FAN-IN:  every worker gets a clone of ONE result Sender, so all results
+//          arrive on a single Receiver.
+//
+// Results arrive in whatever order workers finish, so we sort at the end.
+
+fn fan_out_fan_in(items: Vec<i32>, n_workers: usize) -> Vec<i32> {
+    assert!(n_workers > 0, "need at least one worker");
+    let (result_tx, result_rx) = mpsc::channel();
+    let mut work_senders = vec![];
+    let mut handles = vec![];
+
+    for _ in 0..n_workers {
+        let (work_tx, work_rx) = mpsc::channel::<i32>();
+        let result_tx = result_tx.clone();
+
+        handles.push(thread::spawn(move || {
+            // Ends when the main thread drops work_tx
+            for item in work_rx {
+                result_tx.send(item * 2).unwrap();
+            }
+        }));
+        work_senders.push(work_tx);
+    }
+
+    // Drop the original so the result channel closes once every worker's
+    // clone is gone - otherwise the collecting loop below never ends
+    drop(result_tx);
+
+    for (i, item) in items.into_iter().enumerate() {
+        work_senders[i % n_workers].send(item).unwrap();
+    }
+    drop(work_senders);  // Closes every work channel -> workers finish
+
+    let mut results: Vec<i32> = result_rx.iter().collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    results.sort();
+    results
+}
+
+// =============================================================================
+// PART 11: ThreadPool - Reusing a Fixed Set of Threads
+// =============================================================================
+// Spawning a thread per task is expensive. A pool starts N workers once;
+// each worker loops, pulling jobs off a shared channel.
+//
+// The channel has ONE receiver but N workers, so the receiver is shared as
+// Arc<Mutex<Receiver>> - whichever worker grabs the lock gets the next job.
+
+// A job is any closure that runs once and can move to another thread
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+enum Message {
+    Job(Job),
+    Terminate,
+}
+
+struct Worker {
+    id: usize,
+    thread: Option<thread::JoinHandle<()>>,  // Option so Drop can take() it
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Message>>>) -> Worker {
+        let thread = thread::spawn(move || loop {
+            // The guard is a temporary, so the lock is released as soon as
+            // recv() returns - other workers can fetch jobs while this runs
+            let message = receiver.lock().unwrap().recv().unwrap();
+
+            match message {
+                Message::Job(job) => job(),
+                Message::Terminate => break,
+            }
+        });
+
+        Worker { id, thread: Some(thread) }
+    }
+}
+
+struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: mpsc::Sender<Message>,
+}
+
+impl ThreadPool {
+    fn new(size: usize) -> ThreadPool {
+        assert!(size > 0, "a pool needs at least one thread");
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..size)
+            .map(|id| Worker::new(id, Arc::clone(&receiver)))
+            .collect();
+
+        ThreadPool { workers, sender }
+    }
+
+    // Same bounds as thread::spawn: the job runs on another thread,
+    // possibly after the caller has returned
+    fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender.send(Message::Job(Box::new(f))).unwrap();
+    }
+}
+
+// Graceful shutdown: finish queued jobs, then stop every worker
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Send all Terminates first. Each worker stops after receiving ONE,
+        // so every worker gets exactly one.
+        for _ in &self.workers {
+            self.sender.send(Message::Terminate).unwrap();
+        }
+
+        for worker in &mut self.workers {
+            if let Some(thread) = worker.thread.take() {
+                thread.join().unwrap();
+                println!("Worker {} shut down", worker.id);
+            }
+        }
+    }
+}
+
+fn thread_pool_example() {
+    let pool = ThreadPool::new(3);
+    let completed = Arc::new(AtomicUsize::new(0));
+
+    for task in 0..6 {
+        let completed = Arc::clone(&completed);
+        pool.execute(move || {
+            println!("Task {task} running on {:?}", thread::current().id());
+            completed.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+
+    drop(pool);  // Blocks until every job has run and all workers exit
+    println!("Tasks completed: {}", completed.load(Ordering::SeqCst));
+}
+
+// =============================================================================
+// PART 12: Lock-Free Stack (Treiber Stack)
+// =============================================================================
+// No Mutex: threads race to swing `head` with compare_exchange.
+//   1. Read the current head
+//   2. Prepare the new state based on it
+//   3. compare_exchange(old, new) - succeeds only if head is STILL `old`
+//   4. If another thread got there first, retry from step 1
+//
+// THE ABA PROBLEM
+// ---------------
+// Thread 1 reads head = A (A.next = B) and is paused. Thread 2 pops A, pops
+// B, frees them, then pushes a new node that the allocator happens to place
+// at A's old address. Thread 1 wakes: head is "still A", so its
+// compare_exchange succeeds and installs B - a node that was already freed.
+// The pointer comparison can't tell the old A from the new A.
+//
+// A related hazard: between steps 1 and 3, another thread may free the
+// node we are reading `.next` from - a use-after-free.
+//
+// Real implementations fix both with safe memory reclamation (hazard
+// pointers, or epoch-based schemes like the crossbeam-epoch crate). This
+// example takes the simplest safe route: popped nodes are NOT freed right
+// away but parked in `retired`, and freed when the whole stack is dropped.
+// Because no address is reused while the stack is alive, ABA can't happen
+// - at the cost of memory that grows with every pop. Without that, this
+// stack would be unsound under concurrent pops.
+
+struct Node<T> {
+    value: T,
+    next: *mut Node<T>,
+}
+
+struct LockFreeStack<T> {
+    head: AtomicPtr<Node<T>>,
+    retired: Mutex<Vec<*mut Node<T>>>,  // Popped nodes awaiting deallocation
+}
+
+// SAFETY: raw pointers are !Send/!Sync by default. Every node is owned by
+// the stack, values only ever move out through pop() (which needs T: Send
+// to hand them to another thread), and all shared mutation of `head` goes
+// through atomics.
+unsafe impl<T: Send> Send for LockFreeStack<T> {}
+unsafe impl<T: Send> Sync for LockFreeStack<T> {}
+
+impl<T> LockFreeStack<T> {
+    fn new() -> Self {
+        LockFreeStack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            retired: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn push(&self, value: T) {
+        // Box::into_raw hands ownership of the allocation to us
+        let node = Box::into_raw(Box::new(Node { value, next: ptr::null_mut() }));
+
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            // SAFETY: `node` isn't shared yet, so this write can't race
+            unsafe { (*node).next = head };
+
+            // Release: a thread that later Acquires `head` sees the node's contents
+            if self.head
+                .compare_exchange(head, node, Ordering::Release, Ordering::Relaxed)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<T> {
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            if head.is_null() {
+                return None;
+            }
+
+            // SAFETY: `head` was pushed by push() and is never freed while
+            // the stack is alive (see `retired`), so reading it is valid
+            let next = unsafe { (*head).next };
+
+            if self.head
+                .compare_exchange(head, next, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                // We won the race - this node is ours alone now.
+                // SAFETY: move the value out exactly once; the node is then
+                // freed with dealloc (not Box::from_raw) so it isn't dropped twice
+                let value = unsafe { ptr::read(&(*head).value) };
+                self.retired.lock().unwrap().push(head);
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<T> Drop for LockFreeStack<T> {
+    fn drop(&mut self) {
+        // &mut self: no other thread can be using the stack any more
+        while self.pop().is_some() {}
+
+        let layout = std::alloc::Layout::new::<Node<T>>();
+        for node in self.retired.get_mut().unwrap().drain(..) {
+            // SAFETY: allocated by Box::new with this layout, value already moved out
+            unsafe { std::alloc::dealloc(node as *mut u8, layout) };
+        }
+    }
+}
+
+fn lock_free_stack_example() {
+    let stack = Arc::new(LockFreeStack::new());
+    let mut handles = vec![];
+
+    for t in 0..3 {
+        let stack = Arc::clone(&stack);
+        handles.push(thread::spawn(move || {
+            for i in 0..3 {
+                stack.push(t * 10 + i);
+            }
+        }));
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut popped = vec![];
+    while let Some(value) = stack.pop() {
+        popped.push(value);
+    }
+    popped.sort();
+    println!("Lock-free stack held: {:?}", popped);
+}
+
+// =============================================================================
+// PART 13: Spinlock<T> - What a Mutex Looks Like Inside
+// =============================================================================
+// lock() spins (busy-waits) until it flips `locked` from false to true,
+// then returns a guard. The guard gives &T / &mut T through Deref/DerefMut
+// and stores `false` again when dropped - the same RAII shape as MutexGuard.
+//
+// SPINLOCK vs std::sync::Mutex
+// - Overhead: an uncontended spinlock is one atomic swap, no syscalls.
+//   std's Mutex is also cheap uncontended, but PARKS waiting threads in
+//   the OS instead of burning CPU.
+// - Contention: spinning threads waste whole time slices. If the holder
+//   is descheduled, every waiter spins uselessly until it runs again.
+// - Fairness: none - whichever spinner wins the swap gets the lock, so a
+//   thread can starve. std's Mutex doesn't promise FIFO either, but
+//   parking makes starvation far less likely.
+// Use spinlocks only for very short critical sections (or no-OS kernels).
+
+struct Spinlock<T> {
+    locked: AtomicBool,
+    data: UnsafeCell<T>,  // The ONLY legal way to mutate through &self
+}
+
+// SAFETY: UnsafeCell<T> is !Sync because unsynchronized access through
+// &UnsafeCell would be a data race. Our `locked` flag makes access
+// exclusive: only the thread holding the guard can reach `data`.
+// T: Send is required because the guard hands &mut T to whichever thread
+// locks - effectively moving access to T between threads.
+unsafe impl<T: Send> Send for Spinlock<T> {}
+unsafe impl<T: Send> Sync for Spinlock<T> {}
+
+struct SpinlockGuard<'a, T> {
+    lock: &'a Spinlock<T>,  // Guard can't outlive the lock it came from
+}
+
+impl<T> Spinlock<T> {
+    fn new(data: T) -> Self {
+        Spinlock {
+            locked: AtomicBool::new(false),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    fn lock(&self) -> SpinlockGuard<'_, T> {
+        // swap returns the OLD value: false means we just acquired it.
+        // Acquire pairs with the Release in Drop, so we see the previous
+        // holder's writes to `data`.
+        while self.locked.swap(true, Ordering::Acquire) {
+            std::hint::spin_loop();  // Tell the CPU we're busy-waiting
+        }
+        SpinlockGuard { lock: self }
+    }
+}
+
+impl<T> Deref for SpinlockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: holding the guard means we hold the lock
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> DerefMut for SpinlockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: holding the guard means no one else can access `data`
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for SpinlockGuard<'_, T> {
+    fn drop(&mut self) {
+        // Release: publish our writes before the next Acquire in lock()
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+fn spin_count(threads: usize, increments: usize) -> usize {
+    let counter = Arc::new(Spinlock::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..threads {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..increments {
+                *counter.lock() += 1;  // Guard dropped at end of statement
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    *counter.lock()
+}
+
+fn spinlock_example() {
+    println!("Spinlock counter: {}", spin_count(10, 1_000));
+}
+
+// =============================================================================
+// PART 14: Broadcast - Every Subscriber Gets Every Message
+// =============================================================================
+// An mpsc channel has ONE receiver, and each message is received once.
+// To broadcast, keep one Sender per subscriber and send a clone to each.
+//
+// The list of senders is shared (Arc) and mutated from several places
+// (subscribe / send), so it lives behind a Mutex.
+
+struct BroadcastSender<T: Clone> {
+    subscribers: Arc<Mutex<Vec<mpsc::Sender<T>>>>,
+}
+
+// Manual Clone: #[derive(Clone)] would also demand T: Clone on the
+// struct itself - here we only want to clone the Arc
+impl<T: Clone> Clone for BroadcastSender<T> {
+    fn clone(&self) -> Self {
+        BroadcastSender { subscribers: Arc::clone(&self.subscribers) }
+    }
+}
+
+impl<T: Clone> BroadcastSender<T> {
+    fn new() -> Self {
+        BroadcastSender { subscribers: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    fn subscribe(&self) -> mpsc::Receiver<T> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    fn send(&self, val: T) {
+        // send() fails only when the Receiver was dropped - retain() drops
+        // those Senders, cleaning up dead subscribers as a side effect
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|tx| tx.send(val.clone()).is_ok());
+    }
+
+    fn subscriber_count(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+}
+
+fn broadcast_example() {
+    let broadcaster = BroadcastSender::new();
+    let mut handles = vec![];
+
+    for id in 0..3 {
+        let rx = broadcaster.subscribe();
+        handles.push(thread::spawn(move || {
+            // Loop ends once the broadcaster (and its Senders) is dropped
+            let received: Vec<String> = rx.iter().collect();
+            println!("Subscriber {id} received {:?}", received);
+        }));
+    }
+
+    println!("Subscribers: {}", broadcaster.subscriber_count());
+    for n in 1..=5 {
+        broadcaster.send(format!("msg {n}"));
+    }
+    drop(broadcaster);  // Closes every subscriber's channel
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+// =============================================================================
+// PART 15: TokenBucket Rate Limiter
+// =============================================================================
+// The bucket holds up to `capacity` tokens and refills continuously at
+// `refill_rate_per_sec`. Each request spends `cost` tokens, or is rejected
+// if there aren't enough. Bursts up to `capacity` are allowed; the long-run
+// rate is capped at the refill rate.
+//
+// The bucket is plain single-threaded code (&mut self). Sharing it is the
+// caller's job: Arc<Mutex<TokenBucket>>.
+
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    refill_rate_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64, refill_rate_per_sec: f64) -> Self {
+        TokenBucket {
+            tokens: capacity,  // Start full
+            capacity,
+            refill_rate_per_sec,
+            last_refill: Instant::now(),
+        }
+    }
+
+    // Reads the real clock, then defers to the pure version below
+    fn try_acquire(&mut self, cost: f64) -> bool {
+        let now = Instant::now();
+        let elapsed = now - self.last_refill;
+        self.last_refill = now;
+        self.try_acquire_after(elapsed, cost)
+    }
+
+    // Takes the elapsed time as an argument so tests can "advance the
+    // clock" exactly, instead of sleeping and hoping
+    fn try_acquire_after(&mut self, elapsed: Duration, cost: f64) -> bool {
+        let refill = elapsed.as_secs_f64() * self.refill_rate_per_sec;
+        self.tokens = (self.tokens + refill).min(self.capacity);  // Never overfill
+
+        if self.tokens >= cost {
+            self.tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn token_bucket_example() {
+    // Burst of 5, then 5 tokens per second
+    let bucket = Arc::new(Mutex::new(TokenBucket::new(5.0, 5.0)));
+    let allowed = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+
+    // 20 requests spread over ~1 second
+    for i in 0..20 {
+        let bucket = Arc::clone(&bucket);
+        let allowed = Arc::clone(&allowed);
+        handles.push(thread::spawn(move || {
+            thread::sleep(Duration::from_millis(i * 50));
+            if bucket.lock().unwrap().try_acquire(1.0) {
+                allowed.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    // Expect about 5 (initial burst) + 5 (one second of refill)
+    println!("Requests allowed: {} of 20", allowed.load(Ordering::SeqCst));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rwlock_cache_contains_inserted_keys() {
+        let cache: Cache = Arc::new(RwLock::new(HashMap::new()));
+        for handle in spawn_cache_threads(&cache) {
+            handle.join().unwrap();
+        }
+
+        let keys: Vec<String> = sorted_entries(&cache).into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["w0-k0", "w0-k1", "w0-k2", "w1-k0", "w1-k1", "w1-k2"]);
+        assert_eq!(cache.read().unwrap()["w1-k2"], 102);
+    }
+
+    #[test]
+    fn test_barrier_phase_1_precedes_phase_2() {
+        let log = barrier_phases(4);
+        assert_eq!(log.len(), 8);
+
+        let last_phase_1 = log.iter().rposition(|entry| entry.ends_with("Phase 1 done")).unwrap();
+        let first_phase_2 = log.iter().position(|entry| entry.ends_with("Phase 2 started")).unwrap();
+        assert!(last_phase_1 < first_phase_2);
+    }
+
+    #[test]
+    fn test_condvar_pipeline_is_fifo() {
+        assert_eq!(condvar_pipeline(), (1..=10).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_atomic_and_mutex_counts_match() {
+        assert_eq!(count_with_atomic(), 10_000);
+        assert_eq!(count_with_mutex(), 10_000);
+    }
+
+    #[test]
+    fn test_fan_out_fan_in() {
+        let expected: Vec<i32> = (1..=10).map(|x| x * 2).collect();
+        assert_eq!(fan_out_fan_in((1..=10).collect(), 3), expected);
+    }
+
+    #[test]
+    fn test_fan_out_fan_in_single_worker() {
+        let expected: Vec<i32> = (1..=10).map(|x| x * 2).collect();
+        assert_eq!(fan_out_fan_in((1..=10).collect(), 1), expected);
+    }
+
+    #[test]
+    fn test_fan_out_fan_in_more_workers_than_items() {
+        assert_eq!(fan_out_fan_in(vec![3, 1, 2], 8), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_thread_pool_runs_every_job() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::new(4);
+
+        for _ in 0..10 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        drop(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+    }
+
+    #[test]
+    fn test_lock_free_stack_lifo() {
+        let stack = LockFreeStack::new();
+        for i in 1..=5 {
+            stack.push(i);
+        }
+        let popped: Vec<i32> = std::iter::from_fn(|| stack.pop()).collect();
+        assert_eq!(popped, vec![5, 4, 3, 2, 1]);
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_lock_free_stack_concurrent() {
+        let stack = Arc::new(LockFreeStack::new());
+        let popped = Arc::new(AtomicUsize::new(0));
+        let sum = Arc::new(AtomicUsize::new(0));
+        let mut handles = vec![];
+
+        // 4 pushers x 25 items = 100 items (values 1..=100)
+        for t in 0..4 {
+            let stack = Arc::clone(&stack);
+            handles.push(thread::spawn(move || {
+                for i in 1..=25 {
+                    stack.push(t * 25 + i);
+                }
+            }));
+        }
+
+        // 4 poppers run concurrently until all 100 have been taken
+        for _ in 0..4 {
+            let stack = Arc::clone(&stack);
+            let popped = Arc::clone(&popped);
+            let sum = Arc::clone(&sum);
+            handles.push(thread::spawn(move || {
+                while popped.load(Ordering::SeqCst) < 100 {
+                    if let Some(value) = stack.pop() {
+                        sum.fetch_add(value, Ordering::SeqCst);
+                        popped.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(popped.load(Ordering::SeqCst), 100);
+        assert_eq!(sum.load(Ordering::SeqCst), (1..=100).sum::<usize>());
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_spinlock_counter() {
+        assert_eq!(spin_count(10, 1_000), 10_000);
+    }
+
+    #[test]
+    fn test_broadcast_identical_sequences() {
+        let broadcaster = BroadcastSender::new();
+        let receivers: Vec<_> = (0..3).map(|_| broadcaster.subscribe()).collect();
+
+        let sender = broadcaster.clone();
+        thread::spawn(move || {
+            for n in 1..=5 {
+                sender.send(n);
+            }
+        })
+        .join()
+        .unwrap();
+        drop(broadcaster);
+
+        for rx in receivers {
+            assert_eq!(rx.iter().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn test_broadcast_removes_dropped_subscribers() {
+        let broadcaster = BroadcastSender::new();
+        let keep = broadcaster.subscribe();
+        let gone = broadcaster.subscribe();
+        assert_eq!(broadcaster.subscriber_count(), 2);
+
+        drop(gone);
+        broadcaster.send("hello");  // Discovers the dead receiver
+        assert_eq!(broadcaster.subscribers.lock().unwrap().len(), 1);
+        assert_eq!(keep.recv().unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_token_bucket_refill_is_proportional() {
+        let mut bucket = TokenBucket::new(10.0, 2.0);
+        assert!(bucket.try_acquire_after(Duration::ZERO, 10.0));  // Drain it
+        assert!(!bucket.try_acquire_after(Duration::ZERO, 1.0));
+
+        // 1.5s at 2 tokens/sec = 3 tokens
+        assert!(bucket.try_acquire_after(Duration::from_millis(1500), 3.0));
+        assert!(!bucket.try_acquire_after(Duration::ZERO, 0.5));
+    }
+
+    #[test]
+    fn test_token_bucket_caps_at_capacity() {
+        let mut bucket = TokenBucket::new(4.0, 100.0);
+        assert!(bucket.try_acquire_after(Duration::ZERO, 4.0));
+
+        // An hour of refill still only yields `capacity` tokens
+        assert!(bucket.try_acquire_after(Duration::from_secs(3600), 4.0));
+        assert!(!bucket.try_acquire_after(Duration::ZERO, 1.0));
+    }
+}
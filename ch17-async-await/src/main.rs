@@ -0,0 +1,587 @@
+// =============================================================================
+// RUST ASYNC/AWAIT - Futures, Wakers, and Executors
+// =============================================================================
+// This module demonstrates how async Rust works under the hood:
+// 1. A hand-written Future that is woken by an OS thread (TimerFuture)
+// 2. Async methods in traits: static dispatch vs Box<dyn ...> trait objects
+// 3. Implementing Stream by hand, then processing items concurrently
+// 4. Collections of different futures with Pin<Box<dyn Future>>
+// 5. The pre-Rust-1.75 way to write async trait methods, and migrating off it
+//
+// The `trpl` crate (from "The Rust Programming Language" book) supplies the
+// runtime: trpl::run drives a future to completion, trpl::join runs two
+// futures concurrently.
+// =============================================================================
+
+use futures::stream::{Stream, StreamExt};
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn main() {
+    // =========================================================================
+    // PART 1: TimerFuture - Implementing Future by Hand
+    // =========================================================================
+    timer_future_example();
+
+    // =========================================================================
+    // PART 2: Async Traits - Static vs Dynamic Dispatch
+    // =========================================================================
+    async_trait_objects_example();
+
+    // =========================================================================
+    // PART 3: Streams - A Vec as an Async Source, Mapped Concurrently
+    // =========================================================================
+    vec_stream_example();
+
+    // =========================================================================
+    // PART 4: TaskQueue - Storing Futures of Different Types
+    // =========================================================================
+    task_queue_example();
+
+    // =========================================================================
+    // PART 5: Legacy Async Traits - The Pre-1.75 Shim
+    // =========================================================================
+    legacy_async_trait_example();
+}
+
+// =============================================================================
+// PART 1: TimerFuture - Implementing Future by Hand
+// =============================================================================
+// A Future is anything with a poll() method. The executor calls poll():
+//   - Poll::Ready(value)  -> done
+//   - Poll::Pending       -> not yet; the future PROMISES to call
+//                            cx.waker().wake() once it can make progress
+//
+// The executor does not busy-loop. After Pending it parks until some waker
+// fires, then polls again:
+//
+//   executor             TimerFuture              timer thread
+//   poll() ---------->   store waker, Pending
+//   (sleeps)                                      sleep(duration)
+//                                                 completed = true
+//   <------------------------------------------   waker.wake()
+//   poll() ---------->   Ready(())
+//
+// The state is shared with the timer thread, hence Arc<Mutex<..>>.
+
+struct TimerState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+struct TimerFuture {
+    state: Arc<Mutex<TimerState>>,
+}
+
+impl TimerFuture {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new(TimerState { completed: false, waker: None }));
+
+        // The timer starts NOW, not on first poll
+        let thread_state = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.completed = true;
+            // If nobody has polled yet there is no waker - the first poll
+            // will simply see completed == true
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        TimerFuture { state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.completed {
+            Poll::Ready(())
+        } else {
+            // Store the CURRENT waker: a future may move between tasks, so
+            // the waker from an earlier poll could be stale
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+fn timer_future_example() {
+    println!("--- Part 1: TimerFuture ---\n");
+
+    trpl::run(async {
+        let start = Instant::now();
+        TimerFuture::new(Duration::from_millis(100)).await;
+        println!("One 100ms timer:         {:?}", start.elapsed());
+
+        // Both timers run at the same time, so this takes ~200ms, not ~300ms
+        let start = Instant::now();
+        let slow = async {
+            TimerFuture::new(Duration::from_millis(200)).await;
+            println!("  200ms timer done");
+        };
+        let fast = async {
+            TimerFuture::new(Duration::from_millis(100)).await;
+            println!("  100ms timer done");
+        };
+        trpl::join(slow, fast).await;
+        println!("100ms + 200ms joined:    {:?}", start.elapsed());
+    });
+
+    println!();
+}
+
+// =============================================================================
+// PART 2: Async Traits - Static vs Dynamic Dispatch
+// =============================================================================
+// Since Rust 1.75 a trait can contain `async fn` directly. Each implementor's
+// method returns its OWN anonymous future type, so such a trait is not
+// dyn-compatible: `Box<dyn AsyncProcessor>` does not compile, because the
+// vtable would need one fixed return type.
+//
+// STATIC:  fn run(p: &impl AsyncProcessor)    one compiled copy per type,
+//                                             futures live on the stack
+// DYNAMIC: Vec<Box<dyn DynAsyncProcessor>>    one compiled copy, mixed types
+//                                             in a collection, but every call
+//                                             heap-allocates a boxed future
+//
+// The dyn version gives the future a fixed type, Pin<Box<dyn Future>>, by
+// boxing it. The 'a ties the future to &'a self, since it borrows self.
+
+trait AsyncProcessor {
+    async fn process(&self, input: i32) -> i32;
+}
+
+trait DynAsyncProcessor {
+    fn name(&self) -> &'static str;
+    fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>>;
+}
+
+struct Doubler;
+struct Squarer;
+
+impl AsyncProcessor for Doubler {
+    async fn process(&self, input: i32) -> i32 {
+        input * 2
+    }
+}
+
+impl AsyncProcessor for Squarer {
+    async fn process(&self, input: i32) -> i32 {
+        input * input
+    }
+}
+
+impl DynAsyncProcessor for Doubler {
+    fn name(&self) -> &'static str {
+        "Doubler"
+    }
+
+    fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>> {
+        Box::pin(async move { input * 2 })
+    }
+}
+
+impl DynAsyncProcessor for Squarer {
+    fn name(&self) -> &'static str {
+        "Squarer"
+    }
+
+    fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>> {
+        Box::pin(async move { input * input })
+    }
+}
+
+// Static dispatch: the compiler generates run_static::<Doubler> and
+// run_static::<Squarer> separately
+async fn run_static(processor: &impl AsyncProcessor, inputs: &[i32]) -> Vec<i32> {
+    let mut outputs = Vec::new();
+    for &input in inputs {
+        outputs.push(processor.process(input).await);
+    }
+    outputs
+}
+
+// Dynamic dispatch: one function handles any mix of processors
+async fn run_dyn(processors: &[Box<dyn DynAsyncProcessor>], inputs: &[i32]) -> Vec<Vec<i32>> {
+    let mut results = Vec::new();
+    for processor in processors {
+        let mut outputs = Vec::new();
+        for &input in inputs {
+            outputs.push(processor.process(input).await);
+        }
+        results.push(outputs);
+    }
+    results
+}
+
+fn async_trait_objects_example() {
+    println!("--- Part 2: Async Traits - Static vs Dynamic Dispatch ---\n");
+
+    let inputs = [1, 2, 3, 4];
+
+    trpl::run(async {
+        // Static: each call names a concrete type; they can't share a Vec
+        println!("static Doubler: {:?}", run_static(&Doubler, &inputs).await);
+        println!("static Squarer: {:?}", run_static(&Squarer, &inputs).await);
+
+        // Dynamic: different types behind one trait object type
+        let processors: Vec<Box<dyn DynAsyncProcessor>> = vec![Box::new(Doubler), Box::new(Squarer)];
+        let results = run_dyn(&processors, &inputs).await;
+        for (processor, outputs) in processors.iter().zip(results) {
+            println!("dyn {}:    {:?}", processor.name(), outputs);
+        }
+    });
+
+    println!();
+}
+
+// =============================================================================
+// PART 3: Streams - A Vec as an Async Source, Mapped Concurrently
+// =============================================================================
+// A Stream is the async version of Iterator:
+//
+//   Iterator::next(&mut self)                       -> Option<Item>
+//   Stream::poll_next(self: Pin<&mut Self>, cx)     -> Poll<Option<Item>>
+//
+// VecStream never has to wait, so it always answers Ready. Real streams
+// (sockets, channels) return Pending and wake the task later, like PART 1.
+//
+// map() with an async closure turns a stream of values into a stream of
+// FUTURES. buffered(n) then keeps up to n of those futures running at once,
+// still yielding results in the original order:
+//
+//   10 items x 10ms each:   one at a time    ~100ms
+//                           buffered(4)      ~30ms  (batches of 4, 4, 2)
+
+struct VecStream {
+    data: Vec<i32>,
+    pos: usize,
+}
+
+impl VecStream {
+    fn new(data: Vec<i32>) -> Self {
+        VecStream { data, pos: 0 }
+    }
+}
+
+impl Stream for VecStream {
+    type Item = i32;
+
+    // VecStream is Unpin (a Vec and a usize can move freely), so the Pin can
+    // be treated like a plain &mut Self
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<i32>> {
+        let item = self.data.get(self.pos).copied();
+        if item.is_some() {
+            self.pos += 1;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+async fn double_slowly(stream: VecStream, concurrency: usize) -> Vec<i32> {
+    stream
+        .map(|x| async move {
+            trpl::sleep(Duration::from_millis(10)).await;  // Simulated I/O
+            x * 2
+        })
+        .buffered(concurrency)
+        .collect::<Vec<_>>()
+        .await
+}
+
+fn vec_stream_example() {
+    println!("--- Part 3: Streams ---\n");
+
+    trpl::run(async {
+        // Like Iterator, a Stream can be consumed one item at a time
+        let mut stream = VecStream::new(vec![1, 2, 3]);
+        while let Some(x) = stream.next().await {
+            println!("next: {}", x);
+        }
+
+        let data: Vec<i32> = (1..=10).collect();
+        for concurrency in [1, 4] {
+            let start = Instant::now();
+            let doubled = double_slowly(VecStream::new(data.clone()), concurrency).await;
+            println!("buffered({}): {:?} in {:?}", concurrency, doubled, start.elapsed());
+        }
+    });
+
+    println!();
+}
+
+// =============================================================================
+// PART 4: TaskQueue - Storing Futures of Different Types
+// =============================================================================
+// Every async block has its own unnamed type, so two of them can't go into
+// the same Vec directly. Pin<Box<dyn Future<Output = T>>> is the canonical
+// way to ERASE those types:
+//   - Box<dyn ..> gives them one common type (heap allocated, vtable call)
+//   - Pin<..> promises the future won't move again once polled, which
+//     self-referential async state machines rely on. Box::pin keeps the
+//     future at a fixed heap address, so the Pin<Box<..>> itself is free to
+//     move around - into a Vec, out of a function, to another thread (+ Send)
+//
+// The alternative is Vec<Pin<&mut dyn Future>> over futures pinned on the
+// stack with pin!(). That avoids allocation, but the Vec only BORROWS the
+// futures: it can't outlive the current function or be stored in a struct
+// like TaskQueue, which has to OWN its tasks.
+
+struct TaskQueue {
+    tasks: Vec<Pin<Box<dyn Future<Output = String> + Send>>>,
+}
+
+impl TaskQueue {
+    fn new() -> Self {
+        TaskQueue { tasks: Vec::new() }
+    }
+
+    // 'static: the queue owns the task, so it can't borrow short-lived data
+    fn push<F: Future<Output = String> + Send + 'static>(&mut self, f: F) {
+        self.tasks.push(Box::pin(f));
+    }
+
+    // One task after another - contrast with join_all, which overlaps them
+    async fn run_all(self) -> Vec<String> {
+        let mut results = Vec::new();
+        for task in self.tasks {
+            results.push(task.await);
+        }
+        results
+    }
+}
+
+fn task_queue_example() {
+    println!("--- Part 4: TaskQueue ---\n");
+
+    trpl::run(async {
+        // Three different future types in one queue
+        let mut queue = TaskQueue::new();
+        queue.push(async { String::from("ready immediately") });
+        queue.push(async {
+            TimerFuture::new(Duration::from_millis(20)).await;
+            String::from("after a 20ms timer")
+        });
+        let name = String::from("owned data");
+        queue.push(async move { format!("moved in: {}", name) });
+
+        for result in queue.run_all().await {
+            println!("task -> {}", result);
+        }
+
+        // Stack-pinned alternative: no boxing, but only usable right here
+        let first = pin!(async { String::from("pinned on the stack") });
+        let second = pin!(async {
+            TimerFuture::new(Duration::from_millis(10)).await;
+            String::from("also on the stack")
+        });
+        let borrowed: Vec<Pin<&mut dyn Future<Output = String>>> = vec![first, second];
+        println!("join_all -> {:?}", trpl::join_all(borrowed).await);
+    });
+
+    println!();
+}
+
+// =============================================================================
+// PART 5: Legacy Async Traits - The Pre-1.75 Shim
+// =============================================================================
+// Before Rust 1.75, `async fn` was not allowed in traits at all. The
+// workaround (and what the popular #[async_trait] crate expands to) was to
+// write the desugared signature by hand and box every returned future:
+//
+//   fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>>
+//
+// versus today's
+//
+//   async fn process(&self, input: i32) -> i32
+//
+// The shim is noisier and allocates on every call, but the boxed return type
+// is a single named type - which is exactly what makes the trait
+// dyn-compatible. (PART 2's DynAsyncProcessor is the same trick.)
+//
+// MIGRATION GUIDE (legacy shim -> native async fn):
+// 1. Replace the signature with `async fn process(&self, input: i32) -> i32`
+// 2. In each impl, drop `Box::pin(async move { ... })` and keep the body
+// 3. Remove `<'a>` / `+ 'a`: the compiler ties the future to &self itself
+// 4. Need Send futures (e.g. for tokio::spawn)? Native async fn can't promise
+//    that in the trait; declare `fn process(..) -> impl Future<Output = i32> + Send`
+//    instead - impls may still write `async fn`
+// 5. Still need Box<dyn Trait>? Keep a boxed variant like this one (or the
+//    async_trait crate) next to the native trait - native async fn traits
+//    can't be trait objects
+
+trait LegacyAsyncProcessor {
+    fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>>;
+}
+
+impl Doubler {
+    fn double(&self, x: i32) -> i32 {
+        x * 2
+    }
+}
+
+impl LegacyAsyncProcessor for Doubler {
+    fn process<'a>(&'a self, input: i32) -> Pin<Box<dyn Future<Output = i32> + 'a>> {
+        // `async move` captures `self` (a &'a Doubler) - hence the + 'a
+        Box::pin(async move { self.double(input) })
+    }
+}
+
+fn legacy_async_trait_example() {
+    println!("--- Part 5: Legacy Async Traits ---\n");
+
+    trpl::run(async {
+        // Fully qualified syntax: Doubler implements three traits that all
+        // have a method named process()
+        let native = AsyncProcessor::process(&Doubler, 21).await;
+        let legacy = LegacyAsyncProcessor::process(&Doubler, 21).await;
+        println!("native async fn: {}, legacy shim: {}", native, legacy);
+
+        // Only the shim works as a trait object:
+        //   let p: Box<dyn AsyncProcessor> = Box::new(Doubler);
+        //   ERROR: the trait `AsyncProcessor` is not dyn compatible
+        let boxed: Box<dyn LegacyAsyncProcessor> = Box::new(Doubler);
+        println!("through Box<dyn LegacyAsyncProcessor>: {}", boxed.process(50).await);
+    });
+
+    println!();
+}
+
+// =============================================================================
+// KEY CONCEPTS SUMMARY
+// =============================================================================
+//
+// | Concept        | What it is                                               |
+// |----------------|----------------------------------------------------------|
+// | Future         | A value that may not be ready yet; has poll()            |
+// | Poll::Pending  | "Not yet" - the future must arrange a wake() call        |
+// | Waker          | Handle the future uses to tell the executor to poll again|
+// | Executor       | Polls futures (trpl::run); sleeps while they are Pending |
+// | join           | Polls several futures in one task, so they overlap       |
+// | async fn in    | Static dispatch only; box the future (Pin<Box<dyn        |
+// |   trait        |   Future>>) to use the trait as a trait object           |
+// | Stream         | Async Iterator: poll_next() -> Poll<Option<Item>>        |
+// | buffered(n)    | Runs up to n futures from a stream at once, in order     |
+// | Pin<Box<dyn    | Owned, type-erased future; lets different futures share  |
+// |   Future>>     |   one Vec                                                |
+// | Pre-1.75 shim  | fn -> Pin<Box<dyn Future + 'a>>: verbose, allocates,     |
+// |                |   but dyn-compatible                                     |
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_future_completes() {
+        let start = Instant::now();
+        trpl::run(TimerFuture::new(Duration::from_millis(50)));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_joined_timers_take_max_not_sum() {
+        let start = Instant::now();
+        trpl::run(async {
+            trpl::join(
+                TimerFuture::new(Duration::from_millis(100)),
+                TimerFuture::new(Duration::from_millis(200)),
+            )
+            .await;
+        });
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200), "finished early: {:?}", elapsed);
+        // Run one after the other, they could never beat the 300ms sum
+        assert!(elapsed < Duration::from_millis(300), "timers did not overlap: {:?}", elapsed);
+    }
+
+    #[test]
+    fn test_static_and_dyn_dispatch_agree() {
+        let inputs = [-3, 0, 1, 5, 12];
+        let processors: Vec<Box<dyn DynAsyncProcessor>> = vec![Box::new(Doubler), Box::new(Squarer)];
+
+        trpl::run(async {
+            let static_results = vec![
+                run_static(&Doubler, &inputs).await,
+                run_static(&Squarer, &inputs).await,
+            ];
+            let dyn_results = run_dyn(&processors, &inputs).await;
+            assert_eq!(static_results, dyn_results);
+            assert_eq!(dyn_results[0], vec![-6, 0, 2, 10, 24]);
+            assert_eq!(dyn_results[1], vec![9, 0, 1, 25, 144]);
+        });
+    }
+
+    #[test]
+    fn test_vec_stream_yields_all_items() {
+        let items = trpl::run(VecStream::new(vec![5, 6, 7]).collect::<Vec<_>>());
+        assert_eq!(items, vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn test_buffered_runs_items_concurrently() {
+        let start = Instant::now();
+        let doubled = trpl::run(double_slowly(VecStream::new((1..=10).collect()), 4));
+        let elapsed = start.elapsed();
+
+        assert_eq!(doubled, (1..=10).map(|x| x * 2).collect::<Vec<_>>());  // Order kept
+        // ~30ms for 3 batches of 4; one at a time would take the 100ms sum.
+        // Bound against the sum, not the ideal, so a loaded machine passes
+        let sum_of_delays = Duration::from_millis(10) * 10;
+        assert!(elapsed >= Duration::from_millis(30), "finished early: {:?}", elapsed);
+        assert!(elapsed < sum_of_delays * 3 / 4, "not concurrent: {:?}", elapsed);
+    }
+
+    #[test]
+    fn test_task_queue_runs_all_tasks_in_order() {
+        let mut queue = TaskQueue::new();
+        queue.push(async { String::from("one") });
+        queue.push(async {
+            TimerFuture::new(Duration::from_millis(10)).await;
+            String::from("two")
+        });
+        queue.push(async { format!("{}", 3) });
+
+        assert_eq!(trpl::run(queue.run_all()), vec!["one", "two", "3"]);
+    }
+
+    #[test]
+    fn test_legacy_shim_matches_native_async_fn() {
+        trpl::run(async {
+            for input in [-4, 0, 7, 1000] {
+                let native = AsyncProcessor::process(&Doubler, input).await;
+                let legacy = LegacyAsyncProcessor::process(&Doubler, input).await;
+                assert_eq!(native, legacy);
+            }
+        });
+    }
+
+    #[test]
+    fn test_legacy_shim_works_as_trait_object() {
+        // The native version does not compile here:
+        // let processors: Vec<Box<dyn AsyncProcessor>> = vec![Box::new(Doubler)];
+        let processors: Vec<Box<dyn LegacyAsyncProcessor>> = vec![Box::new(Doubler), Box::new(Doubler)];
+        let outputs = trpl::run(async {
+            let mut outputs = Vec::new();
+            for (i, processor) in processors.iter().enumerate() {
+                outputs.push(processor.process(i as i32 + 1).await);
+            }
+            outputs
+        });
+        assert_eq!(outputs, vec![2, 4]);
+    }
+}
@@ -31,6 +31,61 @@ fn main() {
     // PART 4: STRATEGY PATTERN
     // =========================================================================
     strategy_pattern_example();
+
+    // =========================================================================
+    // PART 5: DECORATOR PATTERN
+    // =========================================================================
+    decorator_pattern_example();
+
+    // =========================================================================
+    // PART 6: COMMAND PATTERN
+    // =========================================================================
+    command_pattern_example();
+
+    // =========================================================================
+    // PART 7: COMPOSITE PATTERN
+    // =========================================================================
+    composite_pattern_example();
+
+    // =========================================================================
+    // PART 8: OBSERVER PATTERN
+    // =========================================================================
+    observer_pattern_example();
+
+    // =========================================================================
+    // PART 9: BUILDER PATTERN
+    // =========================================================================
+    builder_pattern_example();
+
+    // =========================================================================
+    // PART 10: TYPESTATE PATTERN
+    // =========================================================================
+    typestate_pattern_example();
+
+    // =========================================================================
+    // PART 11: VISITOR PATTERN
+    // =========================================================================
+    visitor_pattern_example();
+
+    // =========================================================================
+    // PART 12: PLUGIN REGISTRY
+    // =========================================================================
+    plugin_registry_example();
+
+    // =========================================================================
+    // PART 13: CHAIN OF RESPONSIBILITY
+    // =========================================================================
+    chain_of_responsibility_example();
+
+    // =========================================================================
+    // PART 14: STATE MACHINE MACRO
+    // =========================================================================
+    state_machine_example();
+
+    // =========================================================================
+    // PART 15: PROXY PATTERN
+    // =========================================================================
+    proxy_pattern_example();
 }
 
 // =============================================================================
@@ -111,6 +166,11 @@ fn encapsulation_example() {
 /// Trait defining drawable behavior
 trait Draw {
     fn draw(&self);
+
+    // Double dispatch hook for the Visitor pattern (PART 11).
+    // Deliberately no default: a component that forgot to forward would be
+    // silently skipped by every visitor. Wrappers pass it to their inner.
+    fn accept(&self, visitor: &dyn Visitor);
 }
 
 // Different types implementing the same trait
@@ -127,6 +187,10 @@ impl Draw for Button {
             self.width, self.height, self.label
         );
     }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        visitor.visit_button(self);
+    }
 }
 
 struct SelectBox {
@@ -144,6 +208,10 @@ impl Draw for SelectBox {
             self.options.len()
         );
     }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        visitor.visit_select_box(self);
+    }
 }
 
 struct TextField {
@@ -158,10 +226,15 @@ impl Draw for TextField {
             self.width, self.placeholder
         );
     }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        visitor.visit_text_field(self);
+    }
 }
 
 /// Screen holds a collection of drawable components
 /// Box<dyn Draw> = trait object, allows ANY type implementing Draw
+#[derive(Default)]  // An empty screen; Vec<Box<dyn Draw>> defaults to []
 struct Screen {
     // Vec of trait objects - can hold different types!
     components: Vec<Box<dyn Draw>>,
@@ -176,12 +249,26 @@ impl Screen {
         self.components.push(component);
     }
 
+    fn component_count(&self) -> usize {
+        self.components.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
     fn run(&self) {
         // Polymorphic call - each component's draw() is called
         for component in self.components.iter() {
             component.draw();  // Dynamic dispatch at runtime
         }
     }
+
+    fn visit(&self, visitor: &dyn Visitor) {
+        for component in self.components.iter() {
+            component.accept(visitor);
+        }
+    }
 }
 
 fn polymorphism_example() {
@@ -218,111 +305,314 @@ fn polymorphism_example() {
 }
 
 // =============================================================================
-// PART 3: STATE PATTERN - Object Changes Behavior Based on State
+// PART 3: STATE PATTERN - States as Types, Checked at Compile Time
 // =============================================================================
-// Classic OOP pattern implemented in Rust using trait objects
+// The classic OOP version stores the state in a Box<dyn State> field and
+// checks it at RUNTIME: calling content() on a draft quietly returns "",
+// and approve() on a draft silently does nothing.
+//
+// Rust can do better with the TYPESTATE pattern. Each state is a separate
+// TYPE: Post<Draft>, Post<PendingReview>, Post<Published>. Each method
+// exists only on the states where it makes sense, and transitions consume
+// `self` and return a post of the new type. Invalid transitions aren't
+// handled - they don't compile (see PART 10).
 
 mod blog {
+    use std::marker::PhantomData;
+    use std::mem;
+
+    // Marker types - zero-sized, never instantiated, only used as labels
+    pub struct Draft;
+    pub struct PendingReview;
+    pub struct Published;
+
+    /// Implemented by the three markers so generic code can name the state
+    pub trait State {
+        const NAME: &'static str;
+    }
+
+    impl State for Draft {
+        const NAME: &'static str = "Draft";
+    }
+
+    impl State for PendingReview {
+        const NAME: &'static str = "PendingReview";
+    }
+
+    impl State for Published {
+        const NAME: &'static str = "Published";
+    }
+
     /// Blog post that goes through Draft -> PendingReview -> Published
-    pub struct Post {
-        state: Option<Box<dyn State>>,
+    /// No public constructor - the only way to get a Post is PostBuilder
+    pub struct Post<S: State> {
+        title: String,
         content: String,
+        tags: Vec<String>,
+        // PhantomData "uses" S without storing one: zero bytes at runtime
+        _state: PhantomData<S>,
     }
 
-    impl Post {
-        pub fn new() -> Post {
-            Post {
-                state: Some(Box::new(Draft {})),
-                content: String::new(),
-            }
+    // Available in EVERY state
+    impl<S: State> Post<S> {
+        pub fn title(&self) -> &str {
+            &self.title
         }
 
+        pub fn tags(&self) -> &[String] {
+            &self.tags
+        }
+
+        // Length of the text written so far, regardless of state
+        pub fn text_len(&self) -> usize {
+            self.content.len()
+        }
+
+        pub fn state_name(&self) -> &'static str {
+            S::NAME
+        }
+
+        // Same fields, new state label - private, so only the transition
+        // methods below can change a post's state
+        fn into_state<T: State>(self) -> Post<T> {
+            Post { title: self.title, content: self.content, tags: self.tags, _state: PhantomData }
+        }
+    }
+
+    impl Post<Draft> {
         pub fn add_text(&mut self, text: &str) {
             self.content.push_str(text);
         }
 
+        // Drop everything after the first `len` bytes
+        pub fn truncate_text(&mut self, len: usize) {
+            self.content.truncate(len);
+        }
+
+        // Takes self by value: the Post<Draft> is gone afterwards
+        pub fn request_review(self) -> Post<PendingReview> {
+            self.into_state()
+        }
+    }
+
+    impl Post<PendingReview> {
+        pub fn approve(self) -> Post<Published> {
+            self.into_state()
+        }
+
+        // Send back for more edits
+        pub fn reject(self) -> Post<Draft> {
+            self.into_state()
+        }
+    }
+
+    impl Post<Published> {
+        // Only published posts have readable content
         pub fn content(&self) -> &str {
-            // Delegate to state - only Published returns content
-            self.state.as_ref().unwrap().content(self)
+            &self.content
+        }
+    }
+
+    // =========================================================================
+    // ANYPOST (see PART 10) - when the state is only known at runtime
+    // =========================================================================
+
+    /// One variant per state, so a post whose state changes at runtime
+    /// (e.g. behind an undo history) can live in a single variable
+    pub enum AnyPost {
+        Draft(Post<Draft>),
+        PendingReview(Post<PendingReview>),
+        Published(Post<Published>),
+    }
+
+    impl From<Post<Draft>> for AnyPost {
+        fn from(post: Post<Draft>) -> Self {
+            AnyPost::Draft(post)
         }
+    }
 
-        pub fn request_review(&mut self) {
-            // Take ownership of state, transform it, put back
-            if let Some(s) = self.state.take() {
-                self.state = Some(s.request_review())
+    impl AnyPost {
+        // Back to the compile-time API, if the post is still a draft
+        pub fn into_draft(self) -> Option<Post<Draft>> {
+            match self {
+                AnyPost::Draft(p) => Some(p),
+                _ => None,
             }
         }
 
-        pub fn approve(&mut self) {
-            if let Some(s) = self.state.take() {
-                self.state = Some(s.approve())
+        pub fn title(&self) -> &str {
+            match self {
+                AnyPost::Draft(p) => p.title(),
+                AnyPost::PendingReview(p) => p.title(),
+                AnyPost::Published(p) => p.title(),
             }
         }
 
-        pub fn state_name(&self) -> &str {
-            self.state.as_ref().unwrap().name()
+        pub fn tags(&self) -> &[String] {
+            match self {
+                AnyPost::Draft(p) => p.tags(),
+                AnyPost::PendingReview(p) => p.tags(),
+                AnyPost::Published(p) => p.tags(),
+            }
         }
-    }
 
-    // Private trait - internal implementation detail
-    trait State {
-        fn request_review(self: Box<Self>) -> Box<dyn State>;
-        fn approve(self: Box<Self>) -> Box<dyn State>;
-        fn content<'a>(&self, _post: &'a Post) -> &'a str {
-            ""  // Default: return empty string
+        pub fn state_name(&self) -> &'static str {
+            match self {
+                AnyPost::Draft(p) => p.state_name(),
+                AnyPost::PendingReview(p) => p.state_name(),
+                AnyPost::Published(p) => p.state_name(),
+            }
         }
-        fn name(&self) -> &str;
-    }
 
-    struct Draft {}
+        pub fn text_len(&self) -> usize {
+            match self {
+                AnyPost::Draft(p) => p.text_len(),
+                AnyPost::PendingReview(p) => p.text_len(),
+                AnyPost::Published(p) => p.text_len(),
+            }
+        }
 
-    impl State for Draft {
-        fn request_review(self: Box<Self>) -> Box<dyn State> {
-            Box::new(PendingReview {})
+        // Each method returns whether it applied; the match makes the
+        // "only in Draft" rule explicit instead of silently ignoring it
+        pub fn add_text(&mut self, text: &str) -> bool {
+            match self {
+                AnyPost::Draft(p) => {
+                    p.add_text(text);
+                    true
+                }
+                _ => false,
+            }
         }
 
-        fn approve(self: Box<Self>) -> Box<dyn State> {
-            self  // Can't approve a draft - stay in Draft
+        pub fn truncate_text(&mut self, len: usize) -> bool {
+            match self {
+                AnyPost::Draft(p) => {
+                    p.truncate_text(len);
+                    true
+                }
+                _ => false,
+            }
         }
 
-        fn name(&self) -> &str {
-            "Draft"
+        pub fn request_review(&mut self) -> bool {
+            match self {
+                AnyPost::Draft(p) => {
+                    // The transition needs the post BY VALUE, but we only
+                    // have &mut - so move the fields out, leaving empties
+                    let draft = p.take();
+                    *self = AnyPost::PendingReview(draft.request_review());
+                    true
+                }
+                _ => false,
+            }
         }
-    }
 
-    struct PendingReview {}
+        pub fn approve(&mut self) -> bool {
+            match self {
+                AnyPost::PendingReview(p) => {
+                    let pending = p.take();
+                    *self = AnyPost::Published(pending.approve());
+                    true
+                }
+                _ => false,
+            }
+        }
 
-    impl State for PendingReview {
-        fn request_review(self: Box<Self>) -> Box<dyn State> {
-            self  // Already pending - stay in PendingReview
+        pub fn reject(&mut self) -> bool {
+            match self {
+                AnyPost::PendingReview(p) => {
+                    let pending = p.take();
+                    *self = AnyPost::Draft(pending.reject());
+                    true
+                }
+                _ => false,
+            }
         }
 
-        fn approve(self: Box<Self>) -> Box<dyn State> {
-            Box::new(Published {})
+        // None unless Published - the runtime echo of Post<Published>::content
+        pub fn content(&self) -> Option<&str> {
+            match self {
+                AnyPost::Published(p) => Some(p.content()),
+                _ => None,
+            }
         }
+    }
 
-        fn name(&self) -> &str {
-            "PendingReview"
+    impl<S: State> Post<S> {
+        // Moves the contents out through &mut, leaving an empty post behind.
+        // Private: it's only used just before the old value is overwritten.
+        fn take(&mut self) -> Self {
+            Post {
+                title: mem::take(&mut self.title),
+                content: mem::take(&mut self.content),
+                tags: mem::take(&mut self.tags),
+                _state: PhantomData,
+            }
         }
     }
 
-    struct Published {}
+    // =========================================================================
+    // BUILDER (see PART 9) - lives in this module so it can set private fields
+    // =========================================================================
+
+    /// Collects optional pieces, then validates them all at once in build()
+    pub struct PostBuilder {
+        title: Option<String>,
+        body: Option<String>,
+        tags: Vec<String>,
+        draft: bool,
+    }
 
-    impl State for Published {
-        fn request_review(self: Box<Self>) -> Box<dyn State> {
-            self  // Already published - stay in Published
+    impl PostBuilder {
+        pub fn new() -> Self {
+            PostBuilder {
+                title: None,
+                body: None,
+                tags: vec![],
+                draft: true,  // New posts start as drafts unless told otherwise
+            }
         }
 
-        fn approve(self: Box<Self>) -> Box<dyn State> {
-            self  // Already published
+        // Each setter takes `mut self` by value and returns it,
+        // which is what makes the calls chainable
+        pub fn title(mut self, t: &str) -> Self {
+            self.title = Some(t.to_string());
+            self
         }
 
-        fn content<'a>(&self, post: &'a Post) -> &'a str {
-            &post.content  // Only Published returns actual content!
+        pub fn body(mut self, b: &str) -> Self {
+            self.body = Some(b.to_string());
+            self
         }
 
-        fn name(&self) -> &str {
-            "Published"
+        pub fn add_tag(mut self, tag: &str) -> Self {
+            self.tags.push(tag.to_string());
+            self
+        }
+
+        // draft(false) submits the post for review straight away
+        pub fn draft(mut self, d: bool) -> Self {
+            self.draft = d;
+            self
+        }
+
+        // A runtime flag can't pick the return TYPE, so the result is an
+        // AnyPost. Call into_draft() on it to get back a Post<Draft>.
+        pub fn build(self) -> Result<AnyPost, String> {
+            let title = self.title.ok_or("title required")?;
+            let body = self.body.ok_or("body required")?;
+
+            let post: Post<Draft> = Post {
+                title,
+                content: body,
+                tags: self.tags,
+                _state: PhantomData,
+            };
+            if self.draft {
+                Ok(AnyPost::Draft(post))
+            } else {
+                Ok(AnyPost::PendingReview(post.request_review()))
+            }
         }
     }
 }
@@ -330,15 +620,24 @@ mod blog {
 fn state_pattern_example() {
     println!("--- Part 3: State Pattern ---\n");
 
-    let mut post = blog::Post::new();
+    let mut post = blog::PostBuilder::new()
+        .title("First post")
+        .body("Hello, this is my first blog post!")
+        .build()
+        .ok()
+        .and_then(blog::AnyPost::into_draft)
+        .expect("a valid post starts as a draft");
+    post.add_text(" Edited.");
+    println!("State: {}, {} bytes written", post.state_name(), post.text_len());
 
-    post.add_text("Hello, this is my first blog post!");
-    println!("State: {}, Content: '{}'", post.state_name(), post.content());
+    // Shadowing is idiomatic here: each step produces a value of a new type
+    let post = post.request_review();
+    println!("State: {}", post.state_name());
 
-    post.request_review();
-    println!("State: {}, Content: '{}'", post.state_name(), post.content());
+    let post = post.reject();  // Back to Draft for another pass
+    println!("State: {}", post.state_name());
 
-    post.approve();
+    let post = post.request_review().approve();
     println!("State: {}, Content: '{}'", post.state_name(), post.content());
 
     println!();
@@ -348,15 +647,20 @@ fn state_pattern_example() {
 // PART 4: STRATEGY PATTERN - Interchangeable Algorithms
 // =============================================================================
 // Define a family of algorithms, encapsulate each one, make them interchangeable
+//
+// The trait is generic over the element type T, so one Sorter<T> works for
+// Vec<i32>, Vec<String>, Vec<(i32, i32)>...
+
+use std::cmp::Ordering;
 
-trait SortStrategy {
-    fn sort(&self, data: &mut Vec<i32>);
+trait SortStrategy<T> {
+    fn sort(&self, data: &mut Vec<T>);
     fn name(&self) -> &str;
 }
 
 struct BubbleSort;
-impl SortStrategy for BubbleSort {
-    fn sort(&self, data: &mut Vec<i32>) {
+impl<T: PartialOrd> SortStrategy<T> for BubbleSort {
+    fn sort(&self, data: &mut Vec<T>) {
         let len = data.len();
         for i in 0..len {
             for j in 0..len - 1 - i {
@@ -372,8 +676,8 @@ impl SortStrategy for BubbleSort {
 }
 
 struct QuickSortSimple;
-impl SortStrategy for QuickSortSimple {
-    fn sort(&self, data: &mut Vec<i32>) {
+impl<T: Ord> SortStrategy<T> for QuickSortSimple {
+    fn sort(&self, data: &mut Vec<T>) {
         data.sort();  // Use built-in sort for simplicity
     }
     fn name(&self) -> &str {
@@ -381,20 +685,43 @@ impl SortStrategy for QuickSortSimple {
     }
 }
 
-struct Sorter {
-    strategy: Box<dyn SortStrategy>,
+// Strategy defined by a closure: any ordering, for any T, chosen at runtime.
+// Box<dyn Fn> because every closure has its own unnameable type.
+type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+struct ComparatorSort<T> {
+    compare: Comparator<T>,
+}
+
+impl<T> ComparatorSort<T> {
+    fn new(compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        ComparatorSort { compare: Box::new(compare) }
+    }
+}
+
+impl<T> SortStrategy<T> for ComparatorSort<T> {
+    fn sort(&self, data: &mut Vec<T>) {
+        data.sort_by(|a, b| (self.compare)(a, b));  // Parens call the field
+    }
+    fn name(&self) -> &str {
+        "ComparatorSort"
+    }
+}
+
+struct Sorter<T> {
+    strategy: Box<dyn SortStrategy<T>>,
 }
 
-impl Sorter {
-    fn new(strategy: Box<dyn SortStrategy>) -> Self {
+impl<T> Sorter<T> {
+    fn new(strategy: Box<dyn SortStrategy<T>>) -> Self {
         Sorter { strategy }
     }
 
-    fn set_strategy(&mut self, strategy: Box<dyn SortStrategy>) {
+    fn set_strategy(&mut self, strategy: Box<dyn SortStrategy<T>>) {
         self.strategy = strategy;
     }
 
-    fn sort(&self, data: &mut Vec<i32>) {
+    fn sort(&self, data: &mut Vec<T>) {
         println!("Sorting with {}...", self.strategy.name());
         self.strategy.sort(data);
     }
@@ -416,27 +743,1508 @@ fn strategy_pattern_example() {
     sorter.sort(&mut data);
     println!("After QuickSort: {:?}", data);
 
+    // Same Sorter type, different element types and orderings
+    let mut fruits = vec![String::from("banana"), String::from("fig"), String::from("cherry")];
+    let by_length = Sorter::new(Box::new(ComparatorSort::new(|a: &String, b: &String| {
+        a.len().cmp(&b.len())
+    })));
+    by_length.sort(&mut fruits);
+    println!("By length: {:?}", fruits);
+
+    let mut pairs = vec![(1, 9), (2, 3), (3, 6)];
+    let by_second = Sorter::new(Box::new(ComparatorSort::new(|a: &(i32, i32), b: &(i32, i32)| {
+        a.1.cmp(&b.1)
+    })));
+    by_second.sort(&mut pairs);
+    println!("By second element: {:?}", pairs);
+
     println!();
 }
 
 // =============================================================================
-// KEY CONCEPTS SUMMARY
+// PART 5: DECORATOR PATTERN - Wrapping Behavior Around a Component
 // =============================================================================
+// A decorator implements the SAME trait as the thing it wraps, adds some
+// behavior, then delegates. Because the result is still a Draw, decorators
+// can wrap each other: Logging(Cached(Button)).
 //
-// | OOP Concept    | Rust Approach                                |
-// |----------------|----------------------------------------------|
-// | Encapsulation  | pub/private, impl blocks                     |
-// | Inheritance    | NOT supported - use composition + traits     |
-// | Polymorphism   | Trait objects (dyn Trait) or generics        |
-// | Interfaces     | Traits                                       |
-// | Abstract class | Trait with default implementations           |
-//
-// TRAIT OBJECTS VS GENERICS:
-// - Generics: Static dispatch, faster, monomorphized at compile time
-// - Trait objects: Dynamic dispatch, more flexible, runtime overhead
+// Rust version of "inheritance-free extension": composition + traits.
+
+use std::cell::Cell;
+
+// Forwarding impl so Box<dyn Draw> itself satisfies T: Draw.
+// ?Sized allows T = dyn Draw (trait objects have no compile-time size).
+impl<T: Draw + ?Sized> Draw for Box<T> {
+    fn draw(&self) {
+        (**self).draw();
+    }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        (**self).accept(visitor);
+    }
+}
+
+/// Prints a label before delegating to the wrapped component
+struct LoggingDraw<T: Draw> {
+    inner: T,
+    label: String,
+}
+
+impl<T: Draw> Draw for LoggingDraw<T> {
+    fn draw(&self) {
+        println!("Drawing: {}", self.label);
+        self.inner.draw();
+    }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        self.inner.accept(visitor);
+    }
+}
+
+/// Draws the wrapped component only the first time
+/// draw() takes &self, so the flag needs interior mutability (Cell)
+struct CachedDraw<T: Draw> {
+    inner: T,
+    drawn: Cell<bool>,
+}
+
+impl<T: Draw> CachedDraw<T> {
+    fn new(inner: T) -> Self {
+        CachedDraw { inner, drawn: Cell::new(false) }
+    }
+}
+
+impl<T: Draw> Draw for CachedDraw<T> {
+    fn draw(&self) {
+        if self.drawn.get() {
+            return;  // Already drawn - skip the expensive work
+        }
+        self.inner.draw();
+        self.drawn.set(true);
+    }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        self.inner.accept(visitor);
+    }
+}
+
+fn decorator_pattern_example() {
+    println!("--- Part 5: Decorator Pattern ---\n");
+
+    // Decorators compose: the outer logs every call, the inner draws once
+    let button = LoggingDraw {
+        inner: CachedDraw::new(Button {
+            width: 80,
+            height: 20,
+            label: String::from("OK"),
+        }),
+        label: String::from("cached OK button"),
+    };
+    button.draw();
+    button.draw();  // Logs again, but the Button isn't redrawn
+
+    // Works for trait objects too, thanks to the Box<T> forwarding impl
+    let boxed: Box<dyn Draw> = Box::new(TextField {
+        width: 120,
+        placeholder: String::from("Search..."),
+    });
+    let logged = LoggingDraw { inner: boxed, label: String::from("boxed text field") };
+
+    // ...and the decorated value can itself go into a Screen
+    let mut screen = Screen::new();
+    screen.add(Box::new(logged));
+    screen.run();
+
+    println!();
+}
+
+// =============================================================================
+// PART 6: COMMAND PATTERN - Operations as Objects (with Undo/Redo)
+// =============================================================================
+// Each edit becomes a value that knows how to do AND undo itself.
+// A history of Box<dyn Command> then gives undo/redo for free.
 //
-// WHEN TO USE EACH:
-// - Generics: When you know types at compile time
-// - Trait objects: When you need a collection of different types
-//                  or plugin-style architecture
+// Commands share the post through Rc<RefCell<AnyPost>>. A raw pointer
+// would also work, but every access would need `unsafe` and nothing would
+// stop the post from being dropped while a command still points at it.
+// AnyPost (PART 10) rather than Post<Draft>, because undo/redo moves the
+// post between states at runtime.
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+trait Command {
+    fn execute(&mut self);
+    fn undo(&mut self);
+}
+
+struct AddTextCommand {
+    post: Rc<RefCell<blog::AnyPost>>,
+    text: String,
+    len_before: usize,  // Recorded on execute, used by undo
+    applied: bool,      // False if the post wasn't a Draft
+}
+
+impl AddTextCommand {
+    fn new(post: &Rc<RefCell<blog::AnyPost>>, text: &str) -> Self {
+        AddTextCommand {
+            post: Rc::clone(post),
+            text: text.to_string(),
+            len_before: 0,
+            applied: false,
+        }
+    }
+}
+
+impl Command for AddTextCommand {
+    fn execute(&mut self) {
+        let mut post = self.post.borrow_mut();
+        self.len_before = post.text_len();
+        self.applied = post.add_text(&self.text);
+    }
+
+    fn undo(&mut self) {
+        if self.applied {
+            self.post.borrow_mut().truncate_text(self.len_before);
+        }
+    }
+}
+
+struct RequestReviewCommand {
+    post: Rc<RefCell<blog::AnyPost>>,
+    moved: bool,  // Only undo a transition we actually caused
+}
+
+impl RequestReviewCommand {
+    fn new(post: &Rc<RefCell<blog::AnyPost>>) -> Self {
+        RequestReviewCommand { post: Rc::clone(post), moved: false }
+    }
+}
+
+impl Command for RequestReviewCommand {
+    fn execute(&mut self) {
+        self.moved = self.post.borrow_mut().request_review();
+    }
+
+    fn undo(&mut self) {
+        if self.moved {
+            self.post.borrow_mut().reject();  // PendingReview -> Draft
+        }
+    }
+}
+
+/// Two stacks: commands that can be undone, and commands that can be redone
+struct CommandHistory {
+    history: Vec<Box<dyn Command>>,
+    redo: Vec<Box<dyn Command>>,
+}
+
+impl CommandHistory {
+    fn new() -> Self {
+        CommandHistory { history: vec![], redo: vec![] }
+    }
+
+    fn execute(&mut self, mut command: Box<dyn Command>) {
+        command.execute();
+        self.history.push(command);
+        self.redo.clear();  // A new edit invalidates the redo stack
+    }
+
+    fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(mut command) => {
+                command.undo();
+                self.redo.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(mut command) => {
+                command.execute();
+                self.history.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn command_pattern_example() {
+    println!("--- Part 6: Command Pattern ---\n");
+
+    let post = blog::PostBuilder::new().title("Commands").body("Undo").build().unwrap();
+    let post = Rc::new(RefCell::new(post));
+    let mut history = CommandHistory::new();
+
+    history.execute(Box::new(AddTextCommand::new(&post, " is easy")));
+    history.execute(Box::new(RequestReviewCommand::new(&post)));
+    println!("After 2 commands: {} ({} bytes)", post.borrow().state_name(), post.borrow().text_len());
+
+    history.undo();
+    history.undo();
+    println!("After 2 undos:    {} ({} bytes)", post.borrow().state_name(), post.borrow().text_len());
+
+    history.redo();
+    println!("After 1 redo:     {} ({} bytes)", post.borrow().state_name(), post.borrow().text_len());
+
+    println!();
+}
+
 // =============================================================================
+// PART 7: COMPOSITE PATTERN - Treating Leaves and Containers Uniformly
+// =============================================================================
+// Files and directories both implement FsNode, and a Directory holds
+// Box<dyn FsNode> children - which may be files OR more directories.
+// Callers ask any node for its size without caring which kind it is.
+
+mod filesystem {
+    pub trait FsNode {
+        fn size(&self) -> u64;
+        fn name(&self) -> &str;
+
+        // Writes this node (and any children) into `out`, one line each
+        fn render(&self, indent: usize, out: &mut String);
+
+        // Default method built on top of render()
+        fn print_tree(&self, indent: usize) {
+            let mut out = String::new();
+            self.render(indent, &mut out);
+            print!("{}", out);
+        }
+    }
+
+    /// Leaf node - has a size of its own
+    pub struct File {
+        name: String,
+        size_bytes: u64,
+    }
+
+    impl File {
+        pub fn new(name: &str, size_bytes: u64) -> Self {
+            File { name: name.to_string(), size_bytes }
+        }
+    }
+
+    impl FsNode for File {
+        fn size(&self) -> u64 {
+            self.size_bytes
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn render(&self, indent: usize, out: &mut String) {
+            out.push_str(&format!("{}{} ({} bytes)\n", " ".repeat(indent), self.name, self.size_bytes));
+        }
+    }
+
+    /// Composite node - its size is the sum of its children
+    pub struct Directory {
+        name: String,
+        children: Vec<Box<dyn FsNode>>,
+    }
+
+    impl Directory {
+        pub fn new(name: &str) -> Self {
+            Directory { name: name.to_string(), children: vec![] }
+        }
+
+        pub fn add(&mut self, child: Box<dyn FsNode>) {
+            self.children.push(child);
+        }
+    }
+
+    impl FsNode for Directory {
+        fn size(&self) -> u64 {
+            // Recursion happens through the trait: a child Directory
+            // sums ITS children, a File returns its own size
+            self.children.iter().map(|child| child.size()).sum()
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn render(&self, indent: usize, out: &mut String) {
+            out.push_str(&format!("{}{}/\n", " ".repeat(indent), self.name));
+            for child in &self.children {
+                child.render(indent + 2, out);  // Each level indents 2 more
+            }
+        }
+    }
+}
+
+use filesystem::FsNode;
+
+fn composite_pattern_example() {
+    println!("--- Part 7: Composite Pattern ---\n");
+
+    let mut src = filesystem::Directory::new("src");
+    src.add(Box::new(filesystem::File::new("main.rs", 1200)));
+
+    let mut root = filesystem::Directory::new("project");
+    root.add(Box::new(filesystem::File::new("Cargo.toml", 150)));
+    root.add(Box::new(filesystem::File::new("README.md", 300)));
+    root.add(Box::new(src));
+
+    root.print_tree(0);
+    println!("Total size of '{}': {} bytes", root.name(), root.size());
+
+    println!();
+}
+
+// =============================================================================
+// PART 8: OBSERVER PATTERN - Notify Many Listeners of an Event
+// =============================================================================
+// The EventBus doesn't know what its observers do - it only knows they
+// implement Observer. Adding a new kind of listener needs no bus changes.
+//
+// on_event takes &self, so observers that keep state use RefCell.
+
+trait Observer {
+    fn on_event(&self, event: &str);
+}
+
+// Lets the caller keep an Rc handle to an observer it has subscribed,
+// so it can still inspect the observer's state afterwards
+impl<T: Observer + ?Sized> Observer for Rc<T> {
+    fn on_event(&self, event: &str) {
+        (**self).on_event(event);
+    }
+}
+
+struct EventBus {
+    observers: Vec<Box<dyn Observer>>,
+}
+
+impl EventBus {
+    fn new() -> Self {
+        EventBus { observers: vec![] }
+    }
+
+    fn subscribe(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    fn unsubscribe_all(&mut self) {
+        self.observers.clear();
+    }
+
+    fn publish(&self, event: &str) {
+        for observer in &self.observers {
+            observer.on_event(event);
+        }
+    }
+}
+
+struct LogObserver;
+
+impl Observer for LogObserver {
+    fn on_event(&self, event: &str) {
+        println!("[log] {}", event);
+    }
+}
+
+struct CountingObserver {
+    count: RefCell<u32>,
+}
+
+impl CountingObserver {
+    fn new() -> Self {
+        CountingObserver { count: RefCell::new(0) }
+    }
+}
+
+impl Observer for CountingObserver {
+    fn on_event(&self, _event: &str) {
+        *self.count.borrow_mut() += 1;
+    }
+}
+
+fn observer_pattern_example() {
+    println!("--- Part 8: Observer Pattern ---\n");
+
+    let counter = Rc::new(CountingObserver::new());
+    let mut bus = EventBus::new();
+    bus.subscribe(Box::new(LogObserver));
+    bus.subscribe(Box::new(Rc::clone(&counter)));
+
+    bus.publish("user signed up");
+    bus.publish("user logged in");
+    println!("Events counted: {}", counter.count.borrow());
+
+    bus.unsubscribe_all();
+    bus.publish("nobody hears this");
+    println!("Events counted after unsubscribe: {}", counter.count.borrow());
+
+    println!();
+}
+
+// =============================================================================
+// PART 9: BUILDER PATTERN - Step-by-Step Construction with Validation
+// =============================================================================
+// Rust has no named or optional arguments. A builder fills that gap:
+// optional fields get sensible defaults, required ones are checked in
+// build(), and an invalid Post can never exist.
+// (PostBuilder is defined inside `mod blog` above.)
+//
+// ScreenBuilder below is the simpler, INFALLIBLE kind: every Screen is
+// valid, so build() returns a Screen rather than a Result. Each method
+// takes `self` by value and hands it back, which is what lets calls chain.
+
+#[derive(Default)]
+struct ScreenBuilder {
+    components: Vec<Box<dyn Draw>>,
+}
+
+impl ScreenBuilder {
+    // Generic, so callers pass a plain Button instead of Box::new(Button).
+    // 'static because the Box<dyn Draw> will own it with no lifetime limit.
+    fn add<C: Draw + 'static>(mut self, c: C) -> Self {
+        self.components.push(Box::new(c));
+        self
+    }
+
+    fn build(self) -> Screen {
+        Screen { components: self.components }
+    }
+}
+
+fn builder_pattern_example() {
+    println!("--- Part 9: Builder Pattern ---\n");
+
+    let post = blog::PostBuilder::new()
+        .title("Builders in Rust")
+        .body("Chain setters, then call build().")
+        .add_tag("rust")
+        .add_tag("patterns")
+        .draft(false)  // Skip straight to review
+        .build();
+
+    match post {
+        Ok(post) => println!("Built '{}' {:?} in state {}", post.title(), post.tags(), post.state_name()),
+        Err(e) => println!("Build failed: {}", e),
+    }
+
+    // Forgetting a required field is a recoverable error, not a panic
+    let missing = blog::PostBuilder::new().body("No title here").build();
+    println!("Missing title: {:?}", missing.err());
+
+    let screen = ScreenBuilder::default()
+        .add(Button { width: 60, height: 20, label: String::from("Save") })
+        .add(TextField { width: 180, placeholder: String::from("File name") })
+        .build();
+    println!("Fluent ScreenBuilder made a screen with {} components:", screen.component_count());
+    screen.run();
+    println!("Screen::default() is empty: {}", Screen::default().is_empty());
+
+    println!();
+}
+
+// =============================================================================
+// PART 10: TYPESTATE PATTERN - What the Compiler Now Rejects
+// =============================================================================
+// blog::Post from PART 3 encodes its state in the type, so misuse that a
+// runtime state machine would have to detect (and usually ignore) is a
+// compile error instead:
+//
+//   let draft = blog::PostBuilder::new().title("t").body("b").build().unwrap();
+//   let draft = draft.into_draft().unwrap();  // Post<Draft>
+//   draft.content();
+//   // ERROR: no method named `content` found for struct `Post<Draft>`
+//
+//   let pending = draft.request_review();
+//   draft.add_text("more");
+//   // ERROR: borrow of moved value: `draft` (request_review took it)
+//
+//   pending.add_text("more");
+//   // ERROR: no method named `add_text` found for struct `Post<PendingReview>`
+//
+// The price: the state must be known at COMPILE time. When it isn't - the
+// undo history in PART 6 moves a post back and forth at runtime - wrap the
+// typestates in an enum (blog::AnyPost). Each transition then matches on
+// the current variant, and the "only in Draft" rules are still enforced
+// inside, since each arm can only call what its Post<State> allows.
+
+fn typestate_pattern_example() {
+    println!("--- Part 10: Typestate Pattern ---\n");
+
+    let post = blog::PostBuilder::new().title("Typestate").body("States checked by the compiler").build();
+    let draft = post.ok().and_then(blog::AnyPost::into_draft).expect("a valid post starts as a draft");
+    let published = draft.request_review().approve();
+    println!("Published content: '{}'", published.content());
+
+    // The same rules, checked at runtime through AnyPost
+    let mut any = blog::PostBuilder::new().title("t").body("Hi").build().unwrap();
+    println!("{}: add_text applied? {}", any.state_name(), any.add_text("!"));
+    println!("{}: request_review applied? {}", any.state_name(), any.request_review());
+    println!("{}: add_text applied? {}", any.state_name(), any.add_text("?"));
+    println!("{}: content = {:?}", any.state_name(), any.content());
+    any.approve();
+    println!("{}: content = {:?}", any.state_name(), any.content());
+
+    println!();
+}
+
+// =============================================================================
+// PART 11: VISITOR PATTERN - New Operations Without Touching the Types
+// =============================================================================
+// Draw is one operation baked into every component. To add more (export to
+// HTML, to JSON, ...) without growing the Draw trait each time, each
+// component implements accept() once, and every new operation is a Visitor.
+//
+// DOUBLE DISPATCH: component.accept(v) picks the component's impl (first
+// dispatch), which calls v.visit_button(self) on the visitor (second).
+// Together they select code by BOTH concrete types.
+//
+// Visitors take &self, so they collect output in a RefCell<String>.
+
+trait Visitor {
+    fn visit_button(&self, b: &Button);
+    fn visit_select_box(&self, s: &SelectBox);
+    fn visit_text_field(&self, t: &TextField);
+}
+
+struct HtmlRenderer {
+    output: RefCell<String>,
+}
+
+impl HtmlRenderer {
+    fn new() -> Self {
+        HtmlRenderer { output: RefCell::new(String::new()) }
+    }
+
+    fn finish(self) -> String {
+        self.output.into_inner()
+    }
+}
+
+// Escape text for HTML, so a label like "<b>" shows up as text instead of
+// becoming markup. & goes first, or the &s added for < > " would be escaped
+// a second time.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Visitor for HtmlRenderer {
+    fn visit_button(&self, b: &Button) {
+        self.output.borrow_mut().push_str(&format!(
+            "<button style=\"width:{}px;height:{}px\">{}</button>\n",
+            b.width, b.height, html_escape(&b.label)
+        ));
+    }
+
+    fn visit_select_box(&self, s: &SelectBox) {
+        let mut html = format!("<select style=\"width:{}px;height:{}px\">", s.width, s.height);
+        for option in &s.options {
+            html.push_str(&format!("<option>{}</option>", html_escape(option)));
+        }
+        html.push_str("</select>\n");
+        self.output.borrow_mut().push_str(&html);
+    }
+
+    fn visit_text_field(&self, t: &TextField) {
+        self.output.borrow_mut().push_str(&format!(
+            "<input type=\"text\" style=\"width:{}px\" placeholder=\"{}\">\n",
+            t.width, html_escape(&t.placeholder)
+        ));
+    }
+}
+
+/// Builds a JSON array with one object per component, keyed by its type
+struct JsonExporter {
+    entries: RefCell<Vec<String>>,
+}
+
+impl JsonExporter {
+    fn new() -> Self {
+        JsonExporter { entries: RefCell::new(vec![]) }
+    }
+
+    fn finish(self) -> String {
+        format!("[{}]", self.entries.into_inner().join(","))
+    }
+}
+
+// Quote a string for JSON, escaping the characters that would break it
+fn json_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Visitor for JsonExporter {
+    fn visit_button(&self, b: &Button) {
+        self.entries.borrow_mut().push(format!(
+            "{{\"button\":{{\"width\":{},\"height\":{},\"label\":{}}}}}",
+            b.width, b.height, json_string(&b.label)
+        ));
+    }
+
+    fn visit_select_box(&self, s: &SelectBox) {
+        let options: Vec<String> = s.options.iter().map(|o| json_string(o)).collect();
+        self.entries.borrow_mut().push(format!(
+            "{{\"select_box\":{{\"width\":{},\"height\":{},\"options\":[{}]}}}}",
+            s.width, s.height, options.join(",")
+        ));
+    }
+
+    fn visit_text_field(&self, t: &TextField) {
+        self.entries.borrow_mut().push(format!(
+            "{{\"text_field\":{{\"width\":{},\"placeholder\":{}}}}}",
+            t.width, json_string(&t.placeholder)
+        ));
+    }
+}
+
+fn sample_screen() -> Screen {
+    let mut screen = Screen::new();
+    screen.add(Box::new(Button {
+        width: 100,
+        height: 50,
+        label: String::from("Submit"),
+    }));
+    screen.add(Box::new(SelectBox {
+        width: 150,
+        height: 30,
+        options: vec![String::from("Yes"), String::from("No")],
+    }));
+    screen.add(Box::new(TextField {
+        width: 200,
+        placeholder: String::from("Name"),
+    }));
+    screen
+}
+
+fn visitor_pattern_example() {
+    println!("--- Part 11: Visitor Pattern ---\n");
+
+    let screen = sample_screen();
+
+    let html = HtmlRenderer::new();
+    screen.visit(&html);
+    print!("{}", html.finish());
+
+    let json = JsonExporter::new();
+    screen.visit(&json);
+    println!("{}", json.finish());
+
+    println!();
+}
+
+// =============================================================================
+// PART 12: PLUGIN REGISTRY - Trait Objects Looked Up by Name
+// =============================================================================
+// The "plugin-style architecture" from the summary below: the registry only
+// knows the Plugin trait, and plugins are found by a string key at runtime.
+// New plugins can be added without touching PluginRegistry at all.
+//
+// Plugin: Send + Sync is a SUPERTRAIT bound - every plugin must be safe to
+// share between threads, so a registry could later sit behind an Arc.
+
+use std::collections::HashMap;
+
+trait Plugin: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn execute(&self, input: &str) -> String;
+}
+
+struct UpperCasePlugin;
+impl Plugin for UpperCasePlugin {
+    fn name(&self) -> &'static str {
+        "uppercase"
+    }
+    fn execute(&self, input: &str) -> String {
+        input.to_uppercase()
+    }
+}
+
+struct ReversePlugin;
+impl Plugin for ReversePlugin {
+    fn name(&self) -> &'static str {
+        "reverse"
+    }
+    fn execute(&self, input: &str) -> String {
+        input.chars().rev().collect()
+    }
+}
+
+struct PluginRegistry {
+    plugins: HashMap<String, Box<dyn Plugin>>,
+}
+
+impl PluginRegistry {
+    fn new() -> Self {
+        PluginRegistry { plugins: HashMap::new() }
+    }
+
+    // Generic over P so callers pass a plain value; boxing happens here.
+    // 'static: the Box<dyn Plugin> must not borrow anything short-lived.
+    // Registering a second plugin with the same name replaces the first.
+    fn register<P: Plugin + 'static>(&mut self, p: P) {
+        self.plugins.insert(p.name().to_string(), Box::new(p));
+    }
+
+    // Dynamic dispatch: the HashMap lookup picks the plugin, the vtable
+    // picks its execute()
+    fn execute(&self, name: &str, input: &str) -> Option<String> {
+        self.plugins.get(name).map(|plugin| plugin.execute(input))
+    }
+
+    // HashMap order is unspecified, so sort for stable output
+    fn list_plugins(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.plugins.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+}
+
+fn plugin_registry_example() {
+    println!("--- Part 12: Plugin Registry ---\n");
+
+    let mut registry = PluginRegistry::new();
+    registry.register(UpperCasePlugin);
+    registry.register(ReversePlugin);
+    println!("Registered: {:?}", registry.list_plugins());
+
+    for name in ["uppercase", "reverse", "rot13"] {
+        match registry.execute(name, "hello plugins") {
+            Some(output) => println!("{name}: {output}"),
+            None => println!("{name}: no such plugin"),
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 13: CHAIN OF RESPONSIBILITY - Pass a Request Along a Pipeline
+// =============================================================================
+// Each handler looks at the request and either passes it on (true) or stops
+// the chain (false). The sender doesn't know which handler will reject it,
+// and stages can be added, removed or reordered without changing the others.
+//
+// This is the same idea as middleware in web frameworks.
+
+struct Request {
+    path: String,
+    authenticated: bool,
+    role: String,
+}
+
+trait Handler {
+    fn name(&self) -> &'static str;
+    // &mut Request: a stage may also enrich the request for later stages
+    fn handle(&self, req: &mut Request) -> bool;
+}
+
+struct LoggingHandler;
+impl Handler for LoggingHandler {
+    fn name(&self) -> &'static str {
+        "LoggingHandler"
+    }
+    fn handle(&self, req: &mut Request) -> bool {
+        println!("  [log] {} (authenticated: {}, role: {})", req.path, req.authenticated, req.role);
+        true  // Never stops the chain
+    }
+}
+
+struct AuthHandler;
+impl Handler for AuthHandler {
+    fn name(&self) -> &'static str {
+        "AuthHandler"
+    }
+    fn handle(&self, req: &mut Request) -> bool {
+        req.authenticated
+    }
+}
+
+struct AuthorizationHandler;
+impl Handler for AuthorizationHandler {
+    fn name(&self) -> &'static str {
+        "AuthorizationHandler"
+    }
+    fn handle(&self, req: &mut Request) -> bool {
+        req.role == "admin"
+    }
+}
+
+// Runs each handler in order; Err carries the name of the one that stopped
+fn run_chain(chain: &[Box<dyn Handler>], req: &mut Request) -> Result<(), &'static str> {
+    for handler in chain {
+        if !handler.handle(req) {
+            return Err(handler.name());
+        }
+    }
+    Ok(())
+}
+
+fn default_chain() -> Vec<Box<dyn Handler>> {
+    vec![
+        Box::new(LoggingHandler),  // First, so every request gets logged
+        Box::new(AuthHandler),
+        Box::new(AuthorizationHandler),
+    ]
+}
+
+fn chain_of_responsibility_example() {
+    println!("--- Part 13: Chain of Responsibility ---\n");
+
+    let chain = default_chain();
+    let requests = [
+        ("/admin/users", false, "guest"),
+        ("/admin/users", true, "editor"),
+        ("/admin/users", true, "admin"),
+    ];
+
+    for (path, authenticated, role) in requests {
+        let mut req = Request {
+            path: path.to_string(),
+            authenticated,
+            role: role.to_string(),
+        };
+        match run_chain(&chain, &mut req) {
+            Ok(()) => println!("  -> allowed"),
+            Err(stage) => println!("  -> rejected by {stage}"),
+        }
+    }
+
+    println!();
+}
+
+// =============================================================================
+// PART 14: STATE MACHINE MACRO - Generating the Transition Table
+// =============================================================================
+// PART 3 spread the Post workflow over three marker types and their impls.
+// When a state machine is just "in state X, event E leads to Y", a
+// macro can generate it from the transition table alone:
+//
+//   Draft => PendingReview via RequestReview
+//
+// The ideal call would be just the table: `state_machine!(Post { Draft =>
+// PendingReview via RequestReview, ... })`, generating PostState and
+// PostEvent. Plain macro_rules! can't get there, for two reasons:
+// - it can't build new identifiers (Post + State -> PostState), so both
+//   enum names are passed in (the `paste` crate can lift this limit)
+// - it can't remove duplicates, and a state usually appears in several
+//   transitions, so states and events are listed once up front
+//
+// What it generates is unchanged by this: the two enums plus a free
+// `fn transition(state, event) -> Option<State>`. Being a free fn, it
+// allows one state machine per module.
+
+macro_rules! state_machine {
+    (
+        $state:ident, $event:ident {
+            states: [$($s:ident),* $(,)?],
+            events: [$($e:ident),* $(,)?],
+            transitions: [$($from:ident => $to:ident via $via:ident),* $(,)?] $(,)?
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum $state {
+            $($s),*
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum $event {
+            $($e),*
+        }
+
+        impl $state {
+            const ALL: &'static [$state] = &[$($state::$s),*];
+        }
+
+        // None = the event is not allowed in this state
+        fn transition(state: $state, event: $event) -> Option<$state> {
+            match (state, event) {
+                $(($state::$from, $event::$via) => Some($state::$to),)*
+                #[allow(unreachable_patterns)]  // If every pair is listed
+                _ => None,
+            }
+        }
+
+        impl $event {
+            const ALL: &'static [$event] = &[$($event::$e),*];
+        }
+    };
+}
+
+// The same workflow as blog::Post in PART 3
+state_machine! {
+    PostState, PostEvent {
+        states: [Draft, PendingReview, Published],
+        events: [RequestReview, Approve, Reject],
+        transitions: [
+            Draft => PendingReview via RequestReview,
+            PendingReview => Published via Approve,
+            PendingReview => Draft via Reject,
+        ],
+    }
+}
+
+fn state_machine_example() {
+    println!("--- Part 14: State Machine Macro ---\n");
+
+    let mut state = PostState::Draft;
+    let events = [
+        PostEvent::Approve,        // Not allowed in Draft - ignored
+        PostEvent::RequestReview,
+        PostEvent::Reject,
+        PostEvent::RequestReview,
+        PostEvent::Approve,
+    ];
+    for event in events {
+        match transition(state, event) {
+            Some(next) => {
+                println!("{:?} --{:?}--> {:?}", state, event, next);
+                state = next;
+            }
+            None => println!("{:?} ignores {:?}", state, event),
+        }
+    }
+
+    println!(
+        "{} states x {} events = {} pairs",
+        PostState::ALL.len(),
+        PostEvent::ALL.len(),
+        PostState::ALL.len() * PostEvent::ALL.len()
+    );
+
+    println!();
+}
+
+// =============================================================================
+// PART 15: PROXY PATTERN - Controlling Access to a Component
+// =============================================================================
+// A proxy has the same interface as the real object, like a decorator
+// (PART 5). The difference is intent: a decorator ADDS behavior, while a
+// proxy decides WHETHER and WHEN the real object gets involved at all.
+//
+// - AccessControlProxy: only forwards if the caller is allowed
+// - DeferredProxy: doesn't build the real object until it's first needed
+//   (useful when construction is expensive and the object may never be used)
+
+use std::cell::OnceCell;
+
+struct AccessControlProxy<T: Draw> {
+    inner: T,
+    allowed: bool,
+}
+
+impl<T: Draw> Draw for AccessControlProxy<T> {
+    fn draw(&self) {
+        if self.allowed {
+            self.inner.draw();
+        } else {
+            println!("Access denied");
+        }
+    }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        if self.allowed {
+            self.inner.accept(visitor);
+        }
+    }
+}
+
+/// draw() only gets &self, so "fill in the component later" needs interior
+/// mutability. OnceCell<T> is exactly an Option<T> that can be set once
+/// through a shared reference.
+struct DeferredProxy<T: Draw> {
+    inner: OnceCell<T>,
+    factory: Box<dyn Fn() -> T>,
+}
+
+impl<T: Draw> DeferredProxy<T> {
+    fn new(factory: impl Fn() -> T + 'static) -> Self {
+        DeferredProxy { inner: OnceCell::new(), factory: Box::new(factory) }
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.inner.get().is_some()
+    }
+
+    // Runs the factory on the first call only
+    fn component(&self) -> &T {
+        self.inner.get_or_init(|| (self.factory)())
+    }
+}
+
+impl<T: Draw> Draw for DeferredProxy<T> {
+    fn draw(&self) {
+        self.component().draw();
+    }
+
+    fn accept(&self, visitor: &dyn Visitor) {
+        self.component().accept(visitor);
+    }
+}
+
+fn proxy_pattern_example() {
+    println!("--- Part 15: Proxy Pattern ---\n");
+
+    for allowed in [false, true] {
+        let admin_button = AccessControlProxy {
+            inner: Button { width: 90, height: 25, label: String::from("Delete all") },
+            allowed,
+        };
+        print!("allowed={}: ", allowed);
+        admin_button.draw();
+    }
+
+    let report = DeferredProxy::new(|| {
+        println!("(building the expensive SelectBox now)");
+        SelectBox {
+            width: 300,
+            height: 40,
+            options: (1..=1000).map(|i| format!("Row {}", i)).collect(),
+        }
+    });
+    println!("Loaded before first draw? {}", report.is_loaded());
+    report.draw();
+    report.draw();  // Already built - no second "building" line
+    println!("Loaded after drawing? {}", report.is_loaded());
+
+    println!();
+}
+
+// =============================================================================
+// KEY CONCEPTS SUMMARY
+// =============================================================================
+//
+// | OOP Concept    | Rust Approach                                |
+// |----------------|----------------------------------------------|
+// | Encapsulation  | pub/private, impl blocks                     |
+// | Inheritance    | NOT supported - use composition + traits     |
+// | Polymorphism   | Trait objects (dyn Trait) or generics        |
+// | Interfaces     | Traits                                       |
+// | Abstract class | Trait with default implementations           |
+//
+// TRAIT OBJECTS VS GENERICS:
+// - Generics: Static dispatch, faster, monomorphized at compile time
+// - Trait objects: Dynamic dispatch, more flexible, runtime overhead
+//
+// WHEN TO USE EACH:
+// - Generics: When you know types at compile time
+// - Trait objects: When you need a collection of different types
+//                  or plugin-style architecture
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts how many times draw() actually reaches the component
+    struct CountingDraw {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl Draw for CountingDraw {
+        fn draw(&self) {
+            self.calls.set(self.calls.get() + 1);
+        }
+
+        // Not a real widget, so there's no visit_* method to call
+        fn accept(&self, _visitor: &dyn Visitor) {}
+    }
+
+    #[test]
+    fn test_cached_draw_draws_once() {
+        let calls = Rc::new(Cell::new(0));
+        let cached = CachedDraw::new(CountingDraw { calls: Rc::clone(&calls) });
+
+        cached.draw();
+        cached.draw();
+        cached.draw();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_decorators_compose_over_trait_objects() {
+        let calls = Rc::new(Cell::new(0));
+        let boxed: Box<dyn Draw> = Box::new(CountingDraw { calls: Rc::clone(&calls) });
+        let decorated = LoggingDraw {
+            inner: CachedDraw::new(boxed),
+            label: String::from("counter"),
+        };
+
+        decorated.draw();
+        decorated.draw();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_command_undo_redo() {
+        let post = blog::PostBuilder::new().title("t").body("Hello").build().unwrap();
+        let post = Rc::new(RefCell::new(post));
+        let mut history = CommandHistory::new();
+
+        history.execute(Box::new(AddTextCommand::new(&post, ", world")));
+        history.execute(Box::new(RequestReviewCommand::new(&post)));
+        assert_eq!(post.borrow().state_name(), "PendingReview");
+        assert_eq!(post.borrow().text_len(), 12);
+
+        assert!(history.undo());  // Undo request_review
+        assert_eq!(post.borrow().state_name(), "Draft");
+        assert_eq!(post.borrow().text_len(), 12);
+
+        assert!(history.undo());  // Undo add_text
+        assert_eq!(post.borrow().state_name(), "Draft");
+        assert_eq!(post.borrow().text_len(), 5);
+        assert!(!history.undo()); // Nothing left
+
+        assert!(history.redo());  // Redo add_text
+        assert_eq!(post.borrow().state_name(), "Draft");
+        assert_eq!(post.borrow().text_len(), 12);
+
+        // Approving shows the restored text really is "Hello, world"
+        assert!(post.borrow_mut().request_review());
+        assert!(post.borrow_mut().approve());
+        assert_eq!(post.borrow().content(), Some("Hello, world"));
+    }
+
+    fn sample_fs() -> filesystem::Directory {
+        let mut sub = filesystem::Directory::new("docs");
+        sub.add(Box::new(filesystem::File::new("guide.md", 40)));
+
+        let mut root = filesystem::Directory::new("root");
+        root.add(Box::new(filesystem::File::new("a.txt", 10)));
+        root.add(Box::new(filesystem::File::new("b.txt", 20)));
+        root.add(Box::new(sub));
+        root
+    }
+
+    #[test]
+    fn test_composite_size_sums_all_files() {
+        assert_eq!(sample_fs().size(), 10 + 20 + 40);
+    }
+
+    #[test]
+    fn test_composite_tree_indentation() {
+        let mut out = String::new();
+        sample_fs().render(0, &mut out);
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines, vec![
+            "root/",
+            "  a.txt (10 bytes)",
+            "  b.txt (20 bytes)",
+            "  docs/",
+            "    guide.md (40 bytes)",
+        ]);
+    }
+
+    #[test]
+    fn test_observer_counts_until_unsubscribed() {
+        let counter = Rc::new(CountingObserver::new());
+        let mut bus = EventBus::new();
+        bus.subscribe(Box::new(LogObserver));
+        bus.subscribe(Box::new(Rc::clone(&counter)));
+
+        bus.publish("one");
+        bus.publish("two");
+        bus.publish("three");
+        assert_eq!(*counter.count.borrow(), 3);
+
+        bus.unsubscribe_all();
+        bus.publish("four");
+        assert_eq!(*counter.count.borrow(), 3);
+    }
+
+    #[test]
+    fn test_builder_complete_post() {
+        let post = blog::PostBuilder::new()
+            .title("Title")
+            .body("Body")
+            .add_tag("rust")
+            .build()
+            .unwrap();
+        assert_eq!(post.title(), "Title");
+        assert_eq!(post.tags(), ["rust".to_string()]);
+        assert_eq!(post.state_name(), "Draft");
+    }
+
+    #[test]
+    fn test_builder_non_draft_goes_to_review() {
+        let post = blog::PostBuilder::new().title("t").body("b").draft(false).build().unwrap();
+        assert_eq!(post.state_name(), "PendingReview");
+    }
+
+    #[test]
+    fn test_builder_into_draft_only_for_drafts() {
+        let draft = blog::PostBuilder::new().title("t").body("b").build().unwrap().into_draft();
+        assert_eq!(draft.map(|p| p.request_review().title().to_string()), Some("t".to_string()));
+
+        let pending = blog::PostBuilder::new().title("t").body("b").draft(false).build().unwrap();
+        assert!(pending.into_draft().is_none());
+    }
+
+    #[test]
+    fn test_post_draft_to_review_to_published() {
+        let post = blog::PostBuilder::new().title("t").body("Hello").add_tag("x").build().unwrap();
+        let mut draft = post.into_draft().unwrap();
+        draft.add_text(", world");
+        assert_eq!(draft.state_name(), "Draft");
+
+        let pending = draft.request_review();
+        assert_eq!(pending.state_name(), "PendingReview");
+        assert_eq!(pending.text_len(), 12);
+
+        let published = pending.approve();
+        assert_eq!(published.state_name(), "Published");
+        assert_eq!(published.content(), "Hello, world");
+        assert_eq!((published.title(), published.tags()), ("t", &["x".to_string()][..]));
+    }
+
+    #[test]
+    fn test_post_reject_returns_to_editable_draft() {
+        let draft = blog::PostBuilder::new().title("t").body("v1").build().unwrap().into_draft().unwrap();
+        let mut draft = draft.request_review().reject();
+        assert_eq!(draft.state_name(), "Draft");
+        draft.add_text(" v2");  // Compiles again: it's a Post<Draft>
+        assert_eq!(draft.request_review().approve().content(), "v1 v2");
+    }
+
+    #[test]
+    fn test_any_post_only_allows_valid_transitions() {
+        let mut post = blog::PostBuilder::new().title("t").body("ab").build().unwrap();
+        assert!(!post.reject());  // Nothing to reject in Draft
+        assert!(post.add_text("c"));
+        assert!(post.request_review());
+        assert_eq!(post.state_name(), "PendingReview");
+
+        assert!(!post.request_review());  // Already pending
+        assert!(!post.add_text("d"));     // Not editable outside Draft
+        assert_eq!(post.text_len(), 3);
+
+        assert!(post.reject());
+        assert_eq!(post.state_name(), "Draft");
+        assert_eq!(post.text_len(), 3);  // Contents survived both moves
+        assert_eq!(post.content(), None);
+
+        assert!(!post.approve());  // Must be reviewed first
+        assert!(post.request_review() && post.approve());
+        assert_eq!(post.content(), Some("abc"));
+    }
+
+    #[test]
+    fn test_builder_missing_fields() {
+        let no_title = blog::PostBuilder::new().body("Body").build().err();
+        let no_body = blog::PostBuilder::new().title("Title").build().err();
+        assert_eq!(no_title, Some("title required".to_string()));
+        assert_eq!(no_body, Some("body required".to_string()));
+        assert_ne!(no_title, no_body);
+    }
+
+    #[test]
+    fn test_screen_builder_chains_components() {
+        let calls = Rc::new(Cell::new(0));
+        let screen = ScreenBuilder::default()
+            .add(Button { width: 10, height: 10, label: String::from("OK") })
+            .add(SelectBox { width: 20, height: 10, options: vec![String::from("A")] })
+            .add(CountingDraw { calls: Rc::clone(&calls) })
+            .build();
+
+        screen.run();
+        assert_eq!(screen.component_count(), 3);
+        assert!(!screen.is_empty());
+        assert_eq!(calls.get(), 1);  // run() reached the last component
+    }
+
+    #[test]
+    fn test_default_screen_is_empty() {
+        let screen = ScreenBuilder::default().build();
+        assert!(screen.is_empty());
+        assert_eq!(Screen::default().component_count(), 0);
+    }
+
+    #[test]
+    fn test_visitor_html() {
+        let html = HtmlRenderer::new();
+        sample_screen().visit(&html);
+        let output = html.finish();
+
+        assert!(output.contains("<button"));
+        assert!(output.contains("<select"));
+        assert!(output.contains("<input"));
+    }
+
+    #[test]
+    fn test_visitor_html_escapes_text() {
+        let mut screen = Screen::new();
+        screen.add(Box::new(Button { width: 1, height: 1, label: String::from("<b>Save & Exit</b>") }));
+        screen.add(Box::new(SelectBox { width: 1, height: 1, options: vec![String::from("a<b")] }));
+        screen.add(Box::new(TextField { width: 1, placeholder: String::from("say \"hi\"") }));
+
+        let html = HtmlRenderer::new();
+        screen.visit(&html);
+        let output = html.finish();
+
+        assert!(output.contains(">&lt;b&gt;Save &amp; Exit&lt;/b&gt;</button>"));
+        assert!(output.contains("<option>a&lt;b</option>"));
+        assert!(output.contains("placeholder=\"say &quot;hi&quot;\""));
+        assert!(!output.contains("<b>"));
+    }
+
+    #[test]
+    fn test_visitor_json() {
+        let json = JsonExporter::new();
+        sample_screen().visit(&json);
+        let output = json.finish();
+
+        assert!(output.contains(r#"{"button":{"#));
+        assert!(output.contains(r#"{"select_box":{"#));
+        assert!(output.contains(r#"{"text_field":{"#));
+        assert!(output.contains(r#""options":["Yes","No"]"#));
+    }
+
+    #[test]
+    fn test_visitor_sees_through_decorators() {
+        let mut screen = Screen::new();
+        screen.add(Box::new(CachedDraw::new(Button {
+            width: 1,
+            height: 1,
+            label: String::from("wrapped"),
+        })));
+
+        let html = HtmlRenderer::new();
+        screen.visit(&html);
+        assert!(html.finish().contains(">wrapped</button>"));
+    }
+
+    #[test]
+    fn test_comparator_sort_by_length() {
+        let mut words = vec![
+            String::from("banana"),
+            String::from("apple"),
+            String::from("cherry"),
+        ];
+        // sort_by is stable: equal-length "banana" and "cherry" keep their order
+        ComparatorSort::new(|a: &String, b: &String| a.len().cmp(&b.len())).sort(&mut words);
+        assert_eq!(words, vec!["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn test_comparator_sort_descending() {
+        let mut numbers = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let sorter = Sorter::new(Box::new(ComparatorSort::new(|a: &i32, b: &i32| b.cmp(a))));
+        sorter.sort(&mut numbers);
+        assert_eq!(numbers, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_plugin_registry_executes_by_name() {
+        let mut registry = PluginRegistry::new();
+        registry.register(UpperCasePlugin);
+        registry.register(ReversePlugin);
+
+        assert_eq!(registry.execute("uppercase", "abc"), Some("ABC".to_string()));
+        assert_eq!(registry.execute("reverse", "abc"), Some("cba".to_string()));
+        assert_eq!(registry.execute("missing", "abc"), None);
+    }
+
+    #[test]
+    fn test_plugin_registry_lists_names() {
+        let mut registry = PluginRegistry::new();
+        assert!(registry.list_plugins().is_empty());
+
+        registry.register(UpperCasePlugin);
+        registry.register(ReversePlugin);
+        assert_eq!(registry.list_plugins(), vec!["reverse", "uppercase"]);
+    }
+
+    fn request(authenticated: bool, role: &str) -> Request {
+        Request {
+            path: "/admin".to_string(),
+            authenticated,
+            role: role.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_chain_rejects_unauthenticated_at_auth() {
+        let mut req = request(false, "admin");
+        assert_eq!(run_chain(&default_chain(), &mut req), Err("AuthHandler"));
+    }
+
+    #[test]
+    fn test_chain_rejects_non_admin_at_authorization() {
+        let mut req = request(true, "editor");
+        assert_eq!(run_chain(&default_chain(), &mut req), Err("AuthorizationHandler"));
+    }
+
+    #[test]
+    fn test_chain_admin_passes_all_handlers() {
+        let mut req = request(true, "admin");
+        assert_eq!(run_chain(&default_chain(), &mut req), Ok(()));
+    }
+
+    #[test]
+    fn test_state_machine_valid_transitions() {
+        assert_eq!(transition(PostState::Draft, PostEvent::RequestReview), Some(PostState::PendingReview));
+        assert_eq!(transition(PostState::PendingReview, PostEvent::Approve), Some(PostState::Published));
+        assert_eq!(transition(PostState::PendingReview, PostEvent::Reject), Some(PostState::Draft));
+    }
+
+    #[test]
+    fn test_state_machine_invalid_transitions() {
+        let valid = [
+            (PostState::Draft, PostEvent::RequestReview),
+            (PostState::PendingReview, PostEvent::Approve),
+            (PostState::PendingReview, PostEvent::Reject),
+        ];
+        // Every other (state, event) pair must be rejected - 6 of the 9
+        let mut rejected = 0;
+        for &state in PostState::ALL {
+            for &event in PostEvent::ALL {
+                if !valid.contains(&(state, event)) {
+                    assert_eq!(transition(state, event), None, "{:?} + {:?}", state, event);
+                    rejected += 1;
+                }
+            }
+        }
+        assert_eq!(rejected, 6);
+    }
+
+    #[test]
+    fn test_access_control_proxy_blocks_when_not_allowed() {
+        let calls = Rc::new(Cell::new(0));
+        let denied = AccessControlProxy { inner: CountingDraw { calls: Rc::clone(&calls) }, allowed: false };
+        denied.draw();
+        denied.draw();
+        assert_eq!(calls.get(), 0);
+
+        let granted = AccessControlProxy { inner: CountingDraw { calls: Rc::clone(&calls) }, allowed: true };
+        granted.draw();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_deferred_proxy_builds_once() {
+        let builds = Rc::new(Cell::new(0));
+        let draws = Rc::new(Cell::new(0));
+        let proxy = {
+            let (builds, draws) = (Rc::clone(&builds), Rc::clone(&draws));
+            DeferredProxy::new(move || {
+                builds.set(builds.get() + 1);
+                CountingDraw { calls: Rc::clone(&draws) }
+            })
+        };
+        assert_eq!(builds.get(), 0);  // Nothing built up front
+        assert!(!proxy.is_loaded());
+
+        proxy.draw();
+        proxy.draw();
+        proxy.draw();
+        assert_eq!(builds.get(), 1);
+        assert_eq!(draws.get(), 3);  // Every draw still reaches the component
+        assert!(proxy.is_loaded());
+    }
+}
@@ -0,0 +1,1158 @@
+// =============================================================================
+// CHAPTER 20: ADVANCED FEATURES - Unsafe Rust, Advanced Traits, Macros
+// =============================================================================
+// Safe Rust is a subset of the language where the compiler proves memory
+// safety. `unsafe` unlocks five extra abilities:
+// 1. Dereference raw pointers (*const T, *mut T)
+// 2. Call unsafe functions (including FFI)
+// 3. Access or modify mutable statics
+// 4. Implement unsafe traits (Send, Sync, ...)
+// 5. Access fields of unions
+//
+// unsafe does NOT turn off the borrow checker - it means "I, the programmer,
+// promise these invariants hold". The goal is to wrap unsafe code in a SAFE
+// API so callers can never break those invariants.
+// =============================================================================
+
+use std::alloc::{self, Layout};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::fmt;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Add, Div, Index, Mul, Sub};
+use std::ptr;
+use std::sync::OnceLock;
+use std::thread;
+
+fn main() {
+    // =========================================================================
+    // PART 1: RAW POINTERS - A Hand-Rolled Vec<T>
+    // =========================================================================
+    raw_vec_example();
+
+    // =========================================================================
+    // PART 2: FFI - Calling C Functions Safely
+    // =========================================================================
+    ffi_example();
+
+    // =========================================================================
+    // PART 3: DECLARATIVE MACROS - retry!
+    // =========================================================================
+    retry_example();
+
+    // =========================================================================
+    // PART 4: MACROS THAT GENERATE TYPES - bit_flags!
+    // =========================================================================
+    bit_flags_example();
+
+    // =========================================================================
+    // PART 5: A TINY STACK-BASED VIRTUAL MACHINE
+    // =========================================================================
+    vm_example();
+
+    // =========================================================================
+    // PART 6: NEWTYPES - Units the Compiler Can Check
+    // =========================================================================
+    newtype_units_example();
+
+    // =========================================================================
+    // PART 7: LAZY STATICS - once_static! with OnceLock
+    // =========================================================================
+    once_static_example();
+
+    // =========================================================================
+    // PART 8: CONST GENERICS + MaybeUninit - SmallVec<T, N>
+    // =========================================================================
+    small_vec_example();
+}
+
+// =============================================================================
+// PART 1: RAW POINTERS - A Hand-Rolled Vec<T>
+// =============================================================================
+// RawVec<T> manages its own heap buffer with std::alloc, just like Vec<T>
+// does under the hood:
+//
+//   ptr ──> [ T | T | T | uninit | uninit ]
+//            <---- len ---->
+//            <------------- cap ------------->
+//
+// Invariants every method relies on:
+// - if cap > 0, ptr points to an allocation of Layout::array::<T>(cap)
+// - slots 0..len are initialized, slots len..cap are NOT
+//
+// Zero-sized types (like ()) need special handling that we skip here.
+
+struct RawVec<T> {
+    ptr: *mut T,
+    len: usize,
+    cap: usize,
+}
+
+impl<T> RawVec<T> {
+    fn new() -> Self {
+        assert!(size_of::<T>() != 0, "zero-sized types are not supported");
+        // No allocation until the first push - same as Vec::new()
+        RawVec { ptr: ptr::null_mut(), len: 0, cap: 0 }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    // Double the capacity (0 -> 1 -> 2 -> 4 -> 8 ...), so n pushes cost
+    // O(n) copying in total - "amortized O(1)" per push
+    fn grow(&mut self) {
+        let new_cap = if self.cap == 0 { 1 } else { self.cap * 2 };
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+
+        let new_ptr = if self.cap == 0 {
+            // SAFETY: new_layout has non-zero size because T is not a ZST
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.cap).unwrap();
+            // SAFETY: ptr was allocated with old_layout (invariant), and the
+            // new size is non-zero. realloc moves the initialized bytes for us.
+            unsafe { alloc::realloc(self.ptr as *mut u8, old_layout, new_layout.size()) }
+        };
+
+        // A null return means out of memory - never use it as a buffer
+        if new_ptr.is_null() {
+            alloc::handle_alloc_error(new_layout);
+        }
+        self.ptr = new_ptr as *mut T;
+        self.cap = new_cap;
+    }
+
+    fn push(&mut self, item: T) {
+        if self.len == self.cap {
+            self.grow();
+        }
+        // SAFETY: len < cap, so the slot is inside the allocation. It is
+        // uninitialized, so we must use ptr::write - a plain assignment would
+        // try to DROP the garbage "old value" first.
+        unsafe { ptr::write(self.ptr.add(self.len), item) };
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: slot len was initialized. ptr::read moves the value out;
+        // since len no longer covers it, it will never be read or dropped again.
+        Some(unsafe { ptr::read(self.ptr.add(self.len)) })
+    }
+}
+
+impl<T> Index<usize> for RawVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        // The bounds check is what makes this safe to expose
+        assert!(index < self.len, "index {index} out of bounds (len {})", self.len);
+        // SAFETY: index < len, so the slot is initialized
+        unsafe { &*self.ptr.add(index) }
+    }
+}
+
+impl<T> Drop for RawVec<T> {
+    fn drop(&mut self) {
+        // First drop the elements we still own (pop returns them, then they
+        // go out of scope), then give the memory back to the allocator
+        while self.pop().is_some() {}
+
+        if self.cap > 0 {
+            let layout = Layout::array::<T>(self.cap).unwrap();
+            // SAFETY: ptr was allocated with exactly this layout
+            unsafe { alloc::dealloc(self.ptr as *mut u8, layout) };
+        }
+    }
+}
+
+// Raw pointers are neither Send nor Sync, so RawVec<T> isn't Send by default.
+// SAFETY: RawVec uniquely owns its buffer and the T values in it (no other
+// pointer to them exists), so moving it to another thread is just moving the
+// T values - which is sound whenever T: Send. This is what Vec<T> does too.
+unsafe impl<T: Send> Send for RawVec<T> {}
+
+fn raw_vec_example() {
+    println!("--- Part 1: RawVec<T> ---\n");
+
+    let mut v = RawVec::new();
+    for word in ["unsafe", "raw", "pointers"] {
+        v.push(word.to_string());
+        println!("push {word:?}: len = {}, cap = {}", v.len(), v.capacity());
+    }
+    println!("v[1] = {}", v[1]);
+
+    // Send lets the whole buffer move to another thread
+    let handle = std::thread::spawn(move || v.pop());
+    println!("popped on another thread: {:?}", handle.join().unwrap());
+
+    println!();
+}
+
+// =============================================================================
+// PART 2: FFI - Calling C Functions Safely
+// =============================================================================
+// `extern "C"` declares functions that live in another language - here the C
+// standard library, which Rust already links on every mainstream platform.
+// The compiler can't check what C does, so every call is unsafe. (Since
+// edition 2024 the block itself must also be marked `unsafe extern`.)
+//
+// Type mapping: size_t -> usize, char* -> *const c_char, int -> c_int.
+// Rust &str is NOT null-terminated; C strings are. CString (owned) and
+// CStr (borrowed) bridge the two.
+
+unsafe extern "C" {
+    fn strlen(s: *const c_char) -> usize;
+    fn strncpy(dest: *mut c_char, src: *const c_char, n: usize) -> *mut c_char;
+    fn memcmp(a: *const c_void, b: *const c_void, n: usize) -> c_int;
+}
+
+// Each wrapper is a SAFE function: its signature makes misuse impossible,
+// so callers never need to write `unsafe` themselves.
+
+fn c_strlen(s: &CStr) -> usize {
+    // SAFETY: a &CStr always points to a valid null-terminated string
+    unsafe { strlen(s.as_ptr()) }
+}
+
+// Copies src into dest, truncating if needed; dest is always null-terminated
+// (raw strncpy does NOT guarantee that - a classic C bug we hide here)
+fn c_strncpy(dest: &mut [u8], src: &CStr) {
+    assert!(!dest.is_empty(), "destination must hold at least the terminator");
+    let n = dest.len() - 1;
+    // SAFETY: strncpy writes at most n bytes, and n < dest.len();
+    // src is null-terminated because it is a CStr
+    unsafe { strncpy(dest.as_mut_ptr() as *mut c_char, src.as_ptr(), n) };
+    dest[n] = 0;
+}
+
+fn safe_memcmp(a: &[u8], b: &[u8]) -> Ordering {
+    // memcmp would read past the end of the shorter slice otherwise
+    assert_eq!(a.len(), b.len(), "safe_memcmp requires equal-length slices");
+    // SAFETY: both pointers are valid for a.len() bytes
+    let result = unsafe { memcmp(a.as_ptr() as *const c_void, b.as_ptr() as *const c_void, a.len()) };
+    // C only promises "negative, zero or positive" - not -1/0/1
+    result.cmp(&0)
+}
+
+// &str -> C string -> back to String, measuring the length on the C side.
+// None if s contains an interior '\0', which C would treat as the end.
+fn c_string_roundtrip(s: &str) -> Option<String> {
+    let c_string = CString::new(s).ok()?;
+    let ptr = c_string.as_ptr();
+    // SAFETY: ptr comes from a live CString, so it is null-terminated and
+    // valid for strlen(ptr) bytes for as long as c_string is alive
+    let bytes = unsafe {
+        let len = strlen(ptr);
+        std::slice::from_raw_parts(ptr as *const u8, len)
+    };
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn ffi_example() {
+    println!("--- Part 2: FFI ---\n");
+
+    let greeting = c"hello, ffi";  // C string literal: a &'static CStr
+    println!("strlen({greeting:?}) = {}", c_strlen(greeting));
+
+    let mut buf = [0u8; 6];
+    c_strncpy(&mut buf, greeting);
+    println!("strncpy into 6 bytes: {:?}", CStr::from_bytes_until_nul(&buf).unwrap());
+
+    println!("memcmp(abc, abd) = {:?}", safe_memcmp(b"abc", b"abd"));
+    println!("roundtrip: {:?}", c_string_roundtrip("héllo"));
+    println!("roundtrip with nul: {:?}", c_string_roundtrip("a\0b"));
+
+    println!();
+}
+
+// =============================================================================
+// PART 3: DECLARATIVE MACROS - retry!
+// =============================================================================
+// macro_rules! matches on the SYNTAX it is given and expands to new code.
+// Fragment specifiers say what each piece must be:
+//   $n:expr     - any expression (3, MAX_TRIES, n + 1 ...)
+//   $body:block - a { ... } block
+//
+// Why a macro and not a function taking a closure? The body is pasted in
+// place: it can borrow locals freely, and `return` leaves the CALLER.
+//
+// Each attempt re-evaluates $body; the macro yields the first Ok, or the Err
+// from the final attempt. A macro must be defined before it is used.
+
+macro_rules! retry_delay {
+    ($n:expr, $ms:expr, $body:block) => {{
+        let max_attempts: u32 = $n;
+        let delay = std::time::Duration::from_millis($ms);
+        assert!(max_attempts > 0, "retry needs at least one attempt");
+        let mut attempt = 1;
+        loop {
+            match $body {
+                Ok(value) => break Ok(value),
+                Err(e) if attempt == max_attempts => break Err(e),
+                Err(_) => {
+                    attempt += 1;
+                    if !delay.is_zero() {
+                        std::thread::sleep(delay);
+                    }
+                }
+            }
+        }
+    }};
+}
+
+// The no-delay form is just a special case - macros can call macros
+macro_rules! retry {
+    ($n:expr, $body:block) => {
+        retry_delay!($n, 0, $body)
+    };
+}
+
+// Fails until it has been called `succeed_on` times.
+// &RefCell lets it count calls without needing &mut.
+fn flaky_operation(calls: &RefCell<u32>, succeed_on: u32) -> Result<String, String> {
+    *calls.borrow_mut() += 1;
+    let call = *calls.borrow();
+    if call >= succeed_on {
+        Ok(format!("succeeded on attempt {call}"))
+    } else {
+        Err(format!("attempt {call} failed"))
+    }
+}
+
+fn retry_example() {
+    println!("--- Part 3: retry! Macro ---\n");
+
+    let calls = RefCell::new(0);
+    let result = retry!(5, { flaky_operation(&calls, 3) });
+    println!("retry!(5): {:?}", result);
+
+    let calls = RefCell::new(0);
+    let result = retry_delay!(2, 10, { flaky_operation(&calls, 3) });
+    println!("retry_delay!(2, 10ms): {:?}", result);
+
+    println!();
+}
+
+// =============================================================================
+// PART 4: MACROS THAT GENERATE TYPES - bit_flags!
+// =============================================================================
+// A macro can expand to whole items: a struct, its constants and trait impls.
+//
+//   bit_flags! { Permission { Read = 0b001, Write = 0b010 } }
+//
+// becomes a `struct Permission(u32)` with Permission::Read etc. as associated
+// constants. Using a newtype instead of raw u32s means a Permission can't be
+// mixed up with some unrelated number - that's the "type-safe" part.
+//
+// Operator overloading = implementing the std::ops traits:
+//   |  BitOr   (combine)       &  BitAnd  (intersect)
+//   ^  BitXor  (toggle)        !  Not     (complement)
+//
+// ! only flips DEFINED flags, so !Read is Write | Execute rather than a u32
+// with 29 meaningless high bits set (the bitflags crate does the same).
+
+macro_rules! bit_flags {
+    ($name:ident { $($variant:ident = $val:expr),* $(,)? }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct $name(u32);
+
+        #[allow(non_upper_case_globals)]  // Flags read like enum variants
+        impl $name {
+            $(const $variant: $name = $name($val);)*
+
+            // Every defined flag set at once
+            fn all() -> Self {
+                $name(0 $(| $val)*)
+            }
+
+            fn bits(&self) -> u32 {
+                self.0
+            }
+
+            // True if EVERY flag in `other` is also set in self
+            fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                $name(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitAnd for $name {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                $name(self.0 & rhs.0)
+            }
+        }
+
+        impl std::ops::BitXor for $name {
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self {
+                $name(self.0 ^ rhs.0)
+            }
+        }
+
+        impl std::ops::Not for $name {
+            type Output = Self;
+            fn not(self) -> Self {
+                $name(!self.0 & Self::all().0)
+            }
+        }
+    };
+}
+
+bit_flags! {
+    Permission {
+        Read = 0b001,
+        Write = 0b010,
+        Execute = 0b100,
+    }
+}
+
+fn bit_flags_example() {
+    println!("--- Part 4: bit_flags! Macro ---\n");
+
+    let read_write = Permission::Read | Permission::Write;
+    println!("Read | Write      = {:#05b}", read_write.bits());
+    println!("can read?         {}", read_write.contains(Permission::Read));
+    println!("can execute?      {}", read_write.contains(Permission::Execute));
+    println!("toggle Write      = {:#05b}", (read_write ^ Permission::Write).bits());
+    println!("!Read             = {:#05b}", (!Permission::Read).bits());
+    println!("(rw) & Write      = {:?}", read_write & Permission::Write);
+
+    println!();
+}
+
+// =============================================================================
+// PART 5: A TINY STACK-BASED VIRTUAL MACHINE
+// =============================================================================
+// Bytecode interpreters (the JVM, CPython, WebAssembly) are loops over an enum
+// of instructions. A stack machine needs no registers: operands are pushed,
+// and each operation pops its inputs and pushes its result.
+//
+//   (3 + 4) * 2   =>   Push(3) Push(4) Add Push(2) Mul
+//
+//   stack: [3] -> [3, 4] -> [7] -> [7, 2] -> [14]
+//
+// `match` on the Op enum is exhaustive, so adding a new instruction without
+// handling it is a compile error rather than a runtime surprise.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Push(i32),
+    Pop,
+    Add,
+    Sub,
+    Mul,
+    Dup,
+    Print,
+}
+
+#[derive(Debug, Default)]
+struct Vm {
+    stack: Vec<i32>,
+}
+
+impl Vm {
+    fn new() -> Self {
+        Vm::default()
+    }
+
+    fn pop(&mut self) -> Result<i32, String> {
+        self.stack.pop().ok_or_else(|| String::from("stack underflow"))
+    }
+
+    // Pops b then a, so Sub computes a - b in program order
+    fn binary_op(&mut self, f: fn(i32, i32) -> Option<i32>) -> Result<(), String> {
+        let b = self.pop()?;
+        let a = self.pop()?;
+        let result = f(a, b).ok_or_else(|| String::from("integer overflow"))?;
+        self.stack.push(result);
+        Ok(())
+    }
+
+    fn run(&mut self, program: &[Op]) -> Result<(), String> {
+        for op in program {
+            match *op {
+                Op::Push(value) => self.stack.push(value),
+                Op::Pop => {
+                    self.pop()?;
+                }
+                Op::Add => self.binary_op(i32::checked_add)?,
+                Op::Sub => self.binary_op(i32::checked_sub)?,
+                Op::Mul => self.binary_op(i32::checked_mul)?,
+                Op::Dup => {
+                    let top = *self.stack.last().ok_or("stack underflow")?;
+                    self.stack.push(top);
+                }
+                Op::Print => {
+                    let top = self.stack.last().ok_or("stack underflow")?;
+                    println!("  [vm] {}", top);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn vm_example() {
+    println!("--- Part 5: Stack-Based VM ---\n");
+
+    // (3 + 4) * 2
+    let program = [Op::Push(3), Op::Push(4), Op::Add, Op::Push(2), Op::Mul, Op::Print];
+    let mut vm = Vm::new();
+    println!("Running {:?}", program);
+    match vm.run(&program) {
+        Ok(()) => println!("Final stack: {:?}", vm.stack),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Squaring with Dup (9 * 9), then 81 - 1 and discarding the result
+    let mut vm = Vm::new();
+    let program = [Op::Push(9), Op::Dup, Op::Mul, Op::Print, Op::Push(1), Op::Sub, Op::Print, Op::Pop];
+    let _ = vm.run(&program);
+    println!("Stack after Pop: {:?}", vm.stack);
+
+    // Add needs two values but only one is on the stack
+    let mut vm = Vm::new();
+    println!("Underflow: {:?}", vm.run(&[Op::Push(1), Op::Add]));
+
+    println!();
+}
+
+// =============================================================================
+// PART 6: NEWTYPES - Units the Compiler Can Check
+// =============================================================================
+// A newtype is a one-field tuple struct: struct Meters(f64). At runtime it
+// IS an f64 (zero cost), but to the type checker Meters and Seconds are as
+// different as String and bool. Operator traits then say exactly which
+// combinations make sense:
+//
+//   Meters + Meters   -> Meters            (Add)
+//   Meters * f64      -> Meters            (Mul<f64>: scaling)
+//   Meters / Seconds  -> MetersPerSecond   (Div<Seconds>)
+//   Meters + Seconds  -> compile error     (no such impl)
+//
+// The operators shared by every unit are generated by a small macro.
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Seconds(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct MetersPerSecond(f64);
+
+macro_rules! unit_ops {
+    ($unit:ident, $suffix:literal) => {
+        impl Add for $unit {
+            type Output = $unit;
+            fn add(self, rhs: $unit) -> $unit {
+                $unit(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = $unit;
+            fn sub(self, rhs: $unit) -> $unit {
+                $unit(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul<f64> for $unit {
+            type Output = $unit;
+            fn mul(self, factor: f64) -> $unit {
+                $unit(self.0 * factor)
+            }
+        }
+
+        impl Div<f64> for $unit {
+            type Output = $unit;
+            fn div(self, divisor: f64) -> $unit {
+                $unit(self.0 / divisor)
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}{}", self.0, $suffix)
+            }
+        }
+    };
+}
+
+unit_ops!(Meters, "m");
+unit_ops!(Seconds, "s");
+unit_ops!(MetersPerSecond, "m/s");
+
+// The one cross-unit operation: distance / time = speed
+impl Div<Seconds> for Meters {
+    type Output = MetersPerSecond;
+    fn div(self, time: Seconds) -> MetersPerSecond {
+        MetersPerSecond(self.0 / time.0)
+    }
+}
+
+// Mixing units is rejected at compile time:
+//
+// let oops = Meters(1.0) + Seconds(1.0);
+// ERROR: cannot add `Seconds` to `Meters`
+//        (no implementation for `Meters + Seconds`)
+//
+// With plain f64s this would compile and silently produce nonsense - the
+// class of bug that lost NASA's Mars Climate Orbiter (newton-seconds vs
+// pound-force-seconds).
+
+fn newtype_units_example() {
+    println!("--- Part 6: Newtype Units ---\n");
+
+    let leg1 = Meters(120.0);
+    let leg2 = Meters(80.0);
+    let total = leg1 + leg2;
+    let time = Seconds(40.0);
+
+    println!("{} + {} = {}", leg1, leg2, total);
+    println!("{} - {} = {}", leg1, leg2, leg1 - leg2);
+    println!("{} * 1.5 = {}", total, total * 1.5);
+    println!("{} / 4 = {}", time, time / 4.0);
+    println!("{} / {} = {}", total, time, total / time);
+    println!("longer leg: {}", if leg1 > leg2 { leg1 } else { leg2 });
+
+    println!();
+}
+
+// =============================================================================
+// PART 7: LAZY STATICS - once_static! with OnceLock
+// =============================================================================
+// A `static` must be initialized by a constant expression, so this fails:
+//
+//   static PRIMES: Vec<u32> = sieve_of_eratosthenes(100);  // ERROR: not const
+//
+// OnceLock<T> is a thread-safe cell that is written at most once. The first
+// get_or_init() runs the closure; any thread calling at the same time BLOCKS
+// until that value is ready, and every later call just returns a reference.
+//
+// once_static! wraps the boilerplate in an accessor function. The static is
+// declared INSIDE the function, so each macro use gets its own private CELL
+// and the names can't collide.
+
+macro_rules! once_static {
+    ($name:ident : $ty:ty = $init:expr;) => {
+        #[allow(non_snake_case)]  // Named like the static it stands in for
+        fn $name() -> &'static $ty {
+            static CELL: OnceLock<$ty> = OnceLock::new();
+            CELL.get_or_init(|| $init)
+        }
+    };
+}
+
+// All primes below `limit`: cross out every multiple of each prime, starting
+// at p * p (smaller multiples were already crossed out by smaller primes)
+fn sieve_of_eratosthenes(limit: u32) -> Vec<u32> {
+    let limit = limit as usize;
+    let mut is_prime = vec![true; limit];
+    let mut primes = Vec::new();
+    for n in 2..limit {
+        if is_prime[n] {
+            primes.push(n as u32);
+            for multiple in (n * n..limit).step_by(n) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    primes
+}
+
+once_static! { PRIMES: Vec<u32> = sieve_of_eratosthenes(100); }
+
+fn once_static_example() {
+    println!("--- Part 7: once_static! ---\n");
+
+    // Both threads race to initialize; exactly one runs the sieve
+    let (a, b) = thread::scope(|s| {
+        let first = s.spawn(PRIMES);
+        let second = s.spawn(PRIMES);
+        (first.join().unwrap(), second.join().unwrap())
+    });
+    println!("{} primes below 100: {:?}", a.len(), a);
+    println!("same Vec in both threads? {}", ptr::eq(a, b));
+
+    println!();
+}
+
+// =============================================================================
+// PART 8: CONST GENERICS + MaybeUninit - SmallVec<T, N>
+// =============================================================================
+// Most short lists never need the heap. SmallVec<T, N> keeps up to N items
+// in an array INSIDE the value itself, and only "spills" to a Vec when an
+// (N + 1)th item arrives:
+//
+//   SmallVec<String, 4>:  Inline([s0, s1, uninit, uninit], len = 2)
+//                  push x3 -> Heap(vec![s0, s1, s2, s3, s4])
+//
+// `const N: usize` is a CONST GENERIC: SmallVec<u8, 4> and SmallVec<u8, 16>
+// are different types with different sizes, checked at compile time.
+//
+// Why MaybeUninit? [T; N] requires N valid T values up front - there is no
+// "empty slot" for a String. [MaybeUninit<T>; N] is raw storage that the
+// compiler never reads, writes or drops on its own, so WE must uphold:
+//
+// INVARIANT: in Inline(buf, len), buf[..len] is initialized and
+//            buf[len..] is not; len <= N.
+// DROP OBLIGATION: MaybeUninit never drops its contents, so Drop must drop
+//            buf[..len] by hand - or those values leak.
+
+enum SmallVec<T, const N: usize> {
+    Inline([MaybeUninit<T>; N], usize),
+    Heap(Vec<T>),
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    fn new() -> Self {
+        // `const { .. }` lets the array repeat a non-Copy value
+        SmallVec::Inline([const { MaybeUninit::uninit() }; N], 0)
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            SmallVec::Inline(_, len) => *len,
+            SmallVec::Heap(vec) => vec.len(),
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        matches!(self, SmallVec::Inline(..))
+    }
+
+    fn push(&mut self, value: T) {
+        match self {
+            SmallVec::Inline(buf, len) if *len < N => {
+                // write() does not drop the old slot contents - correct, since
+                // buf[len] is uninitialized (INVARIANT)
+                buf[*len].write(value);
+                *len += 1;
+            }
+            SmallVec::Inline(buf, len) => {
+                // Full: move every item into a Vec, then switch variants
+                let mut vec = Vec::with_capacity(N * 2 + 1);
+                let count = mem::replace(len, 0);
+                for slot in &buf[..count] {
+                    // SAFETY: slots ..count were initialized. Each is read
+                    // (moved out) exactly once, and len is already 0, so the
+                    // Drop impl will not drop them again when *self is replaced.
+                    vec.push(unsafe { slot.assume_init_read() });
+                }
+                vec.push(value);
+                *self = SmallVec::Heap(vec);
+            }
+            SmallVec::Heap(vec) => vec.push(value),
+        }
+    }
+
+    // Stays on the heap once spilled (like the smallvec crate)
+    fn pop(&mut self) -> Option<T> {
+        match self {
+            SmallVec::Inline(_, 0) => None,
+            SmallVec::Inline(buf, len) => {
+                *len -= 1;
+                // SAFETY: buf[len] was initialized; with len decremented it is
+                // now outside buf[..len], so it will never be read or dropped again
+                Some(unsafe { buf[*len].assume_init_read() })
+            }
+            SmallVec::Heap(vec) => vec.pop(),
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        match self {
+            // SAFETY: index < len, so the slot is initialized (INVARIANT)
+            SmallVec::Inline(buf, len) if index < *len => Some(unsafe { buf[index].assume_init_ref() }),
+            SmallVec::Inline(..) => None,
+            SmallVec::Heap(vec) => vec.get(index),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVec<T, N> {
+    fn drop(&mut self) {
+        if let SmallVec::Inline(buf, len) = self {
+            for slot in &mut buf[..*len] {
+                // SAFETY: buf[..len] is initialized and this is the last use
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+        // A Heap Vec is dropped automatically after this, like any field
+    }
+}
+
+// By-value iterator. Inline items not yet yielded are still owned by the
+// iterator, so it needs the same drop obligation as SmallVec itself.
+enum SmallVecIntoIter<T, const N: usize> {
+    Inline { buf: [MaybeUninit<T>; N], next: usize, end: usize },
+    Heap(std::vec::IntoIter<T>),
+}
+
+impl<T, const N: usize> IntoIterator for SmallVec<T, N> {
+    type Item = T;
+    type IntoIter = SmallVecIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // SmallVec has a Drop impl, so its fields can't simply be moved out.
+        // ManuallyDrop turns off that Drop: ownership of the items passes to
+        // the iterator instead.
+        let mut this = ManuallyDrop::new(self);
+        match &mut *this {
+            SmallVec::Inline(buf, len) => {
+                // SAFETY: copies the storage bitwise; `this` is never dropped
+                // or used again, so each item now has exactly one owner
+                let buf = unsafe { ptr::read(buf) };
+                SmallVecIntoIter::Inline { buf, next: 0, end: *len }
+            }
+            // mem::take leaves an empty Vec behind, so nothing leaks
+            SmallVec::Heap(vec) => SmallVecIntoIter::Heap(mem::take(vec).into_iter()),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for SmallVecIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self {
+            SmallVecIntoIter::Inline { buf, next, end } if *next < *end => {
+                // SAFETY: buf[next..end] is initialized and not yet yielded;
+                // advancing `next` hands this item over to the caller
+                let item = unsafe { buf[*next].assume_init_read() };
+                *next += 1;
+                Some(item)
+            }
+            SmallVecIntoIter::Inline { .. } => None,
+            SmallVecIntoIter::Heap(iter) => iter.next(),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVecIntoIter<T, N> {
+    fn drop(&mut self) {
+        if let SmallVecIntoIter::Inline { buf, next, end } = self {
+            for slot in &mut buf[*next..*end] {
+                // SAFETY: the items the caller never took are still initialized
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+}
+
+fn small_vec_example() {
+    println!("--- Part 8: SmallVec<T, N> ---\n");
+
+    let mut words: SmallVec<String, 3> = SmallVec::new();
+    for word in ["one", "two", "three", "four"] {
+        words.push(word.to_string());
+        println!("pushed {:<5} len {}, inline? {}", word, words.len(), words.is_inline());
+    }
+    println!("get(1) = {:?}, get(9) = {:?}", words.get(1), words.get(9));
+    println!("pop() = {:?}", words.pop());
+    println!("into_iter: {:?}", words.into_iter().collect::<Vec<_>>());
+
+    // Same type name, different N: different sizes, fixed at compile time
+    println!(
+        "size_of SmallVec<u64, 2> = {}, SmallVec<u64, 8> = {}",
+        size_of::<SmallVec<u64, 2>>(),
+        size_of::<SmallVec<u64, 8>>()
+    );
+
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // Bumps a shared counter when dropped, so tests can detect leaks
+    // (never dropped) and double frees (dropped twice)
+    struct DropCounter {
+        id: usize,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_push_grows_by_doubling_and_pop_is_lifo() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = RawVec::new();
+        let mut capacities = Vec::new();
+
+        for id in 0..16 {
+            v.push(DropCounter { id, drops: Rc::clone(&drops) });
+            capacities.push(v.capacity());
+        }
+        // Reallocations happen when pushing item 1, 2, 3, 5 and 9
+        // (full at capacity 1, 2, 4 and 8)
+        assert_eq!(capacities, vec![1, 2, 4, 4, 8, 8, 8, 8, 16, 16, 16, 16, 16, 16, 16, 16]);
+        assert_eq!(v[0].id, 0);
+        assert_eq!(v[15].id, 15);
+
+        for expected in (0..16).rev() {
+            let item = v.pop().expect("16 items were pushed");
+            assert_eq!(item.id, expected);
+        }
+        assert!(v.pop().is_none());
+        assert_eq!(drops.get(), 16);  // Every popped item dropped exactly once
+    }
+
+    #[test]
+    fn test_drop_releases_remaining_items() {
+        let drops = Rc::new(Cell::new(0));
+        {
+            let mut v = RawVec::new();
+            for id in 0..10 {
+                v.push(DropCounter { id, drops: Rc::clone(&drops) });
+            }
+            v.pop();  // One dropped here...
+            assert_eq!(drops.get(), 1);
+        }  // ...and the other nine when v goes out of scope
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_out_of_bounds_panics() {
+        let mut v = RawVec::new();
+        v.push(1);
+        let _ = v[1];
+    }
+
+    #[test]
+    fn test_c_strlen_counts_bytes() {
+        assert_eq!(c_strlen(c""), 0);
+        assert_eq!(c_strlen(c"hello"), 5);
+        // Bytes, not characters: 'é' is two bytes in UTF-8
+        let c_string = CString::new("héllo").unwrap();
+        assert_eq!(c_strlen(&c_string), 6);
+    }
+
+    #[test]
+    fn test_safe_memcmp() {
+        assert_eq!(safe_memcmp(b"abc", b"abc"), Ordering::Equal);
+        assert_eq!(safe_memcmp(b"abc", b"abd"), Ordering::Less);
+        assert_eq!(safe_memcmp(b"b", b"a"), Ordering::Greater);
+        assert_eq!(safe_memcmp(b"", b""), Ordering::Equal);
+    }
+
+    #[test]
+    #[should_panic(expected = "equal-length")]
+    fn test_safe_memcmp_rejects_different_lengths() {
+        safe_memcmp(b"ab", b"abc");
+    }
+
+    #[test]
+    fn test_c_strncpy_truncates_and_terminates() {
+        let mut buf = [0xffu8; 4];
+        c_strncpy(&mut buf, c"rustacean");
+        assert_eq!(&buf, b"rus\0");
+    }
+
+    #[test]
+    fn test_c_string_roundtrip() {
+        assert_eq!(c_string_roundtrip("hello"), Some("hello".to_string()));
+        assert_eq!(c_string_roundtrip("héllo wörld"), Some("héllo wörld".to_string()));
+        assert_eq!(c_string_roundtrip(""), Some(String::new()));
+        assert_eq!(c_string_roundtrip("nul\0inside"), None);
+    }
+
+    #[test]
+    fn test_retry_succeeds_on_third_attempt() {
+        let calls = RefCell::new(0);
+        let result = retry!(5, { flaky_operation(&calls, 3) });
+        assert_eq!(result, Ok("succeeded on attempt 3".to_string()));
+        assert_eq!(*calls.borrow(), 3);  // Stopped as soon as it worked
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_n_attempts() {
+        let calls = RefCell::new(0);
+        let result = retry!(4, { flaky_operation(&calls, 10) });
+        assert_eq!(result, Err("attempt 4 failed".to_string()));  // The LAST error
+        assert_eq!(*calls.borrow(), 4);
+    }
+
+    #[test]
+    fn test_retry_delay_waits_between_attempts() {
+        let calls = RefCell::new(0);
+        let start = std::time::Instant::now();
+        let result = retry_delay!(3, 20, { flaky_operation(&calls, 10) });
+        assert!(result.is_err());
+        // 3 attempts = 2 pauses; no pause after the final failure
+        assert!(start.elapsed() >= std::time::Duration::from_millis(2 * 20));
+        assert_eq!(*calls.borrow(), 3);
+    }
+
+    #[test]
+    fn test_bit_flags_or_and_contains() {
+        let read_write = Permission::Read | Permission::Write;
+        assert_eq!(read_write.bits(), 0b011);
+        assert!(read_write.contains(Permission::Read));
+        assert!(!read_write.contains(Permission::Execute));
+        assert!(!Permission::Write.contains(Permission::Read));
+        assert_eq!(read_write & Permission::Write, Permission::Write);
+    }
+
+    #[test]
+    fn test_bit_flags_xor_and_not() {
+        let read_write = Permission::Read | Permission::Write;
+        assert_eq!(read_write ^ Permission::Write, Permission::Read);
+        // Only the three defined bits are flipped
+        assert_eq!((!Permission::Read).bits(), 0b110);
+        assert_eq!(!Permission::Read, Permission::Write | Permission::Execute);
+        assert_eq!(!Permission::all(), Permission(0));
+    }
+
+    #[test]
+    fn test_vm_computes_expression() {
+        let mut vm = Vm::new();
+        let program = [Op::Push(3), Op::Push(4), Op::Add, Op::Push(2), Op::Mul];
+        assert_eq!(vm.run(&program), Ok(()));
+        assert_eq!(vm.stack, vec![14]);
+    }
+
+    #[test]
+    fn test_vm_sub_dup_pop_print() {
+        let mut vm = Vm::new();
+        let program = [Op::Push(10), Op::Push(3), Op::Sub, Op::Dup, Op::Print, Op::Pop];
+        assert_eq!(vm.run(&program), Ok(()));
+        assert_eq!(vm.stack, vec![7]);  // Print doesn't consume, Pop does
+    }
+
+    #[test]
+    fn test_vm_underflow_is_err() {
+        assert_eq!(Vm::new().run(&[Op::Push(1), Op::Add]), Err(String::from("stack underflow")));
+        assert!(Vm::new().run(&[Op::Pop]).is_err());
+        assert!(Vm::new().run(&[Op::Dup]).is_err());
+        assert!(Vm::new().run(&[Op::Print]).is_err());
+    }
+
+    #[test]
+    fn test_newtype_same_unit_arithmetic() {
+        assert_eq!(Meters(5.0) + Meters(3.0), Meters(8.0));
+        assert_eq!(Meters(5.0) - Meters(3.0), Meters(2.0));
+        assert_eq!(Seconds(2.0) * 3.0, Seconds(6.0));
+        assert_eq!(Seconds(9.0) / 3.0, Seconds(3.0));
+        assert!(Meters(1.0) < Meters(2.0));
+    }
+
+    #[test]
+    fn test_newtype_meters_per_second() {
+        assert_eq!(Meters(10.0) / Seconds(2.0), MetersPerSecond(5.0));
+        assert_eq!((Meters(10.0) / Seconds(2.0)).to_string(), "5m/s");
+    }
+
+    #[test]
+    fn test_sieve_counts_primes_below_100() {
+        let primes = sieve_of_eratosthenes(100);
+        assert_eq!(primes.len(), 25);
+        assert_eq!(primes[..5], [2, 3, 5, 7, 11]);
+        assert_eq!(primes.last(), Some(&97));
+        assert!(sieve_of_eratosthenes(2).is_empty());
+    }
+
+    #[test]
+    fn test_once_static_shared_across_threads() {
+        let handles: Vec<_> = (0..4).map(|_| thread::spawn(PRIMES)).collect();
+        for handle in handles {
+            let primes: &'static Vec<u32> = handle.join().unwrap();
+            assert!(ptr::eq(primes, PRIMES()));  // The very same allocation
+        }
+    }
+
+    #[test]
+    fn test_small_vec_stays_inline_up_to_n() {
+        let mut v: SmallVec<i32, 4> = SmallVec::new();
+        for i in 0..4 {
+            v.push(i);
+        }
+        assert!(v.is_inline());
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.get(3), Some(&3));
+        assert_eq!(v.get(4), None);
+    }
+
+    #[test]
+    fn test_small_vec_spills_to_heap() {
+        let mut v: SmallVec<String, 4> = SmallVec::new();
+        for i in 0..5 {
+            v.push(i.to_string());
+        }
+        assert!(!v.is_inline());
+        assert_eq!(v.len(), 5);
+        assert_eq!(v.get(0).map(String::as_str), Some("0"));
+        assert_eq!(v.get(4).map(String::as_str), Some("4"));
+    }
+
+    #[test]
+    fn test_small_vec_pop_is_lifo() {
+        for count in [3, 6] {  // Inline, and spilled
+            let mut v: SmallVec<usize, 4> = SmallVec::new();
+            for i in 0..count {
+                v.push(i);
+            }
+            let popped: Vec<usize> = std::iter::from_fn(|| v.pop()).collect();
+            assert_eq!(popped, (0..count).rev().collect::<Vec<_>>());
+            assert_eq!(v.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_small_vec_into_iter_in_order() {
+        for count in [0, 2, 4, 9] {
+            let mut v: SmallVec<String, 4> = SmallVec::new();
+            for i in 0..count {
+                v.push(format!("item{i}"));
+            }
+            let items: Vec<String> = v.into_iter().collect();
+            assert_eq!(items, (0..count).map(|i| format!("item{i}")).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_small_vec_drops_every_item_exactly_once() {
+        let tracker = Rc::new(());
+        {
+            let mut inline: SmallVec<Rc<()>, 4> = SmallVec::new();
+            let mut spilled: SmallVec<Rc<()>, 2> = SmallVec::new();
+            let mut partly_iterated: SmallVec<Rc<()>, 4> = SmallVec::new();
+            for _ in 0..3 {
+                inline.push(Rc::clone(&tracker));
+                spilled.push(Rc::clone(&tracker));
+                partly_iterated.push(Rc::clone(&tracker));
+            }
+            assert_eq!(Rc::strong_count(&tracker), 10);
+            let mut iter = partly_iterated.into_iter();
+            drop(iter.next());  // Take one item; the iterator still owns two
+            assert_eq!(Rc::strong_count(&tracker), 9);
+        }
+        assert_eq!(Rc::strong_count(&tracker), 1);  // No leaks, no double drops
+    }
+}
@@ -0,0 +1,202 @@
+// =============================================================================
+// ENCODING - Turning Bytes into Other Bytes (and Back)
+// =============================================================================
+// A library crate: run the examples with
+//   cargo test -p encoding
+//
+// Every encoder here has a matching decoder, and the most important property
+// to test is the ROUND TRIP: decode(encode(data)) == data.
+// =============================================================================
+
+// =============================================================================
+// BASE64 - Binary Data as Printable Text
+// =============================================================================
+// Every 3 bytes (24 bits) become 4 characters of 6 bits each:
+//
+//   bytes:  01001101 01100001 01101110       "Man"
+//   6-bit:  010011 010110 000101 101110
+//   chars:    T      W      F      u         "TWFu"
+//
+// A final group of 1 or 2 bytes is zero-filled and padded with '=' so the
+// output length is always a multiple of 4: 1 byte -> "xx==", 2 -> "xxx=".
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn base64_encode(input: &[u8]) -> String {
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+
+    for chunk in input.chunks(3) {
+        // Pack up to 3 bytes into the top 24 bits of a u32
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let group = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        // n input bytes carry n + 1 characters' worth of bits
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0b11_1111;
+                output.push(ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+// Reverse lookup: character -> its 6-bit value
+fn decode_char(c: u8) -> Option<u32> {
+    ALPHABET.iter().position(|&a| a == c).map(|i| i as u32)
+}
+
+pub fn base64_decode(input: &str) -> Result<Vec<u8>, String> {
+    let bytes = input.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(format!("length {} is not a multiple of 4", bytes.len()));
+    }
+
+    let mut output = Vec::with_capacity(bytes.len() / 4 * 3);
+    for (n, chunk) in bytes.chunks(4).enumerate() {
+        let is_last = (n + 1) * 4 == bytes.len();
+
+        // '=' is only allowed as the last one or two characters of the input
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return Err(format!("misplaced '=' padding in group {}", n + 1));
+        }
+
+        let mut group = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let value = decode_char(c).ok_or_else(|| {
+                format!("invalid character {:?} at position {}", c as char, n * 4 + i)
+            })?;
+            group |= value << (18 - 6 * i);
+        }
+
+        // 4 chars -> 3 bytes, 3 chars + '=' -> 2 bytes, 2 chars + '==' -> 1 byte
+        let decoded = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+        output.extend_from_slice(&decoded[..3 - padding]);
+    }
+    Ok(output)
+}
+
+// =============================================================================
+// RUN-LENGTH ENCODING - Compressing Repeated Bytes
+// =============================================================================
+// Consecutive identical bytes are stored as (byte, count) pairs:
+//
+//   b"AAAABBBCC"  ->  [(b'A', 4), (b'B', 3), (b'C', 2)]
+//
+// The count is a u8, so a run longer than 255 is split across several pairs:
+// 300 zeros -> [(0, 255), (0, 45)]. Great for long runs, but input with no
+// repeats DOUBLES in size (every byte becomes a pair with count 1).
+
+pub fn rle_encode(input: &[u8]) -> Vec<(u8, u8)> {
+    let mut runs: Vec<(u8, u8)> = Vec::new();
+    for &byte in input {
+        match runs.last_mut() {
+            // Extend the current run unless it is already full
+            Some((b, count)) if *b == byte && *count < u8::MAX => *count += 1,
+            _ => runs.push((byte, 1)),
+        }
+    }
+    runs
+}
+
+pub fn rle_decode(runs: &[(u8, u8)]) -> Vec<u8> {
+    let total: usize = runs.iter().map(|&(_, count)| count as usize).sum();
+    let mut output = Vec::with_capacity(total);
+    for &(byte, count) in runs {
+        output.extend(std::iter::repeat_n(byte, count as usize));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_known_values_and_padding() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"Man"), "TWFu");   // len % 3 == 0: no padding
+        assert_eq!(base64_encode(b"Ma"), "TWE=");    // len % 3 == 2: one '='
+        assert_eq!(base64_encode(b"M"), "TQ==");     // len % 3 == 1: two '='
+        assert_eq!(base64_encode(b"hello world"), "aGVsbG8gd29ybGQ=");
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        // Every byte value, and every length from 0 to 100
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(base64_decode(&base64_encode(&all_bytes)), Ok(all_bytes));
+
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 37 + 11) as u8).collect();
+        for len in 0..=data.len() {
+            let encoded = base64_encode(&data[..len]);
+            assert_eq!(encoded.len() % 4, 0);
+            assert_eq!(base64_decode(&encoded), Ok(data[..len].to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_base64_rejects_invalid_characters() {
+        assert!(base64_decode("TW@u").unwrap_err().contains("invalid character '@'"));
+        assert!(base64_decode("TWF").is_err());  // Not a multiple of 4
+    }
+
+    #[test]
+    fn test_base64_rejects_misplaced_padding() {
+        assert!(base64_decode("T=Fu").is_err());      // '=' in the middle
+        assert!(base64_decode("TQ==TWFu").is_err());  // Padding before the last group
+        assert!(base64_decode("T===").is_err());      // Too much padding
+        assert!(base64_decode("====").is_err());
+    }
+
+    #[test]
+    fn test_rle_long_run_is_split_at_255() {
+        let data = vec![7u8; 300];
+        assert_eq!(rle_encode(&data), vec![(7, 255), (7, 45)]);
+        assert_eq!(rle_decode(&rle_encode(&data)), data);
+    }
+
+    #[test]
+    fn test_rle_alternating_bytes() {
+        let data = b"ababab";
+        let runs = rle_encode(data);
+        assert_eq!(runs.len(), data.len());
+        assert!(runs.iter().all(|&(_, count)| count == 1));
+    }
+
+    #[test]
+    fn test_rle_empty_input() {
+        assert!(rle_encode(b"").is_empty());
+        assert!(rle_decode(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rle_round_trip_fixed_inputs() {
+        let inputs: [&[u8]; 5] = [b"", b"x", b"AAAABBBCC", b"abcabc", &[0; 1000]];
+        for data in inputs {
+            assert_eq!(rle_decode(&rle_encode(data)), data);
+        }
+        assert_eq!(rle_encode(b"AAAABBBCC"), vec![(b'A', 4), (b'B', 3), (b'C', 2)]);
+    }
+
+    #[test]
+    fn test_rle_round_trip_random_input() {
+        // Fixed seeds keep any failure reproducible
+        for seed in [1u64, 0x9E37_79B9_7F4A_7C15, 0xDEAD_BEEF] {
+            let mut state = seed;
+            let data: Vec<u8> = (0..2000)
+                .map(|_| {
+                    // xorshift64, then only 4 distinct values so runs actually occur
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (state % 4) as u8
+                })
+                .collect();
+            assert_eq!(rle_decode(&rle_encode(&data)), data, "seed {seed:#x}");
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// =============================================================================
+// PARSER - Lexing, Parsing, and Evaluating Arithmetic Expressions
+// =============================================================================
+// A library crate: run the examples with
+//   cargo test -p parser
+//
+// Turning "3 + 4 * 2" into 11.0 happens in three stages:
+//
+//   "3 + 4 * 2"  --lex-->  [Num(3), Plus, Num(4), Star, Num(2)]
+//                --parse-> BinOp(Num(3), '+', BinOp(Num(4), '*', Num(2)))
+//                --eval--> 11.0
+// =============================================================================
+
+// =============================================================================
+// PART 1: LEXING - Characters to Tokens
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Num(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+}
+
+pub fn lex(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                // Consume the whole number, then let f64's parser validate it
+                let mut end = start;
+                while let Some(&(i, d)) = chars.peek() {
+                    if !(d.is_ascii_digit() || d == '.') {
+                        break;
+                    }
+                    end = i + d.len_utf8();
+                    chars.next();
+                }
+                let text = &input[start..end];
+                let value = text
+                    .parse()
+                    .map_err(|_| format!("invalid number {:?} at position {}", text, start))?;
+                tokens.push(Token::Num(value));
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            other => return Err(format!("unexpected character {:?} at position {}", other, start)),
+        };
+        tokens.push(token);
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+// =============================================================================
+// PART 2: PARSING - Tokens to a Syntax Tree (Recursive Descent)
+// =============================================================================
+// One function per precedence level; each calls the next-tighter level:
+//
+//   expr   := term (('+' | '-') term)*        lowest precedence
+//   term   := factor (('*' | '/') factor)*
+//   factor := '-' factor | NUM | '(' expr ')'  highest precedence
+//
+// Because `term` is parsed completely before `expr` sees a '+', the
+// multiplication in "3 + 4 * 2" ends up deeper in the tree and runs first.
+// The loops (rather than recursion on the right) make operators
+// left-associative: "8 - 2 - 1" is (8 - 2) - 1.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    BinOp(Box<Expr>, char, Box<Expr>),
+    Neg(Box<Expr>),
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => '+',
+                Some(Token::Minus) => '-',
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            left = Expr::BinOp(Box::new(left), op, Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.factor()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => '*',
+                Some(Token::Slash) => '/',
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            left = Expr::BinOp(Box::new(left), op, Box::new(self.factor()?));
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Num(n)) => Ok(Expr::Num(*n)),
+            Some(Token::Minus) => Ok(Expr::Neg(Box::new(self.factor()?))),
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    other => Err(format!("expected ')', found {:?}", other)),
+                }
+            }
+            Some(other) => Err(format!("unexpected token {:?}", other)),
+            None => Err(String::from("unexpected end of input")),
+        }
+    }
+}
+
+pub fn parse(tokens: &[Token]) -> Result<Expr, String> {
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.expr()?;
+
+    // Everything must be consumed: "1 2" parses "1" and leaves "2" behind
+    match parser.peek() {
+        None => Ok(expr),
+        Some(token) => Err(format!("unexpected trailing token {:?}", token)),
+    }
+}
+
+// =============================================================================
+// PART 3: EVALUATION - Walking the Tree
+// =============================================================================
+// Parsing already rejected every malformed input, so evaluation can't fail.
+// Division by zero follows IEEE 754: 1 / 0 is infinity, 0 / 0 is NaN.
+
+pub fn eval(expr: &Expr) -> f64 {
+    match expr {
+        Expr::Num(n) => *n,
+        Expr::Neg(inner) => -eval(inner),
+        Expr::BinOp(left, op, right) => {
+            let (l, r) = (eval(left), eval(right));
+            match op {
+                '+' => l + r,
+                '-' => l - r,
+                '*' => l * r,
+                '/' => l / r,
+                _ => unreachable!("parser only produces + - * /"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calc(input: &str) -> Result<f64, String> {
+        Ok(eval(&parse(&lex(input)?)?))
+    }
+
+    #[test]
+    fn test_lex() {
+        assert_eq!(
+            lex("3 + 4.5*(2)"),
+            Ok(vec![
+                Token::Num(3.0),
+                Token::Plus,
+                Token::Num(4.5),
+                Token::Star,
+                Token::LParen,
+                Token::Num(2.0),
+                Token::RParen,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(calc("3 + 4 * 2"), Ok(11.0));
+        assert_eq!(calc("(3+4)*2"), Ok(14.0));
+        assert_eq!(calc("-5 + 3"), Ok(-2.0));
+    }
+
+    #[test]
+    fn test_left_associativity() {
+        assert_eq!(calc("8 - 2 - 1"), Ok(5.0));
+        assert_eq!(calc("8 / 4 / 2"), Ok(1.0));
+        assert_eq!(calc("--2"), Ok(2.0));
+    }
+
+    #[test]
+    fn test_invalid_input_is_err() {
+        assert!(calc("").is_err());
+        assert!(calc("3 +").is_err());
+        assert!(calc("(1 + 2").is_err());
+        assert!(calc("1 2").is_err());
+        assert!(calc("1.2.3").is_err());
+        assert!(calc("2 ^ 3").unwrap_err().contains("unexpected character '^'"));
+    }
+}
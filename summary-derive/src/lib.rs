@@ -0,0 +1,186 @@
+// =============================================================================
+// #[derive(Summary)] - GENERATING A TRAIT IMPL
+// =============================================================================
+// Writes `impl Summary for T` so you don't have to:
+//
+//   #[derive(Summary)]
+//   struct Book { title: String, #[summary(skip)] body: String, pages: u32 }
+//
+//   book.summarize() == "title: Dune, pages: 412"
+//
+// Each non-skipped field is formatted with Display, as "name: value",
+// joined by ", ". To choose the wording yourself, put a format string on the
+// struct; fields are referred to by name, as in format!("{title}"):
+//
+//   #[derive(Summary)]
+//   #[summary(format = "{title} ({pages} pages)")]
+//   struct Book { ... }
+//
+// The Summary trait itself is NOT defined here - the
+// generated impl refers to `Summary`, so it must be in scope where the
+// derive is used (a derive can only add code, it can't provide the trait).
+//
+// See builder-macro for another derive written with the same syn + quote
+// toolkit.
+// =============================================================================
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, LitStr};
+
+#[proc_macro_derive(Summary, attributes(summary))]
+pub fn derive_summary(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => return Err(syn::Error::new_spanned(name, "Summary requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "Summary can only be derived for structs")),
+    };
+
+    let mut idents = Vec::new();
+    for field in fields {
+        if is_skipped(field)? {
+            continue;
+        }
+        idents.push(field.ident.as_ref().expect("named fields always have an ident"));
+    }
+
+    let body = match custom_format(&input.attrs)? {
+        Some(format_string) => {
+            // format! rejects unused named arguments, so pass only the fields
+            // the string mentions ("{title}" or "{title:>10}")
+            let used: Vec<_> = idents
+                .into_iter()
+                .filter(|ident| {
+                    let text = format_string.value();
+                    text.contains(&format!("{{{ident}}}")) || text.contains(&format!("{{{ident}:"))
+                })
+                .collect();
+            quote! { ::std::format!(#format_string, #(#used = self.#used),*) }
+        }
+        None => {
+            // Built at compile time, e.g. "headline: {}, author: {}"
+            let labels: Vec<String> = idents.iter().map(|ident| format!("{ident}: {{}}")).collect();
+            let format_string = labels.join(", ");
+            quote! { ::std::format!(#format_string, #(self.#idents),*) }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics Summary for #name #ty_generics #where_clause {
+            fn summarize(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
+
+// Looks for #[summary(format = "...")] on the struct
+fn custom_format(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut format = None;
+    for attr in attrs {
+        if attr.path().is_ident("summary") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("format") {
+                    format = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `format`"))
+                }
+            })?;
+        }
+    }
+    Ok(format)
+}
+
+// Looks for #[summary(skip)] on a field
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("summary") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_format_string_lists_fields_in_order() {
+        let output = expand(parse_quote! {
+            struct Book {
+                title: String,
+                #[summary(skip)]
+                body: String,
+                pages: u32,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(output.contains("\"title: {}, pages: {}\""));
+        assert!(!output.contains("body"));
+    }
+
+    #[test]
+    fn test_custom_format_passes_only_named_fields() {
+        let output = expand(parse_quote! {
+            #[summary(format = "{title} by {author:>8}")]
+            struct Book {
+                title: String,
+                author: String,
+                pages: u32,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(output.contains("\"{title} by {author:>8}\""));
+        assert!(output.contains("title = self . title"));
+        assert!(output.contains("author = self . author"));
+        assert!(!output.contains("pages"));
+    }
+
+    #[test]
+    fn test_rejects_enums_and_unknown_attributes() {
+        let err = expand(parse_quote! { enum Shape { Circle } }).unwrap_err();
+        assert_eq!(err.to_string(), "Summary can only be derived for structs");
+
+        let err = expand(parse_quote! {
+            struct Book {
+                #[summary(hide)]
+                title: String,
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "expected `skip`");
+
+        let err = expand(parse_quote! {
+            #[summary(template = "{title}")]
+            struct Book {
+                title: String,
+            }
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "expected `format`");
+    }
+}
@@ -0,0 +1,11 @@
+// trybuild compiles (and runs) each file under tests/ui/ as a separate
+// program that USES the derive - the only way to test a proc macro's output
+// the way a real user would see it.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/basic.rs");
+    t.pass("tests/ui/skip_field.rs");
+    t.pass("tests/ui/custom_format.rs");
+}
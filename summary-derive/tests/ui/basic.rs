@@ -0,0 +1,16 @@
+use summary_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+#[derive(Summary)]
+struct Book {
+    title: String,
+    pages: u32,
+}
+
+fn main() {
+    let book = Book { title: String::from("Dune"), pages: 412 };
+    assert_eq!(book.summarize(), "title: Dune, pages: 412");
+}
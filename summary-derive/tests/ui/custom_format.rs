@@ -0,0 +1,26 @@
+use summary_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+#[derive(Summary)]
+#[summary(format = "(Read {headline} from {location}... by {author})")]
+struct NewsArticle {
+    headline: String,
+    location: String,
+    author: String,
+    #[summary(skip)]
+    content: String,
+}
+
+fn main() {
+    let article = NewsArticle {
+        headline: String::from("Penguins win"),
+        location: String::from("Pittsburgh"),
+        author: String::from("Iceburgh"),
+        content: String::from("..."),
+    };
+    let _ = &article.content;
+    assert_eq!(article.summarize(), "(Read Penguins win from Pittsburgh... by Iceburgh)");
+}
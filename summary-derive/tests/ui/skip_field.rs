@@ -0,0 +1,22 @@
+use summary_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+// Not Display: this only compiles because the field is skipped
+struct Secret;
+
+#[derive(Summary)]
+struct Account {
+    user: String,
+    #[summary(skip)]
+    password: Secret,
+    logins: u32,
+}
+
+fn main() {
+    let account = Account { user: String::from("ferris"), password: Secret, logins: 3 };
+    let _ = &account.password;
+    assert_eq!(account.summarize(), "user: ferris, logins: 3");
+}